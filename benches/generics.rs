@@ -0,0 +1,61 @@
+// $ cargo bench --features full,extra-traits,test --bench generics
+
+#![feature(test)]
+#![allow(clippy::uninlined_format_args)]
+
+extern crate test;
+
+use quote::format_ident;
+use syn::{parse_quote, Generics, ItemStruct};
+use test::Bencher;
+
+// Generics in the shape of machine-generated code: many type parameters that
+// all repeat the same bounds, on the parameter and again in the `where`
+// clause.
+fn get_generics() -> Generics {
+    let params: Vec<_> = (0..300usize).map(|i| format_ident!("T{}", i)).collect();
+    let item: ItemStruct = parse_quote! {
+        struct S<#(#params: ::core::clone::Clone + ::core::clone::Clone),*>
+        where
+            #(#params: ::core::fmt::Debug + ::core::marker::Send,)*
+            #(#params: ::core::fmt::Debug,)*;
+    };
+    item.generics
+}
+
+#[bench]
+fn clone(b: &mut Bencher) {
+    let generics = get_generics();
+    b.iter(|| generics.clone());
+}
+
+#[bench]
+fn merge(b: &mut Bencher) {
+    let generics = get_generics();
+    b.iter(|| {
+        let mut merged = generics.clone();
+        merged.merge(&generics);
+        merged
+    });
+}
+
+#[bench]
+fn merge_intern_bounds(b: &mut Bencher) {
+    let generics = get_generics();
+    b.iter(|| {
+        let mut merged = generics.clone();
+        merged.merge(&generics);
+        merged.intern_bounds();
+        merged
+    });
+}
+
+#[bench]
+fn intern_bounds(b: &mut Bencher) {
+    let generics = get_generics();
+    b.iter(|| {
+        let mut generics = generics.clone();
+        generics.intern_bounds();
+        generics
+    });
+}
@@ -78,7 +78,8 @@ fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
     let ident = Ident::new(&node.ident, Span::call_site());
     let cfg_features = cfg::features(&node.features);
 
-    let copy = node.ident == "AttrStyle"
+    let copy = node.ident == "AsmDirection"
+        || node.ident == "AttrStyle"
         || node.ident == "BinOp"
         || node.ident == "RangeLimits"
         || node.ident == "TraitBoundModifier"
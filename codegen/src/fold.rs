@@ -1,4 +1,4 @@
-use crate::{file, full, gen};
+use crate::{cfg, file, full, gen};
 use anyhow::Result;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -7,6 +7,23 @@ use syn_codegen::{Data, Definitions, Features, Node, Type};
 
 const FOLD_SRC: &str = "src/gen/fold.rs";
 
+// Syntax tree types that do not implement ToTokens, whose changes cannot be
+// detected by TrackChanges.
+const NO_TO_TOKENS: &[&str] = &[
+    "AsmDirection",
+    "AttrStyle",
+    "Data",
+    "DataEnum",
+    "DataStruct",
+    "DataUnion",
+    "FieldMutability",
+    "ImplRestriction",
+    "LocalInit",
+    "MacroDelimiter",
+    "QSelf",
+    "Span",
+];
+
 fn simple_visit(item: &str, name: &TokenStream) -> TokenStream {
     let ident = gen::under_name(item);
     let method = format_ident!("fold_{}", ident);
@@ -227,8 +244,45 @@ fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Defi
     });
 }
 
+fn track_changes(defs: &Definitions) -> TokenStream {
+    let mut types: Vec<(&str, Features)> = defs
+        .types
+        .iter()
+        .map(|ty| (ty.ident.as_str(), ty.features.clone()))
+        .collect();
+    for &terminal in gen::TERMINAL_TYPES {
+        types.push((terminal, Features::default()));
+    }
+    types.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut methods = TokenStream::new();
+    for (ident, features) in types {
+        let features = cfg::features(&features);
+        let ty = Ident::new(ident, Span::call_site());
+        let fold_fn = format_ident!("fold_{}", gen::under_name(ident));
+        let body = if NO_TO_TOKENS.contains(&ident) {
+            quote! {
+                self.changed = true;
+                self.inner.#fold_fn(i)
+            }
+        } else {
+            quote! {
+                track_changes(self, i, F::#fold_fn)
+            }
+        };
+        methods.extend(quote! {
+            #features
+            fn #fold_fn(&mut self, i: #ty) -> #ty {
+                #body
+            }
+        });
+    }
+    methods
+}
+
 pub fn generate(defs: &Definitions) -> Result<()> {
     let (traits, impls) = gen::traverse(defs, node);
+    let track_changes = track_changes(defs);
     let full_macro = full::get_macro();
     file::write(
         FOLD_SRC,
@@ -236,13 +290,20 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             // Unreachable code is generated sometimes without the full feature.
             #![allow(unreachable_code, unused_variables)]
             #![allow(clippy::match_wildcard_for_single_variants)]
+            // Folding an enum of only unit variants, like AsmDirection,
+            // rebuilds the same value.
+            #![allow(clippy::needless_match)]
 
             #[cfg(any(feature = "full", feature = "derive"))]
             use crate::gen::helper::fold::*;
             #[cfg(any(feature = "full", feature = "derive"))]
             use crate::token::{Brace, Bracket, Group, Paren};
+            #[cfg(feature = "printing")]
+            use crate::tt::TokenStreamHelper;
             use crate::*;
             use proc_macro2::Span;
+            #[cfg(feature = "printing")]
+            use quote::ToTokens;
 
             #full_macro
 
@@ -255,6 +316,102 @@ pub fn generate(defs: &Definitions) -> Result<()> {
                 #traits
             }
 
+            /// A [`Fold`] that records whether folding changed the syntax tree.
+            ///
+            /// Every method delegates to the wrapped fold `inner`. If `changed`
+            /// is not already set, the tokens of the node are compared before
+            /// and after folding and `changed` is set if they differ. Changes
+            /// that only affect spans are not detected.
+            ///
+            /// Only the node passed to a method of `TrackChanges` itself is
+            /// compared, because the wrapped fold recurses into the children of
+            /// that node without going through the wrapper. The granularity of
+            /// the tracking is therefore chosen by the caller: folding a whole
+            /// file through `fold_file` compares the file once, while folding
+            /// each item through `fold_item` and resetting `changed` in between
+            /// tells which of the items changed.
+            ///
+            /// The comparison prints the node to tokens twice, which costs about
+            /// as much as printing the tree, so this is worthwhile when the
+            /// result is going to be tokenized or processed further only if it
+            /// changed. A fold can also set `changed` itself, which skips the
+            /// comparison. The few node types that have no token representation,
+            /// such as `AttrStyle` and `Data`, are always reported as changed
+            /// when folded through the wrapper directly.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// // [dependencies]
+            /// // syn = { version = "1.0", features = ["fold", "full"] }
+            ///
+            /// use syn::fold::{Fold, TrackChanges};
+            /// use syn::{parse_quote, Ident, ItemFn};
+            ///
+            /// struct RenameFoo;
+            ///
+            /// impl Fold for RenameFoo {
+            ///     fn fold_ident(&mut self, ident: Ident) -> Ident {
+            ///         if ident == "foo" {
+            ///             Ident::new("bar", ident.span())
+            ///         } else {
+            ///             ident
+            ///         }
+            ///     }
+            /// }
+            ///
+            /// let mut fold = TrackChanges::new(RenameFoo);
+            ///
+            /// let item: ItemFn = parse_quote!(fn f() { baz() });
+            /// let _ = fold.fold_item_fn(item);
+            /// assert!(!fold.changed);
+            ///
+            /// let item: ItemFn = parse_quote!(fn f() { foo() });
+            /// let _ = fold.fold_item_fn(item);
+            /// assert!(fold.changed);
+            /// ```
+            #[cfg(feature = "printing")]
+            #[cfg_attr(doc_cfg, doc(cfg(all(feature = "fold", feature = "printing"))))]
+            pub struct TrackChanges<F> {
+                pub inner: F,
+                pub changed: bool,
+            }
+
+            #[cfg(feature = "printing")]
+            impl<F> TrackChanges<F> {
+                /// Wraps `inner`, with `changed` initially unset.
+                pub fn new(inner: F) -> Self {
+                    TrackChanges {
+                        inner,
+                        changed: false,
+                    }
+                }
+            }
+
+            #[cfg(feature = "printing")]
+            impl<F: Fold> Fold for TrackChanges<F> {
+                #track_changes
+            }
+
+            #[cfg(feature = "printing")]
+            fn track_changes<F, T>(
+                tracker: &mut TrackChanges<F>,
+                node: T,
+                fold: impl FnOnce(&mut F, T) -> T,
+            ) -> T
+            where
+                T: ToTokens,
+            {
+                if tracker.changed {
+                    return fold(&mut tracker.inner, node);
+                }
+                let before = node.to_token_stream();
+                let node = fold(&mut tracker.inner, node);
+                let after = node.to_token_stream();
+                tracker.changed = TokenStreamHelper(&before) != TokenStreamHelper(&after);
+                node
+            }
+
             #impls
         },
     )?;
@@ -1,5 +1,5 @@
 use crate::operand::{Borrowed, Operand, Owned};
-use crate::{file, full, gen};
+use crate::{cfg, file, full, gen};
 use anyhow::Result;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -49,12 +49,13 @@ fn visit(
             let operand = Borrowed(quote!(it));
             let val = visit(&p.element, features, defs, &operand)?;
             let name = name.ref_tokens();
+            let punct = &defs.tokens[&p.punct];
             Some(quote! {
                 for el in Punctuated::pairs(#name) {
                     let (it, p) = el.into_tuple();
                     #val;
                     if let Some(p) = p {
-                        tokens_helper(v, &p.spans);
+                        v.visit_punct(#punct, &p.spans);
                     }
                 }
             })
@@ -216,8 +217,36 @@ fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Defi
     });
 }
 
+fn visitable(defs: &Definitions) -> TokenStream {
+    let mut impls = TokenStream::new();
+    let ident = Node {
+        ident: "Ident".to_owned(),
+        features: Features::default(),
+        data: Data::Private,
+        exhaustive: true,
+    };
+    for node in defs.types.iter().chain(Some(&ident)) {
+        let features = cfg::features(&node.features);
+        let ty = Ident::new(&node.ident, Span::call_site());
+        let visit_fn = format_ident!("visit_{}", gen::under_name(&node.ident));
+        impls.extend(quote! {
+            #features
+            impl<'ast> Visitable<'ast> for #ty {
+                fn visit<V>(&'ast self, v: &mut V)
+                where
+                    V: Visit<'ast> + ?Sized,
+                {
+                    v.#visit_fn(self);
+                }
+            }
+        });
+    }
+    impls
+}
+
 pub fn generate(defs: &Definitions) -> Result<()> {
     let (traits, impls) = gen::traverse(defs, node);
+    let visitable = visitable(defs);
     let full_macro = full::get_macro();
     file::write(
         VISIT_SRC,
@@ -230,6 +259,7 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             use crate::punctuated::Punctuated;
             use crate::*;
             use proc_macro2::Span;
+            use std::marker::PhantomData;
 
             #full_macro
 
@@ -244,9 +274,264 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             /// [module documentation]: self
             pub trait Visit<'ast> {
                 #traits
+
+                /// Visits the separator that follows an element of a
+                /// [`Punctuated`] sequence, such as either `,` in `a, b,`.
+                ///
+                /// `punct` is the separator as written in the source, like `","`
+                /// or `"::"`, and `spans` holds one span per character of it. The
+                /// separator is visited right after the element it follows and
+                /// before the next element, so a trailing separator is the last
+                /// thing visited in the sequence. The default implementation
+                /// visits each span with [`Visit::visit_span`].
+                ///
+                /// [`Punctuated`]: crate::punctuated::Punctuated
+                fn visit_punct(&mut self, punct: &str, spans: &[Span]) {
+                    visit_punct(self, punct, spans);
+                }
             }
 
             #impls
+
+            pub fn visit_punct<'ast, V>(v: &mut V, punct: &str, spans: &[Span])
+            where
+                V: Visit<'ast> + ?Sized,
+            {
+                for span in spans {
+                    v.visit_span(span);
+                }
+            }
+
+            /// A syntax tree node that can be walked by a [`Visit`] implementation.
+            ///
+            /// This is implemented for every syntax tree type that has a method in
+            /// [`Visit`], and is what lets the helpers [`for_each`], [`collect`],
+            /// [`find_first`] and [`collect_idents`] accept any node as their root.
+            /// Taking the node itself, as in `visit::collect_idents(&item)`, spares
+            /// callers a closure like `|v: &mut dyn Visit| v.visit_item_fn(&item)`
+            /// that would have to name the right method for the node's type and
+            /// would make every call of the walk dynamically dispatched.
+            pub trait Visitable<'ast> {
+                /// Invokes the method of `v` corresponding to this node's type, for
+                /// example [`Visit::visit_item_fn`] for an [`ItemFn`].
+                fn visit<V>(&'ast self, v: &mut V)
+                where
+                    V: Visit<'ast> + ?Sized;
+            }
+
+            impl<'ast, T> Visitable<'ast> for Box<T>
+            where
+                T: Visitable<'ast> + ?Sized,
+            {
+                fn visit<V>(&'ast self, v: &mut V)
+                where
+                    V: Visit<'ast> + ?Sized,
+                {
+                    (**self).visit(v);
+                }
+            }
+
+            #visitable
+
+            /// A [`Visit`] that calls a closure on every node of type `T` in a syntax
+            /// tree.
+            ///
+            /// The supported node types are:
+            ///
+            /// - [`Ident`]: every identifier, in the order they appear. The
+            ///   identifier inside a [`Lifetime`] is not included, so `'a` does not
+            ///   produce an identifier `a`.
+            /// - [`Lifetime`]: every lifetime, including those in generic parameter
+            ///   lists, bounds and labels.
+            /// - [`ExprPath`]: every path expression, such as `x` or `Vec::new` in
+            ///   `Vec::new()`, including ones nested within other path expressions'
+            ///   generic arguments.
+            ///
+            /// The nodes are reached in the same order as by the default [`Visit`]
+            /// methods, which is source order. Nodes within the tokens of a macro
+            /// invocation or of a `Verbatim` node are not syntax trees and are not
+            /// visited.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// // [dependencies]
+            /// // syn = { version = "1.0", features = ["full", "visit"] }
+            ///
+            /// use syn::visit::{Collector, Visit};
+            /// use syn::{parse_quote, ExprPath, ItemFn};
+            ///
+            /// let item: ItemFn = parse_quote! {
+            ///     fn f() {
+            ///         let v = Vec::new();
+            ///         drop(v);
+            ///     }
+            /// };
+            ///
+            /// let mut paths = Vec::new();
+            /// let mut collector = Collector::new(|path: &ExprPath| {
+            ///     paths.push(path.path.segments.last().unwrap().ident.to_string());
+            /// });
+            /// collector.visit_item_fn(&item);
+            /// assert_eq!(paths, ["new", "drop", "v"]);
+            /// ```
+            pub struct Collector<T, F> {
+                f: F,
+                marker: PhantomData<fn(&T)>,
+            }
+
+            impl<T, F> Collector<T, F> {
+                /// Creates a visitor that calls `f` on every node of type `T`.
+                pub fn new(f: F) -> Self {
+                    Collector {
+                        f,
+                        marker: PhantomData,
+                    }
+                }
+
+                /// Returns the closure, along with any state it has captured.
+                pub fn into_inner(self) -> F {
+                    self.f
+                }
+            }
+
+            impl<'ast, F> Visit<'ast> for Collector<Ident, F>
+            where
+                F: FnMut(&'ast Ident),
+            {
+                fn visit_ident(&mut self, i: &'ast Ident) {
+                    (self.f)(i);
+                }
+
+                fn visit_lifetime(&mut self, i: &'ast Lifetime) {}
+            }
+
+            impl<'ast, F> Visit<'ast> for Collector<Lifetime, F>
+            where
+                F: FnMut(&'ast Lifetime),
+            {
+                fn visit_lifetime(&mut self, i: &'ast Lifetime) {
+                    (self.f)(i);
+                }
+            }
+
+            #[cfg(any(feature = "derive", feature = "full"))]
+            impl<'ast, F> Visit<'ast> for Collector<ExprPath, F>
+            where
+                F: FnMut(&'ast ExprPath),
+            {
+                fn visit_expr_path(&mut self, i: &'ast ExprPath) {
+                    (self.f)(i);
+                    visit_expr_path(self, i);
+                }
+            }
+
+            /// Calls `f` on every node of type `T` within `node`.
+            ///
+            /// See [`Collector`] for the supported node types.
+            pub fn for_each<'ast, T, N, F>(node: &'ast N, mut f: F)
+            where
+                T: 'ast,
+                N: Visitable<'ast> + ?Sized,
+                F: FnMut(&'ast T),
+                for<'f> Collector<T, &'f mut dyn FnMut(&'ast T)>: Visit<'ast>,
+            {
+                let mut collector = Collector::<T, &mut dyn FnMut(&'ast T)>::new(&mut f);
+                node.visit(&mut collector);
+            }
+
+            /// Returns every node of type `T` within `node`, in source order.
+            ///
+            /// See [`Collector`] for the supported node types.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// // [dependencies]
+            /// // syn = { version = "1.0", features = ["full", "visit"] }
+            ///
+            /// use syn::{parse_quote, visit, ItemFn, Lifetime};
+            ///
+            /// let item: ItemFn = parse_quote! {
+            ///     fn longest<'a, 'b: 'a>(x: &'a str, y: &'b str) -> &'a str {}
+            /// };
+            ///
+            /// let lifetimes = visit::collect::<Lifetime, _>(&item);
+            /// assert_eq!(lifetimes.len(), 6);
+            /// ```
+            pub fn collect<'ast, T, N>(node: &'ast N) -> Vec<&'ast T>
+            where
+                T: 'ast,
+                N: Visitable<'ast> + ?Sized,
+                for<'f> Collector<T, &'f mut dyn FnMut(&'ast T)>: Visit<'ast>,
+            {
+                let mut nodes = Vec::new();
+                for_each(node, |t| nodes.push(t));
+                nodes
+            }
+
+            /// Returns the first node of type `T` within `node`, in source order.
+            ///
+            /// A [`Visit`] cannot end a traversal early, so the rest of `node` is
+            /// still walked after the first match. This takes time proportional to
+            /// the size of `node`, like [`collect`], but does not allocate.
+            ///
+            /// See [`Collector`] for the supported node types.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// // [dependencies]
+            /// // syn = { version = "1.0", features = ["full", "visit"] }
+            ///
+            /// use syn::{parse_quote, visit, Expr, ExprPath};
+            ///
+            /// let expr: Expr = parse_quote!(1 + len(&items));
+            /// let first = visit::find_first::<ExprPath, _>(&expr).unwrap();
+            /// assert!(first.path.is_ident("len"));
+            /// ```
+            pub fn find_first<'ast, T, N>(node: &'ast N) -> Option<&'ast T>
+            where
+                T: 'ast,
+                N: Visitable<'ast> + ?Sized,
+                for<'f> Collector<T, &'f mut dyn FnMut(&'ast T)>: Visit<'ast>,
+            {
+                let mut first = None;
+                for_each(node, |t| {
+                    if first.is_none() {
+                        first = Some(t);
+                    }
+                });
+                first
+            }
+
+            /// Returns a copy of every identifier within `node`, in source order.
+            ///
+            /// Identifiers of lifetimes are not included.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// // [dependencies]
+            /// // syn = { version = "1.0", features = ["full", "visit"] }
+            ///
+            /// use syn::{parse_quote, visit, ItemStruct};
+            ///
+            /// let item: ItemStruct = parse_quote! {
+            ///     struct Wrapper<'a, T>(&'a T);
+            /// };
+            ///
+            /// let idents = visit::collect_idents(&item);
+            /// assert_eq!(idents, ["Wrapper", "T", "T"]);
+            /// ```
+            pub fn collect_idents<'ast, N>(node: &'ast N) -> Vec<Ident>
+            where
+                N: Visitable<'ast> + ?Sized,
+            {
+                let mut idents = Vec::new();
+                for_each(node, |ident: &Ident| idents.push(ident.clone()));
+                idents
+            }
         },
     )?;
     Ok(())
@@ -1,5 +1,5 @@
 use crate::operand::{Borrowed, Operand, Owned};
-use crate::{file, full, gen};
+use crate::{cfg, file, full, gen};
 use anyhow::Result;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -49,12 +49,13 @@ fn visit(
             let operand = Borrowed(quote!(it));
             let val = visit(&p.element, features, defs, &operand)?;
             let name = name.ref_mut_tokens();
+            let punct = &defs.tokens[&p.punct];
             Some(quote! {
                 for el in Punctuated::pairs_mut(#name) {
                     let (it, p) = el.into_tuple();
                     #val;
                     if let Some(p) = p {
-                        tokens_helper(v, &mut p.spans);
+                        v.visit_punct_mut(#punct, &mut p.spans);
                     }
                 }
             })
@@ -212,8 +213,36 @@ fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Defi
     });
 }
 
+fn attr_stripper(defs: &Definitions) -> TokenStream {
+    let mut methods = TokenStream::new();
+    for node in &defs.types {
+        let has_attrs = match &node.data {
+            Data::Struct(fields) => match fields.get("attrs") {
+                Some(Type::Vec(ty)) => **ty == Type::Syn("Attribute".to_owned()),
+                _ => false,
+            },
+            Data::Enum(_) | Data::Private => false,
+        };
+        if !has_attrs {
+            continue;
+        }
+        let features = cfg::features(&node.features);
+        let ty = Ident::new(&node.ident, Span::call_site());
+        let visit_mut_fn = format_ident!("visit_{}_mut", gen::under_name(&node.ident));
+        methods.extend(quote! {
+            #features
+            fn #visit_mut_fn(&mut self, node: &mut #ty) {
+                node.attrs.retain(|attr| !(self.pred)(attr));
+                #visit_mut_fn(self, node);
+            }
+        });
+    }
+    methods
+}
+
 pub fn generate(defs: &Definitions) -> Result<()> {
     let (traits, impls) = gen::traverse(defs, node);
+    let attr_stripper = attr_stripper(defs);
     let full_macro = full::get_macro();
     file::write(
         VISIT_MUT_SRC,
@@ -241,9 +270,95 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             /// [module documentation]: self
             pub trait VisitMut {
                 #traits
+
+                /// Visits the separator that follows an element of a
+                /// [`Punctuated`] sequence, such as either `,` in `a, b,`.
+                ///
+                /// `punct` is the separator as written in the source, like `","`
+                /// or `"::"`, and `spans` holds one span per character of it. The
+                /// separator is visited right after the element it follows and
+                /// before the next element, so a trailing separator is the last
+                /// thing visited in the sequence. The default implementation
+                /// visits each span with [`VisitMut::visit_span_mut`].
+                ///
+                /// [`Punctuated`]: crate::punctuated::Punctuated
+                fn visit_punct_mut(&mut self, punct: &str, spans: &mut [Span]) {
+                    visit_punct_mut(self, punct, spans);
+                }
+            }
+
+            /// A [`VisitMut`] that removes every attribute for which a predicate
+            /// returns true from the syntax tree.
+            ///
+            /// This is typically used by a derive or attribute macro to remove its
+            /// helper attributes from the code it emits after reading them. All
+            /// attributes of every node in the tree are checked, including those of
+            /// nested items, but not attributes within the tokens of a macro
+            /// invocation or of a `Verbatim` node, which are not syntax trees.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// // [dependencies]
+            /// // quote = "1.0"
+            /// // syn = { version = "1.0", features = ["full", "visit-mut"] }
+            ///
+            /// use quote::quote;
+            /// use syn::visit_mut::{AttrStripper, VisitMut};
+            /// use syn::{parse_quote, ItemStruct};
+            ///
+            /// let mut item: ItemStruct = parse_quote! {
+            ///     #[derive(MyDerive)]
+            ///     #[my_helper(skip)]
+            ///     struct S {
+            ///         #[my_helper(rename = "b")]
+            ///         a: u8,
+            ///     }
+            /// };
+            ///
+            /// let mut stripper = AttrStripper::new(|attr| attr.path().is_ident("my_helper"));
+            /// stripper.visit_item_struct_mut(&mut item);
+            /// assert_eq!(
+            ///     quote!(#item).to_string(),
+            ///     quote!(#[derive(MyDerive)] struct S { a: u8, }).to_string(),
+            /// );
+            /// ```
+            #[cfg(any(feature = "full", feature = "derive"))]
+            #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+            pub struct AttrStripper<F> {
+                pred: F,
+            }
+
+            #[cfg(any(feature = "full", feature = "derive"))]
+            impl<F> AttrStripper<F>
+            where
+                F: FnMut(&Attribute) -> bool,
+            {
+                /// Creates a visitor that removes the attributes for which `pred`
+                /// returns true.
+                pub fn new(pred: F) -> Self {
+                    AttrStripper { pred }
+                }
+            }
+
+            #[cfg(any(feature = "full", feature = "derive"))]
+            impl<F> VisitMut for AttrStripper<F>
+            where
+                F: FnMut(&Attribute) -> bool,
+            {
+                #attr_stripper
             }
 
             #impls
+
+            pub fn visit_punct_mut<V>(v: &mut V, punct: &str, spans: &mut [Span])
+            where
+                V: VisitMut + ?Sized,
+            {
+                for span in spans {
+                    v.visit_span_mut(span);
+                }
+            }
         },
     )?;
     Ok(())
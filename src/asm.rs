@@ -0,0 +1,510 @@
+//! Inline assembly macro arguments, like those of `asm!`.
+
+use super::*;
+use crate::punctuated::Punctuated;
+use proc_macro2::Span;
+#[cfg(feature = "parsing")]
+use proc_macro2::TokenStream;
+
+/// Parses the arguments of an inline assembly macro like `asm!` into their
+/// template strings, operands, `clobber_abi` and `options`.
+///
+/// The tokens are the input of the macro, without the macro name and the
+/// delimiters around the input, as in [`Macro::tokens`]. The grammar is that
+/// of the `asm!` macro built into rustc, except that templates must be
+/// string literals rather than macro invocations like `concat!`.
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::AsmArg;
+///
+/// let tokens = quote!("mov {0}, {1}", out(reg) x, in(reg) y, options(nostack));
+/// let asm = syn::asm::parse(tokens.clone()).unwrap();
+///
+/// let template: Vec<String> = asm.templates().map(|lit| lit.value()).collect();
+/// assert_eq!(template, ["mov {0}, {1}"]);
+/// assert_eq!(asm.operands().count(), 2);
+/// assert!(asm.options().any(|option| option == "nostack"));
+/// assert!(matches!(asm.args[0], AsmArg::Template(_)));
+///
+/// // The arguments print back as they were written.
+/// assert_eq!(quote!(#asm).to_string(), tokens.to_string());
+/// ```
+#[cfg(feature = "parsing")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "parsing"))))]
+pub fn parse(tokens: TokenStream) -> Result<InlineAsm> {
+    use crate::parse::{Parse, Parser};
+    InlineAsm::parse.parse2(tokens)
+}
+
+ast_struct! {
+    /// The arguments of an inline assembly macro like `asm!`, as parsed by
+    /// [`asm::parse`][crate::asm::parse()].
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct InlineAsm {
+        /// The template strings followed by the operands, `clobber_abi` and
+        /// `options` in the order they were written.
+        pub args: Punctuated<AsmArg, Token![,]>,
+    }
+}
+
+impl InlineAsm {
+    /// Returns an iterator over the template strings.
+    pub fn templates(&self) -> impl Iterator<Item = &LitStr> {
+        self.args.iter().filter_map(|arg| match arg {
+            AsmArg::Template(template) => Some(template),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the operands, both positional and named.
+    pub fn operands(&self) -> impl Iterator<Item = &AsmOperand> {
+        self.args.iter().filter_map(|arg| match arg {
+            AsmArg::Operand(operand) => Some(operand),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the ABIs of all `clobber_abi` arguments.
+    pub fn clobber_abis(&self) -> impl Iterator<Item = &LitStr> {
+        self.args
+            .iter()
+            .flat_map(|arg| match arg {
+                AsmArg::ClobberAbi(clobber_abi) => Some(&clobber_abi.abis),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Returns an iterator over the options of all `options` arguments, like
+    /// `nostack` and `pure`.
+    pub fn options(&self) -> impl Iterator<Item = &Ident> {
+        self.args
+            .iter()
+            .flat_map(|arg| match arg {
+                AsmArg::Options(options) => Some(&options.options),
+                _ => None,
+            })
+            .flatten()
+    }
+}
+
+ast_enum! {
+    /// A single argument of an inline assembly macro.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    #[non_exhaustive]
+    pub enum AsmArg {
+        /// A template string: `"mov {0}, {1}"`.
+        Template(LitStr),
+        /// An operand: `out(reg) x` or `x = in(reg) 1`.
+        Operand(AsmOperand),
+        /// Registers clobbered by calls: `clobber_abi("C")`.
+        ClobberAbi(AsmClobberAbi),
+        /// Options for the assembly: `options(nostack, pure)`.
+        Options(AsmOptions),
+    }
+}
+
+ast_struct! {
+    /// An operand of an inline assembly macro, optionally named: the
+    /// `out(reg) x` in `asm!("...", out(reg) x)` or the `y = in(reg) 1` in
+    /// `asm!("...", y = in(reg) 1)`.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct AsmOperand {
+        pub name: Option<(Ident, Token![=])>,
+        pub kind: AsmOperandKind,
+    }
+}
+
+ast_enum! {
+    /// The kind of an inline assembly operand.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    #[non_exhaustive]
+    pub enum AsmOperandKind {
+        /// An operand passed in or out of a register: `in(reg) x`.
+        Reg(AsmRegOperand),
+        /// A constant operand: `const N`.
+        Const(Token![const], Expr),
+        /// A symbol operand: `sym path::to::function`.
+        ///
+        /// The identifier is the `sym` keyword.
+        Sym(Ident, Path),
+        /// A block the assembly code may jump to: `label { ... }`.
+        ///
+        /// The identifier is the `label` keyword.
+        Label(Ident, Block),
+    }
+}
+
+ast_struct! {
+    /// An operand of an inline assembly macro passed in or out of a
+    /// register: `in(reg) x`, `out("eax") y`, `lateout(reg) _`, `inout(reg)
+    /// z` or `inlateout(reg) a => b`.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct AsmRegOperand {
+        /// The direction given by the `in`, `out`, `lateout`, `inout` or
+        /// `inlateout` keyword.
+        pub direction: AsmDirection,
+        /// The span of the direction keyword.
+        pub direction_span: Span,
+        pub paren_token: token::Paren,
+        pub reg: AsmReg,
+        /// The input or output expression, or for `inout` and `inlateout`
+        /// with a separate output, the input expression. An output may be
+        /// `_`.
+        pub expr: Expr,
+        /// The separate output expression of `inout(reg) in_expr =>
+        /// out_expr`.
+        pub out_expr: Option<(Token![=>], Expr)>,
+    }
+}
+
+ast_enum! {
+    /// The direction of a register operand, given by its keyword.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    #[non_exhaustive]
+    pub enum AsmDirection {
+        /// `in`
+        In,
+        /// `out`
+        Out,
+        /// `lateout`
+        LateOut,
+        /// `inout`
+        InOut,
+        /// `inlateout`
+        InLateOut,
+    }
+}
+
+impl AsmDirection {
+    /// Returns true if the operand passes a value into the assembly.
+    pub fn is_input(&self) -> bool {
+        match self {
+            AsmDirection::In | AsmDirection::InOut | AsmDirection::InLateOut => true,
+            AsmDirection::Out | AsmDirection::LateOut => false,
+        }
+    }
+
+    /// Returns true if the operand receives a value from the assembly.
+    pub fn is_output(&self) -> bool {
+        match self {
+            AsmDirection::Out
+            | AsmDirection::LateOut
+            | AsmDirection::InOut
+            | AsmDirection::InLateOut => true,
+            AsmDirection::In => false,
+        }
+    }
+
+    /// Returns the keyword of this direction, like `"inout"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AsmDirection::In => "in",
+            AsmDirection::Out => "out",
+            AsmDirection::LateOut => "lateout",
+            AsmDirection::InOut => "inout",
+            AsmDirection::InLateOut => "inlateout",
+        }
+    }
+}
+
+ast_enum! {
+    /// The register of a register operand.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub enum AsmReg {
+        /// Any register of a class, chosen by the compiler: `reg`.
+        Class(Ident),
+        /// A specific register: `"eax"`.
+        Explicit(LitStr),
+    }
+}
+
+ast_struct! {
+    /// The `clobber_abi("C")` argument of an inline assembly macro.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct AsmClobberAbi {
+        /// The `clobber_abi` keyword.
+        pub clobber_abi_token: Ident,
+        pub paren_token: token::Paren,
+        pub abis: Punctuated<LitStr, Token![,]>,
+    }
+}
+
+ast_struct! {
+    /// The `options(nostack, pure)` argument of an inline assembly macro.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct AsmOptions {
+        /// The `options` keyword.
+        pub options_token: Ident,
+        pub paren_token: token::Paren,
+        pub options: Punctuated<Ident, Token![,]>,
+    }
+}
+
+#[cfg(feature = "parsing")]
+pub(crate) mod parsing {
+    use super::*;
+    use crate::ext::IdentExt;
+    use crate::parse::{Parse, ParseStream, Result};
+
+    const DIRECTIONS: [&str; 5] = ["in", "out", "lateout", "inout", "inlateout"];
+
+    impl Parse for InlineAsm {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let mut args = Punctuated::new();
+            let template: LitStr = input.parse()?;
+            args.push_value(AsmArg::Template(template));
+            let mut operands = false;
+            while !input.is_empty() {
+                args.push_punct(input.parse()?);
+                if input.is_empty() {
+                    break;
+                }
+                let arg = if !operands && input.peek(LitStr) {
+                    AsmArg::Template(input.parse()?)
+                } else {
+                    operands = true;
+                    input.parse()?
+                };
+                args.push_value(arg);
+            }
+            Ok(InlineAsm { args })
+        }
+    }
+
+    impl Parse for AsmArg {
+        fn parse(input: ParseStream) -> Result<Self> {
+            if input.peek(Ident::peek_any) && input.peek2(token::Paren) {
+                let fork = input.fork();
+                let keyword = fork.call(Ident::parse_any)?;
+                if keyword == "clobber_abi" {
+                    return input.parse().map(AsmArg::ClobberAbi);
+                } else if keyword == "options" {
+                    return input.parse().map(AsmArg::Options);
+                }
+            }
+            input.parse().map(AsmArg::Operand)
+        }
+    }
+
+    impl Parse for AsmOperand {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let name = if input.peek(Ident::peek_any)
+                && input.peek2(Token![=])
+                && !input.peek2(Token![=>])
+            {
+                Some((input.call(Ident::parse_any)?, input.parse()?))
+            } else {
+                None
+            };
+            Ok(AsmOperand {
+                name,
+                kind: input.parse()?,
+            })
+        }
+    }
+
+    impl Parse for AsmOperandKind {
+        fn parse(input: ParseStream) -> Result<Self> {
+            if input.peek(Token![const]) {
+                return Ok(AsmOperandKind::Const(input.parse()?, input.parse()?));
+            }
+            if input.peek(Ident::peek_any) {
+                let fork = input.fork();
+                let keyword = fork.call(Ident::parse_any)?;
+                if keyword == "sym" {
+                    let sym_token = input.call(Ident::parse_any)?;
+                    return Ok(AsmOperandKind::Sym(
+                        sym_token,
+                        input.call(Path::parse_mod_style)?,
+                    ));
+                }
+                if keyword == "label" && fork.peek(token::Brace) {
+                    let label_token = input.call(Ident::parse_any)?;
+                    return Ok(AsmOperandKind::Label(label_token, input.parse()?));
+                }
+                if DIRECTIONS.iter().any(|direction| keyword == direction)
+                    && fork.peek(token::Paren)
+                {
+                    return input.parse().map(AsmOperandKind::Reg);
+                }
+            }
+            Err(input
+                .error("expected operand, clobber_abi, options, or additional template string"))
+        }
+    }
+
+    impl Parse for AsmRegOperand {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let keyword = input.call(Ident::parse_any)?;
+            let direction = if keyword == "in" {
+                AsmDirection::In
+            } else if keyword == "out" {
+                AsmDirection::Out
+            } else if keyword == "lateout" {
+                AsmDirection::LateOut
+            } else if keyword == "inout" {
+                AsmDirection::InOut
+            } else if keyword == "inlateout" {
+                AsmDirection::InLateOut
+            } else {
+                return Err(Error::new(keyword.span(), "expected operand direction"));
+            };
+            let content;
+            let paren_token = parenthesized!(content in input);
+            let reg = content.parse()?;
+            let expr: Expr = input.parse()?;
+            let out_expr = if matches!(direction, AsmDirection::InOut | AsmDirection::InLateOut)
+                && input.peek(Token![=>])
+            {
+                Some((input.parse()?, input.parse()?))
+            } else {
+                None
+            };
+            Ok(AsmRegOperand {
+                direction,
+                direction_span: keyword.span(),
+                paren_token,
+                reg,
+                expr,
+                out_expr,
+            })
+        }
+    }
+
+    impl Parse for AsmReg {
+        fn parse(input: ParseStream) -> Result<Self> {
+            if input.peek(LitStr) {
+                input.parse().map(AsmReg::Explicit)
+            } else {
+                input.call(Ident::parse_any).map(AsmReg::Class)
+            }
+        }
+    }
+
+    impl Parse for AsmClobberAbi {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let content;
+            Ok(AsmClobberAbi {
+                clobber_abi_token: input.call(Ident::parse_any)?,
+                paren_token: parenthesized!(content in input),
+                abis: content.parse_terminated(<LitStr as Parse>::parse, Token![,])?,
+            })
+        }
+    }
+
+    impl Parse for AsmOptions {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let content;
+            Ok(AsmOptions {
+                options_token: input.call(Ident::parse_any)?,
+                paren_token: parenthesized!(content in input),
+                options: content.parse_terminated(Ident::parse_any, Token![,])?,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+mod printing {
+    use super::*;
+    use proc_macro2::TokenStream;
+    use quote::ToTokens;
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for InlineAsm {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.args.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AsmArg {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                AsmArg::Template(template) => template.to_tokens(tokens),
+                AsmArg::Operand(operand) => operand.to_tokens(tokens),
+                AsmArg::ClobberAbi(clobber_abi) => clobber_abi.to_tokens(tokens),
+                AsmArg::Options(options) => options.to_tokens(tokens),
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AsmOperand {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            if let Some((name, eq_token)) = &self.name {
+                name.to_tokens(tokens);
+                eq_token.to_tokens(tokens);
+            }
+            self.kind.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AsmOperandKind {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                AsmOperandKind::Reg(operand) => operand.to_tokens(tokens),
+                AsmOperandKind::Const(const_token, expr) => {
+                    const_token.to_tokens(tokens);
+                    expr.to_tokens(tokens);
+                }
+                AsmOperandKind::Sym(sym_token, path) => {
+                    sym_token.to_tokens(tokens);
+                    path.to_tokens(tokens);
+                }
+                AsmOperandKind::Label(label_token, block) => {
+                    label_token.to_tokens(tokens);
+                    block.to_tokens(tokens);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AsmRegOperand {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            let direction = Ident::new(self.direction.as_str(), self.direction_span);
+            direction.to_tokens(tokens);
+            self.paren_token
+                .surround(tokens, |tokens| self.reg.to_tokens(tokens));
+            self.expr.to_tokens(tokens);
+            if let Some((arrow_token, out_expr)) = &self.out_expr {
+                arrow_token.to_tokens(tokens);
+                out_expr.to_tokens(tokens);
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AsmReg {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                AsmReg::Class(class) => class.to_tokens(tokens),
+                AsmReg::Explicit(reg) => reg.to_tokens(tokens),
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AsmClobberAbi {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.clobber_abi_token.to_tokens(tokens);
+            self.paren_token
+                .surround(tokens, |tokens| self.abis.to_tokens(tokens));
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AsmOptions {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.options_token.to_tokens(tokens);
+            self.paren_token
+                .surround(tokens, |tokens| self.options.to_tokens(tokens));
+        }
+    }
+}
@@ -1,13 +1,24 @@
+//! Attributes and helpers for working with lists of attributes.
+
 use super::*;
 use proc_macro2::TokenStream;
 use std::iter;
 use std::slice;
 
+#[cfg(feature = "printing")]
+use quote::ToTokens;
+#[cfg(feature = "printing")]
+use std::collections::HashSet;
+
 #[cfg(feature = "parsing")]
 use crate::meta::{self, ParseNestedMeta};
 #[cfg(feature = "parsing")]
 use crate::parse::{Parse, ParseStream, Parser, Result};
 #[cfg(feature = "parsing")]
+use crate::punctuated::Punctuated;
+#[cfg(all(feature = "parsing", feature = "printing"))]
+use proc_macro2::Span;
+#[cfg(feature = "parsing")]
 use std::fmt::Write;
 
 ast_struct! {
@@ -181,6 +192,25 @@ impl Attribute {
         self.meta.path()
     }
 
+    /// Replaces the content of the attribute between the brackets, keeping
+    /// the `#`, `!` and brackets along with their spans.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Attribute, Meta};
+    ///
+    /// let mut attr: Attribute = parse_quote!(#[derive(Debug)]);
+    /// let meta: Meta = parse_quote!(derive(Debug, Clone));
+    /// attr.set_meta(meta);
+    ///
+    /// let expected: Attribute = parse_quote!(#[derive(Debug, Clone)]);
+    /// assert_eq!(attr, expected);
+    /// ```
+    pub fn set_meta(&mut self, meta: Meta) {
+        self.meta = meta;
+    }
+
     /// Parse the arguments to the attribute as a syntax tree.
     ///
     /// This is similar to pulling out the `TokenStream` from `Meta::List` and
@@ -247,7 +277,7 @@ impl Attribute {
                 let msg = format!("expected attribute arguments in parentheses: {}", expected);
                 Err(crate::error::new2(
                     self.pound_token.span,
-                    self.bracket_token.span,
+                    self.bracket_token.span.join(),
                     msg,
                 ))
             }
@@ -334,6 +364,43 @@ impl Attribute {
         self.parse_args_with(meta::parser(logic))
     }
 
+    /// Decomposes a `#[cfg_attr(predicate, attr1, attr2, ...)]` attribute into
+    /// its configuration predicate and the attributes it conditionally
+    /// applies.
+    ///
+    /// Returns `None` if this is not a `cfg_attr` attribute or if its
+    /// arguments are not a predicate followed by a comma and a
+    /// comma-separated list of attributes. The predicate is not evaluated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Attribute};
+    ///
+    /// let attr: Attribute = parse_quote! {
+    ///     #[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
+    /// };
+    ///
+    /// let (predicate, attrs) = attr.cfg_attr_split().unwrap();
+    /// assert!(predicate.path().is_ident("feature"));
+    /// assert_eq!(attrs.len(), 2);
+    /// assert!(attrs[1].path().is_ident("serde"));
+    /// ```
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn cfg_attr_split(&self) -> Option<(Meta, Vec<Meta>)> {
+        if !self.path().is_ident("cfg_attr") {
+            return None;
+        }
+        let parser = |input: ParseStream| {
+            let predicate: Meta = input.parse()?;
+            input.parse::<Token![,]>()?;
+            let attrs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+            Ok((predicate, attrs.into_iter().collect()))
+        };
+        self.parse_args_with(parser).ok()
+    }
+
     /// Parses zero or more outer attributes from the stream.
     ///
     /// # Example
@@ -472,6 +539,376 @@ impl Meta {
             Meta::NameValue(meta) => &meta.path,
         }
     }
+
+    /// Creates a path meta item consisting of a single identifier, like the
+    /// `test` in `#[test]`.
+    pub fn path_from(ident: Ident) -> Self {
+        Meta::Path(Path::from(ident))
+    }
+
+    /// Creates a list meta item with parentheses around `tokens`, like the
+    /// `derive(Clone)` in `#[derive(Clone)]`.
+    ///
+    /// The parentheses are spanned at `Span::call_site()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use quote::quote;
+    /// use syn::{Ident, Meta};
+    ///
+    /// let derive = Ident::new("derive", Span::call_site());
+    /// let meta = Meta::list(derive.into(), quote!(Clone));
+    /// assert_eq!(quote!(#[#meta]).to_string(), "# [derive (Clone)]");
+    /// ```
+    pub fn list(path: Path, tokens: TokenStream) -> Self {
+        Meta::List(MetaList {
+            path,
+            delimiter: MacroDelimiter::Paren(token::Paren::default()),
+            tokens,
+        })
+    }
+
+    /// Creates a name-value meta item, like the `path = "sys/windows.rs"` in
+    /// `#[path = "sys/windows.rs"]`.
+    ///
+    /// The `=` token is spanned at `Span::call_site()`.
+    pub fn name_value(path: Path, value: Expr) -> Self {
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token: Default::default(),
+            value,
+        })
+    }
+}
+
+/// Determines whether any of the given attributes is the marker `marker`.
+///
+/// The marker is written as a path like `"my_crate_processed"` or
+/// `"my_crate::processed"` and is matched against the path of each attribute,
+/// such as one previously added by [`insert_marker`]. This lets an attribute
+/// macro that may be applied more than once to the same code recognize its
+/// own output and avoid processing it twice.
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, ItemFn};
+///
+/// let mut item: ItemFn = parse_quote! {
+///     #[inline]
+///     fn f() {}
+/// };
+///
+/// if !syn::attr::has_marker(&item.attrs, "my_macro::expanded") {
+///     // ... expand ...
+///     syn::attr::insert_marker(&mut item.attrs, parse_quote!(my_macro::expanded));
+/// }
+///
+/// assert!(syn::attr::has_marker(&item.attrs, "my_macro::expanded"));
+/// ```
+pub fn has_marker(attrs: &[Attribute], marker: &str) -> bool {
+    attrs.iter().any(|attr| path_is(attr.path(), marker))
+}
+
+/// Appends the inert marker attribute `#[marker]` to the given attributes.
+///
+/// The attribute is added as an outer attribute at the end of the list and
+/// can later be detected by [`has_marker`]. The caller is responsible for
+/// making sure that the marker is an attribute the compiler accepts, for
+/// example one registered by a derive or removed again by the macro itself.
+pub fn insert_marker(attrs: &mut Vec<Attribute>, marker: Path) {
+    attrs.push(Attribute {
+        pound_token: Default::default(),
+        style: AttrStyle::Outer,
+        bracket_token: token::Bracket::default(),
+        meta: Meta::Path(marker),
+    });
+}
+
+/// Removes every attribute that repeats an earlier one, keeping the first
+/// occurrence of each in its original position.
+///
+/// Two attributes are duplicates if they have the same style, inner or outer,
+/// and their meta renders to the same tokens, ignoring spans. Documentation
+/// attributes are never removed, since a doc comment may legitimately contain
+/// the same line more than once.
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, ItemFn};
+///
+/// let mut item: ItemFn = parse_quote! {
+///     #[allow(dead_code)]
+///     #[inline]
+///     #[allow(dead_code)]
+///     fn f() {}
+/// };
+///
+/// syn::attr::dedup(&mut item.attrs);
+///
+/// let expected: ItemFn = parse_quote! {
+///     #[allow(dead_code)]
+///     #[inline]
+///     fn f() {}
+/// };
+/// assert_eq!(item, expected);
+/// ```
+#[cfg(feature = "printing")]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+)]
+pub fn dedup(attrs: &mut Vec<Attribute>) {
+    let mut seen = HashSet::new();
+    attrs.retain(|attr| {
+        attr.path().is_ident("doc") || {
+            let is_inner = match attr.style {
+                AttrStyle::Outer => false,
+                AttrStyle::Inner(_) => true,
+            };
+            seen.insert((is_inner, attr.meta.to_token_stream().to_string()))
+        }
+    });
+}
+
+/// Returns the `cfg` predicate under which an item with the given attributes
+/// is compiled, or `None` if it has no `#[cfg(...)]` attribute.
+///
+/// A single `#[cfg(predicate)]` gives back `predicate` itself. Several `cfg`
+/// attributes must all hold for the item to be compiled, so their predicates
+/// are combined into one `all(...)` in the order they appear. Attributes
+/// whose content is not a comma-separated list of metas, such as `#[cfg]`
+/// without parentheses, are skipped. `#[cfg_attr(...)]` attributes are not
+/// expanded.
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{parse_quote, ItemFn};
+///
+/// let item: ItemFn = parse_quote! {
+///     #[cfg(feature = "parsing")]
+///     #[inline]
+///     #[cfg(any(unix, windows))]
+///     fn f() {}
+/// };
+///
+/// let cfg = syn::attr::item_cfg(&item.attrs).unwrap();
+/// assert_eq!(
+///     quote!(#cfg).to_string(),
+///     quote!(all(feature = "parsing", any(unix, windows))).to_string(),
+/// );
+/// ```
+#[cfg(all(feature = "parsing", feature = "printing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(
+        any(feature = "full", feature = "derive"),
+        feature = "parsing",
+        feature = "printing"
+    )))
+)]
+pub fn item_cfg(attrs: &[Attribute]) -> Option<Meta> {
+    let mut predicates = Punctuated::<Meta, Token![,]>::new();
+    for attr in attrs {
+        if !attr.path().is_ident("cfg") {
+            continue;
+        }
+        if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+            predicates.extend(list);
+        }
+    }
+    match predicates.len() {
+        0 => None,
+        1 => predicates.pop().map(crate::punctuated::Pair::into_value),
+        _ => Some(Meta::List(MetaList {
+            path: Ident::new("all", Span::call_site()).into(),
+            delimiter: MacroDelimiter::Paren(token::Paren::default()),
+            tokens: predicates.into_token_stream(),
+        })),
+    }
+}
+
+/// Collects the `#[repr(...)]` attributes among `attrs` into one
+/// [`ReprOptions`].
+///
+/// Several `repr` attributes are combined as the compiler does, so
+/// `#[repr(C)] #[repr(u8)]` is the same as `#[repr(C, u8)]`. A `packed`
+/// without an argument means `packed(1)`. An explicit `Rust` is accepted and
+/// has no effect.
+///
+/// An error is returned for a representation hint that is not recognized,
+/// such as the unstable `simd`, for an argument of `align` or `packed` that
+/// is not an integer literal, and for more than one primitive integer
+/// representation. Other combinations that the compiler rejects, such as
+/// `packed` together with `align`, are not checked.
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, DeriveInput};
+///
+/// let input: DeriveInput = parse_quote! {
+///     #[repr(C, packed(2))]
+///     #[repr(align(4))]
+///     pub struct MyStruct(u16, u32);
+/// };
+///
+/// let repr = syn::attr::parse_repr(&input.attrs)?;
+/// assert!(repr.c);
+/// assert!(!repr.transparent);
+/// assert!(repr.int.is_none());
+/// assert_eq!(repr.packed, Some(2));
+/// assert_eq!(repr.align, Some(4));
+/// # syn::Result::Ok(())
+/// ```
+#[cfg(feature = "parsing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+pub fn parse_repr(attrs: &[Attribute]) -> Result<ReprOptions> {
+    const INTS: [&str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    let mut repr = ReprOptions::default();
+    for attr in attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr.c = true;
+            } else if meta.path.is_ident("transparent") {
+                repr.transparent = true;
+            } else if meta.path.is_ident("Rust") {
+                // The default representation.
+            } else if meta.path.is_ident("align") {
+                let content;
+                parenthesized!(content in meta.input);
+                let lit: LitInt = content.parse()?;
+                repr.align = Some(lit.base10_parse()?);
+            } else if meta.path.is_ident("packed") {
+                repr.packed = Some(if meta.input.peek(token::Paren) {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    let lit: LitInt = content.parse()?;
+                    lit.base10_parse()?
+                } else {
+                    1
+                });
+            } else if let Some(ident) = meta
+                .path
+                .get_ident()
+                .filter(|ident| INTS.iter().any(|int| *ident == int))
+            {
+                if repr.int.is_some() {
+                    return Err(meta.error("conflicting representation hints"));
+                }
+                repr.int = Some(ident.clone());
+            } else {
+                return Err(meta.error("unrecognized repr"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr)
+}
+
+/// The representation of a type given by its `#[repr(...)]` attributes, as
+/// returned by [`parse_repr`].
+///
+/// The default value is the default representation, without any `repr`
+/// attribute.
+///
+/// This struct is non-exhaustive so that representation hints the compiler
+/// adds later, like `repr(simd)`, can get a field without a breaking change.
+/// Start from [`ReprOptions::default()`] to build one.
+#[cfg(feature = "parsing")]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "parsing")))
+)]
+#[non_exhaustive]
+pub struct ReprOptions {
+    /// `#[repr(C)]`.
+    pub c: bool,
+
+    /// `#[repr(transparent)]`.
+    pub transparent: bool,
+
+    /// The primitive integer representation, like the `u8` in `#[repr(u8)]`.
+    pub int: Option<Ident>,
+
+    /// The alignment N of `#[repr(packed(N))]`, or 1 for `#[repr(packed)]`.
+    pub packed: Option<usize>,
+
+    /// The alignment N of `#[repr(align(N))]`.
+    pub align: Option<usize>,
+}
+
+#[cfg(feature = "parsing")]
+impl Default for ReprOptions {
+    fn default() -> Self {
+        ReprOptions {
+            c: false,
+            transparent: false,
+            int: None,
+            packed: None,
+            align: None,
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Clone for ReprOptions {
+    fn clone(&self) -> Self {
+        ReprOptions {
+            c: self.c,
+            transparent: self.transparent,
+            int: self.int.clone(),
+            packed: self.packed,
+            align: self.align,
+        }
+    }
+}
+
+#[cfg(all(feature = "parsing", feature = "extra-traits"))]
+mod debug_impls {
+    use super::*;
+    use std::fmt::{self, Debug};
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+    impl Debug for ReprOptions {
+        fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter
+                .debug_struct("ReprOptions")
+                .field("c", &self.c)
+                .field("transparent", &self.transparent)
+                .field("int", &self.int)
+                .field("packed", &self.packed)
+                .field("align", &self.align)
+                .finish()
+        }
+    }
+}
+
+fn path_is(path: &Path, marker: &str) -> bool {
+    let marker = match marker.strip_prefix("::") {
+        Some(rest) if path.leading_colon.is_some() => rest,
+        None if path.leading_colon.is_none() => marker,
+        _ => return false,
+    };
+    let mut names = marker.split("::");
+    for segment in &path.segments {
+        match names.next() {
+            Some(name) if segment.ident == name && segment.arguments.is_none() => {}
+            _ => return false,
+        }
+    }
+    names.next().is_none()
 }
 
 pub(crate) trait FilterAttrs<'a> {
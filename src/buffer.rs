@@ -11,6 +11,7 @@
 ))]
 use crate::proc_macro as pm;
 use crate::Lifetime;
+use proc_macro2::extra::DelimSpan;
 use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 use std::cmp::Ordering;
 use std::marker::PhantomData;
@@ -191,7 +192,17 @@ impl<'a> Cursor<'a> {
 
     /// If the cursor is pointing at a `Group` with the given delimiter, returns
     /// a cursor into that group and one pointing to the next `TokenTree`.
-    pub fn group(mut self, delim: Delimiter) -> Option<(Cursor<'a>, Span, Cursor<'a>)> {
+    pub fn group(self, delim: Delimiter) -> Option<(Cursor<'a>, Span, Cursor<'a>)> {
+        self.delimited(delim)
+            .map(|(inside, span, after)| (inside, span.join(), after))
+    }
+
+    // Like `group`, but giving the spans of the opening and closing delimiter
+    // too.
+    pub(crate) fn delimited(
+        mut self,
+        delim: Delimiter,
+    ) -> Option<(Cursor<'a>, DelimSpan, Cursor<'a>)> {
         // If we're not trying to enter a none-delimited group, we want to
         // ignore them. We have to make sure to _not_ ignore them when we want
         // to enter them, of course. For obvious reasons.
@@ -201,7 +212,7 @@ impl<'a> Cursor<'a> {
 
         if let Entry::Group(group, end_offset) = self.entry() {
             if group.delimiter() == delim {
-                let span = group.span();
+                let span = group.delim_span();
                 let end_of_group = unsafe { self.ptr.add(*end_offset) };
                 let inside_of_group = unsafe { Cursor::create(self.ptr.add(1), end_of_group) };
                 let after_group = unsafe { Cursor::create(end_of_group, self.scope) };
@@ -225,6 +236,25 @@ impl<'a> Cursor<'a> {
         None
     }
 
+    // Like `group`, but for whichever of parentheses, braces or brackets the
+    // cursor is pointing at.
+    #[cfg(any(feature = "full", feature = "derive"))]
+    pub(crate) fn any_visible_group(
+        mut self,
+    ) -> Option<(Cursor<'a>, Delimiter, DelimSpan, Cursor<'a>)> {
+        self.ignore_none();
+        if let Entry::Group(group, end_offset) = self.entry() {
+            let delimiter = group.delimiter();
+            let span = group.delim_span();
+            let end_of_group = unsafe { self.ptr.add(*end_offset) };
+            let inside_of_group = unsafe { Cursor::create(self.ptr.add(1), end_of_group) };
+            let after_group = unsafe { Cursor::create(end_of_group, self.scope) };
+            return Some((inside_of_group, delimiter, span, after_group));
+        }
+
+        None
+    }
+
     /// If the cursor is pointing at a `Ident`, returns it along with a cursor
     /// pointing at the next `TokenTree`.
     pub fn ident(mut self) -> Option<(Ident, Cursor<'a>)> {
@@ -23,6 +23,7 @@
 /// - [Printing] — `quote!( ... #whatever_token ... )`
 ///
 /// - Construction from a [`Span`] — `let whatever_token = kw::whatever(sp)`
+///   or `kw::whatever::from(sp)`
 ///
 /// - Field access to its span — `let sp = whatever_token.span`
 ///
@@ -113,6 +114,12 @@ macro_rules! custom_keyword {
                 }
             }
 
+            impl $crate::__private::From<$crate::__private::Span> for $ident {
+                fn from(span: $crate::__private::Span) -> Self {
+                    $ident(span)
+                }
+            }
+
             $crate::impl_parse_for_custom_keyword!($ident);
             $crate::impl_to_tokens_for_custom_keyword!($ident);
             $crate::impl_clone_for_custom_keyword!($ident);
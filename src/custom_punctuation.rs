@@ -16,7 +16,8 @@
 ///
 /// - [Printing] — `quote!( ... #lrarrow ... )`
 ///
-/// - Construction from a [`Span`] — `let lrarrow = LeftRightArrow(sp)`
+/// - Construction from a [`Span`] — `let lrarrow = LeftRightArrow(sp)` or
+///   `LeftRightArrow::from(sp)`
 ///
 /// - Construction from multiple [`Span`] — `let lrarrow = LeftRightArrow([sp, sp, sp])`
 ///
@@ -27,6 +28,13 @@
 /// [Printing]: quote::ToTokens
 /// [`Span`]: proc_macro2::Span
 ///
+/// The symbol may be any sequence of punctuation characters. Peeking and
+/// parsing only match the characters if each of them but the last has
+/// [`Spacing::Joint`], so `<<<` is not matched by the input `< <<`, and
+/// printing emits the characters joined in the same way.
+///
+/// [`Spacing::Joint`]: proc_macro2::Spacing::Joint
+///
 /// # Example
 ///
 /// ```
@@ -99,6 +107,12 @@ macro_rules! custom_punctuation {
                 }
             }
 
+            impl $crate::__private::From<$crate::__private::Span> for $ident {
+                fn from(span: $crate::__private::Span) -> Self {
+                    $ident(span)
+                }
+            }
+
             $crate::impl_parse_for_custom_punctuation!($ident, $($tt)+);
             $crate::impl_to_tokens_for_custom_punctuation!($ident, $($tt)+);
             $crate::impl_clone_for_custom_punctuation!($ident, $($tt)+);
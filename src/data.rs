@@ -1,5 +1,6 @@
 use super::*;
-use crate::punctuated::Punctuated;
+use crate::punctuated::{Pair, Punctuated};
+use std::convert::Infallible;
 
 ast_struct! {
     /// An enum variant.
@@ -18,6 +19,68 @@ ast_struct! {
     }
 }
 
+impl Variant {
+    /// Evaluates the explicit discriminant of a C-like enum variant, if it is
+    /// an integer literal such as `= 1`, `= 0x7f` or `= -1`.
+    ///
+    /// Returns `None` if the variant has no explicit discriminant, and an
+    /// error if the discriminant is some other expression that would require
+    /// constant evaluation, such as `= 1 << 3` or `= OTHER as isize`, or if
+    /// the literal does not fit in an `i128`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Variant};
+    ///
+    /// let variant: Variant = parse_quote!(Error = -1);
+    /// assert_eq!(variant.discriminant_value().unwrap().unwrap(), -1);
+    ///
+    /// let variant: Variant = parse_quote!(Ok);
+    /// assert!(variant.discriminant_value().is_none());
+    /// ```
+    pub fn discriminant_value(&self) -> Option<Result<i128>> {
+        let (_eq_token, discriminant) = self.discriminant.as_ref()?;
+        let (negate, expr) = match discriminant {
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => (true, &**expr),
+            expr => (false, expr),
+        };
+        let int = match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) => int,
+            _ => {
+                let message = "expected integer literal as discriminant";
+                #[cfg(feature = "printing")]
+                let error = Error::new_spanned(discriminant, message);
+                #[cfg(not(feature = "printing"))]
+                let error = Error::new(_eq_token.span, message);
+                return Some(Err(error));
+            }
+        };
+        // Parse the magnitude as u128 so that i128::MIN is in range even
+        // though its negation is not.
+        let magnitude = match int.base10_parse::<u128>() {
+            Ok(magnitude) => magnitude,
+            Err(err) => return Some(Err(err)),
+        };
+        let value = if negate {
+            if magnitude <= 1 << 127 {
+                Some((magnitude as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        Some(value.ok_or_else(|| Error::new(int.span(), "discriminant out of range for i128")))
+    }
+}
+
 ast_enum_of_structs! {
     /// Data stored within an enum variant or struct.
     ///
@@ -82,6 +145,60 @@ impl Fields {
         }
     }
 
+    /// Get an iterator over the named fields of a braced struct or variant,
+    /// paired with their identifiers.
+    ///
+    /// Returns `None` for tuple and unit structs or variants.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, DeriveInput, Data};
+    ///
+    /// let input: DeriveInput = parse_quote! {
+    ///     struct Point {
+    ///         x: f64,
+    ///         y: f64,
+    ///     }
+    /// };
+    ///
+    /// if let Data::Struct(data) = &input.data {
+    ///     let names: Vec<String> = data
+    ///         .fields
+    ///         .iter_named()
+    ///         .unwrap()
+    ///         .map(|(ident, _field)| ident.to_string())
+    ///         .collect();
+    ///     assert_eq!(names, ["x", "y"]);
+    /// }
+    /// ```
+    pub fn iter_named(&self) -> Option<impl Iterator<Item = (&Ident, &Field)>> {
+        match self {
+            Fields::Named(f) => Some(
+                f.named
+                    .iter()
+                    .filter_map(|field| Some((field.ident.as_ref()?, field))),
+            ),
+            Fields::Unnamed(_) | Fields::Unit => None,
+        }
+    }
+
+    /// Get an iterator over the fields of a tuple struct or variant, paired
+    /// with the [`Index`] by which each field is accessed.
+    ///
+    /// Returns `None` for braced and unit structs or variants.
+    pub fn iter_indexed(&self) -> Option<impl Iterator<Item = (Index, &Field)>> {
+        match self {
+            Fields::Unnamed(f) => Some(
+                f.unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| (Index::from(i), field)),
+            ),
+            Fields::Named(_) | Fields::Unit => None,
+        }
+    }
+
     /// Returns the number of fields.
     pub fn len(&self) -> usize {
         match self {
@@ -99,6 +216,65 @@ impl Fields {
             Fields::Unnamed(f) => f.unnamed.is_empty(),
         }
     }
+
+    /// Transforms each field, keeping whether the fields are named, unnamed
+    /// or unit along with the braces or parentheses and commas around them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Field, Fields, FieldsNamed};
+    ///
+    /// let fields: FieldsNamed = parse_quote!({ x: f64, y: f64 });
+    /// let fields = Fields::Named(fields).map(|mut field: Field| {
+    ///     let ty = field.ty;
+    ///     field.ty = parse_quote!(Option<#ty>);
+    ///     field
+    /// });
+    /// assert_eq!(
+    ///     quote!(#fields).to_string(),
+    ///     quote!({ x: Option<f64>, y: Option<f64> }).to_string(),
+    /// );
+    /// ```
+    pub fn map(self, mut f: impl FnMut(Field) -> Field) -> Fields {
+        match self.map_with(|field| Ok::<Field, Infallible>(f(field))) {
+            Ok(fields) => fields,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Transforms each field like [`map`][Fields::map], stopping at the
+    /// first error.
+    pub fn try_map(self, f: impl FnMut(Field) -> Result<Field>) -> Result<Fields> {
+        self.map_with(f)
+    }
+
+    fn map_with<E>(
+        self,
+        mut f: impl FnMut(Field) -> std::result::Result<Field, E>,
+    ) -> std::result::Result<Fields, E> {
+        let mut map_punctuated = |fields: Punctuated<Field, Token![,]>| {
+            fields
+                .into_pairs()
+                .map(|pair| {
+                    let (field, comma) = pair.into_tuple();
+                    Ok(Pair::new(f(field)?, comma))
+                })
+                .collect::<std::result::Result<Punctuated<Field, Token![,]>, E>>()
+        };
+        Ok(match self {
+            Fields::Unit => Fields::Unit,
+            Fields::Named(fields) => Fields::Named(FieldsNamed {
+                brace_token: fields.brace_token,
+                named: map_punctuated(fields.named)?,
+            }),
+            Fields::Unnamed(fields) => Fields::Unnamed(FieldsUnnamed {
+                paren_token: fields.paren_token,
+                unnamed: map_punctuated(fields.unnamed)?,
+            }),
+        })
+    }
 }
 
 impl IntoIterator for Fields {
@@ -150,6 +326,12 @@ ast_struct! {
         pub colon_token: Option<Token![:]>,
 
         pub ty: Type,
+
+        /// Default value of the field, like `= 0` in `x: i32 = 0`, if any.
+        ///
+        /// This is the unstable `default_field_values` syntax, which is only
+        /// parsed for named fields.
+        pub default: Option<(Token![=], Expr)>,
     }
 }
 
@@ -225,6 +407,13 @@ pub(crate) mod parsing {
                 }?),
                 colon_token: Some(input.parse()?),
                 ty: input.parse()?,
+                default: if input.peek(Token![=]) {
+                    let eq_token: Token![=] = input.parse()?;
+                    let default: Expr = input.parse()?;
+                    Some((eq_token, default))
+                } else {
+                    None
+                },
             })
         }
 
@@ -238,6 +427,7 @@ pub(crate) mod parsing {
                 ident: None,
                 colon_token: None,
                 ty: input.parse()?,
+                default: None,
             })
         }
     }
@@ -291,6 +481,10 @@ mod printing {
                 TokensOrDefault(&self.colon_token).to_tokens(tokens);
             }
             self.ty.to_tokens(tokens);
+            if let Some((eq_token, default)) = &self.default {
+                eq_token.to_tokens(tokens);
+                default.to_tokens(tokens);
+            }
         }
     }
 }
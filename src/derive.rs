@@ -13,6 +13,105 @@ ast_struct! {
     }
 }
 
+impl DeriveInput {
+    /// Collects the helper attributes named `name`, like the
+    /// `#[my_trait(...)]` attributes declared by
+    /// `#[proc_macro_derive(MyTrait, attributes(my_trait))]`, from the
+    /// container and every variant and field of this input.
+    ///
+    /// Each attribute is returned along with where it was found. The
+    /// attributes of the container come first, followed by those of each
+    /// variant and field in the order they are written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, DeriveInput, HelperAttrLocation};
+    ///
+    /// let input: DeriveInput = parse_quote! {
+    ///     #[my_trait(rename_all = "snake_case")]
+    ///     enum E {
+    ///         #[my_trait(skip)]
+    ///         A,
+    ///         B(#[my_trait(default)] u8),
+    ///     }
+    /// };
+    ///
+    /// let attrs = input.collect_helper_attrs("my_trait");
+    /// assert_eq!(attrs.len(), 3);
+    /// assert!(matches!(attrs[0].0, HelperAttrLocation::Container));
+    /// assert!(matches!(attrs[1].0, HelperAttrLocation::Variant(v) if v.ident == "A"));
+    /// assert!(matches!(attrs[2].0, HelperAttrLocation::Field { index: 0, .. }));
+    /// ```
+    pub fn collect_helper_attrs<'a>(
+        &'a self,
+        name: &str,
+    ) -> Vec<(HelperAttrLocation<'a>, &'a Attribute)> {
+        let mut helpers = Vec::new();
+        let mut push = |location: HelperAttrLocation<'a>, attrs: &'a [Attribute]| {
+            for attr in attrs {
+                if attr.path().is_ident(name) {
+                    helpers.push((location, attr));
+                }
+            }
+        };
+        push(HelperAttrLocation::Container, &self.attrs);
+        let fields = match &self.data {
+            Data::Struct(data) => data.fields.iter(),
+            Data::Enum(data) => {
+                for variant in &data.variants {
+                    push(HelperAttrLocation::Variant(variant), &variant.attrs);
+                    for (index, field) in variant.fields.iter().enumerate() {
+                        let location = HelperAttrLocation::Field {
+                            variant: Some(variant),
+                            index,
+                            field,
+                        };
+                        push(location, &field.attrs);
+                    }
+                }
+                return helpers;
+            }
+            Data::Union(data) => data.fields.named.iter(),
+        };
+        for (index, field) in fields.enumerate() {
+            let location = HelperAttrLocation::Field {
+                variant: None,
+                index,
+                field,
+            };
+            push(location, &field.attrs);
+        }
+        helpers
+    }
+}
+
+/// Where a helper attribute returned by [`DeriveInput::collect_helper_attrs`]
+/// was found.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub enum HelperAttrLocation<'a> {
+    /// On the struct, enum or union itself.
+    Container,
+    /// On a variant of an enum.
+    Variant(&'a Variant),
+    /// On a field of a struct or union, or of the given variant of an enum.
+    /// The `index` is the position of the field among the fields of its
+    /// struct, union or variant.
+    Field {
+        variant: Option<&'a Variant>,
+        index: usize,
+        field: &'a Field,
+    },
+}
+
+impl<'a> Copy for HelperAttrLocation<'a> {}
+
+impl<'a> Clone for HelperAttrLocation<'a> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
 ast_enum! {
     /// The storage of a struct, enum or union data structure.
     ///
@@ -49,6 +148,44 @@ ast_struct! {
     }
 }
 
+impl DataEnum {
+    /// Returns true if every variant is a unit variant without fields, as in
+    /// a C-like enum. An enum without any variants is also fieldless.
+    ///
+    /// Variants with empty braces or parentheses, like `A {}` or `B()`, are
+    /// not unit variants.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Data, DeriveInput};
+    ///
+    /// let input: DeriveInput = parse_quote! {
+    ///     enum Status {
+    ///         Ok = 0,
+    ///         Error = 1,
+    ///     }
+    /// };
+    /// if let Data::Enum(data) = &input.data {
+    ///     assert!(data.is_fieldless());
+    ///     assert!(data.has_discriminants());
+    /// }
+    /// ```
+    pub fn is_fieldless(&self) -> bool {
+        self.variants
+            .iter()
+            .all(|variant| matches!(variant.fields, Fields::Unit))
+    }
+
+    /// Returns true if any variant has an explicit discriminant, like the
+    /// `= 1` in `Error = 1`.
+    pub fn has_discriminants(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| variant.discriminant.is_some())
+    }
+}
+
 ast_struct! {
     /// An untagged union input to a `proc_macro_derive` macro.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
@@ -0,0 +1,95 @@
+//! Reconstructing documentation from `#[doc]` attributes.
+
+use crate::attr::{Attribute, Meta};
+use crate::expr::{Expr, ExprLit};
+use crate::lit::Lit;
+
+/// Concatenates the text of all `#[doc = "..."]` attributes, in order, into
+/// one documentation block.
+///
+/// Each `///` comment is written by the compiler as a separate `#[doc]`
+/// attribute whose value keeps the space that conventionally follows the
+/// slashes, so one leading space is removed from every line. The text of
+/// different attributes is joined with `\n`.
+///
+/// A `/** ... */` block comment is written as a single attribute holding
+/// every line of the block. Leading and trailing blank lines of such a block
+/// are dropped and, if every other line is prefixed with a `*` like in the
+/// common style below, that prefix is removed before the leading space.
+///
+/// ```text
+/// /**
+///  * Block comment
+///  * documentation.
+///  */
+/// ```
+///
+/// Attributes other than `doc`, as well as `doc` attributes that are not a
+/// name-value pair with a string literal value, such as `#[doc(hidden)]` or
+/// `#[doc = include_str!("README.md")]`, are skipped. Inner `//!`
+/// documentation is included just like outer documentation.
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, ItemFn};
+///
+/// let item: ItemFn = parse_quote! {
+///     /// Returns the answer.
+///     ///
+///     /// Computed by a very large computer.
+///     #[doc(hidden)]
+///     fn answer() -> u8 { 42 }
+/// };
+///
+/// let doc = syn::doc::extract(&item.attrs);
+/// assert_eq!(doc, "Returns the answer.\n\nComputed by a very large computer.");
+/// ```
+pub fn extract(attrs: &[Attribute]) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for attr in attrs {
+        if let Some(value) = doc_value(attr) {
+            lines.extend(unindent(&value).into_iter().map(str::to_owned));
+        }
+    }
+    lines.join("\n")
+}
+
+fn doc_value(attr: &Attribute) -> Option<String> {
+    let meta = match &attr.meta {
+        Meta::NameValue(meta) if meta.path.is_ident("doc") => meta,
+        _ => return None,
+    };
+    match &meta.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Some(lit.value()),
+        _ => None,
+    }
+}
+
+fn unindent(value: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = value.lines().collect();
+    if lines.len() > 1 {
+        // Written as a block comment.
+        while lines.first().map_or(false, |line| line.trim().is_empty()) {
+            lines.remove(0);
+        }
+        while lines.last().map_or(false, |line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        if lines.iter().all(|line| line.trim_start().starts_with('*')) {
+            for line in &mut lines {
+                *line = &line.trim_start()[1..];
+            }
+        }
+    } else if lines.is_empty() {
+        lines.push("");
+    }
+    for line in &mut lines {
+        if let Some(rest) = line.strip_prefix(' ') {
+            *line = rest;
+        }
+    }
+    lines
+}
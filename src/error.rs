@@ -201,6 +201,31 @@ impl Error {
         }
     }
 
+    /// Creates an error with the specified message spanning two syntax tree
+    /// nodes and everything in between, for example two attributes that
+    /// conflict with each other.
+    ///
+    /// The error spans from the first token of `a` to the last token of `b`,
+    /// so `a` is expected to come before `b` in the source. Like
+    /// `Error::new_spanned`, this produces a span covering both nodes on
+    /// stable Rust, where spans cannot otherwise be joined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Attribute, Error};
+    ///
+    /// fn check_conflict(skip: &Attribute, rename: &Attribute) -> Error {
+    ///     Error::new_spanned2(skip, rename, "`skip` conflicts with `rename`")
+    /// }
+    /// ```
+    #[cfg(feature = "printing")]
+    pub fn new_spanned2<T: ToTokens, U: ToTokens, M: Display>(a: T, b: U, message: M) -> Self {
+        let mut tokens = a.into_token_stream();
+        b.to_tokens(&mut tokens);
+        Error::new_spanned(tokens, message)
+    }
+
     /// The source location of the error.
     ///
     /// Spans are not thread-safe so this function returns `Span::call_site()`
@@ -6,7 +6,7 @@ use quote::IdentFragment;
 #[cfg(feature = "printing")]
 use std::fmt::{self, Display};
 use std::hash::{Hash, Hasher};
-#[cfg(feature = "parsing")]
+#[cfg(any(feature = "parsing", all(feature = "full", feature = "visit-mut")))]
 use std::mem;
 
 ast_enum_of_structs! {
@@ -99,6 +99,9 @@ ast_enum_of_structs! {
         /// An await expression: `fut.await`.
         Await(ExprAwait),
 
+        /// An explicit tail call: `become f(x)`.
+        Become(ExprBecome),
+
         /// A binary operation: `a + b`, `a += b`.
         Binary(ExprBinary),
 
@@ -181,6 +184,9 @@ ast_enum_of_structs! {
         /// A range expression: `1..2`, `1..`, `..2`, `1..=2`, `..=2`.
         Range(ExprRange),
 
+        /// Address-of operation: `&raw const place` or `&raw mut place`.
+        RawAddr(ExprRawAddr),
+
         /// A referencing operation: `&a` or `&mut a`.
         Reference(ExprReference),
 
@@ -282,6 +288,19 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// An explicit tail call: `become f(x)`.
+    ///
+    /// This is an unstable language feature; the compiler only accepts it
+    /// with `#![feature(explicit_tail_calls)]`.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct ExprBecome #full {
+        pub attrs: Vec<Attribute>,
+        pub become_token: Token![become],
+        pub expr: Box<Expr>,
+    }
+}
+
 ast_struct! {
     /// A binary operation: `a + b`, `a += b`.
     #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
@@ -337,6 +356,18 @@ ast_struct! {
     }
 }
 
+impl ExprCast {
+    /// Returns the expression being cast, like the `x` in `x as u8`.
+    pub fn operand(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Returns the type being cast to, like the `u8` in `x as u8`.
+    pub fn target_type(&self) -> &Type {
+        &self.ty
+    }
+}
+
 ast_struct! {
     /// A closure expression: `|a, b| a + b`.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
@@ -355,6 +386,35 @@ ast_struct! {
     }
 }
 
+#[cfg(feature = "full")]
+impl ExprClosure {
+    /// Returns true if this is an `async` closure, like `async || ...`.
+    pub fn is_async(&self) -> bool {
+        self.asyncness.is_some()
+    }
+
+    /// Returns true if this closure takes ownership of its captures, like
+    /// `move || ...`.
+    pub fn is_move(&self) -> bool {
+        self.capture.is_some()
+    }
+
+    /// Returns true if this is a `static` closure, like `static || ...`,
+    /// which defines a coroutine that may hold borrows across yield points.
+    pub fn is_static(&self) -> bool {
+        self.movability.is_some()
+    }
+
+    /// Returns true if the closure's return type is written explicitly, like
+    /// `|x| -> u8 { x }`, rather than inferred.
+    pub fn has_return_type(&self) -> bool {
+        match self.output {
+            ReturnType::Default => false,
+            ReturnType::Type(..) => true,
+        }
+    }
+}
+
 ast_struct! {
     /// A const block: `const { ... }`.
     pub struct ExprConst #full {
@@ -550,6 +610,38 @@ ast_struct! {
     }
 }
 
+#[cfg(feature = "full")]
+impl ExprRange {
+    /// Returns the lower bound of the range, like the `1` in `1..5`.
+    ///
+    /// This is `None` for a range unbounded below like `..5`, as well as for
+    /// the full range `..`.
+    pub fn start(&self) -> Option<&Expr> {
+        self.start.as_deref()
+    }
+
+    /// Returns the upper bound of the range, like the `5` in `1..5` or
+    /// `..=5`.
+    ///
+    /// This is `None` for a range unbounded above like `1..`, as well as for
+    /// the full range `..`.
+    pub fn end(&self) -> Option<&Expr> {
+        self.end.as_deref()
+    }
+}
+
+ast_struct! {
+    /// Address-of operation: `&raw const place` or `&raw mut place`.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct ExprRawAddr #full {
+        pub attrs: Vec<Attribute>,
+        pub and_token: Token![&],
+        pub raw: Token![raw],
+        pub mutability: PointerMutability,
+        pub expr: Box<Expr>,
+    }
+}
+
 ast_struct! {
     /// A referencing operation: `&a` or `&mut a`.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
@@ -610,6 +702,127 @@ ast_struct! {
     }
 }
 
+#[cfg(all(feature = "full", feature = "clone-impls"))]
+impl ExprTry {
+    /// Rewrites `expr?` into an explicit `match` on a `Result`.
+    ///
+    /// This is the desugaring of the `?` operator from before the `Try`
+    /// trait, which is exact for `Result` but does not cover `Option`, `Poll`
+    /// or other types implementing `Try`:
+    ///
+    /// ```
+    /// # fn f<T, E, F: From<E>>(expr: Result<T, E>) -> Result<T, F> {
+    /// # Ok(
+    /// match expr {
+    ///     ::core::result::Result::Ok(val) => val,
+    ///     ::core::result::Result::Err(err) => {
+    ///         return ::core::result::Result::Err(::core::convert::From::from(err));
+    ///     }
+    /// }
+    /// # )
+    /// # }
+    /// ```
+    ///
+    /// The attributes of the `ExprTry` are moved to the `match`, and every
+    /// token produced by the desugaring has the span of the `?` token.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Expr, ExprTry};
+    ///
+    /// let expr: ExprTry = parse_quote!(file.read_to_string(&mut buf)?);
+    /// let desugared = expr.desugar();
+    ///
+    /// let expected: Expr = parse_quote! {
+    ///     match file.read_to_string(&mut buf) {
+    ///         ::core::result::Result::Ok(val) => val,
+    ///         ::core::result::Result::Err(err) => return ::core::result::Result::Err(::core::convert::From::from(err)),
+    ///     }
+    /// };
+    /// assert_eq!(desugared, expected);
+    /// ```
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "clone-impls"))))]
+    pub fn desugar(&self) -> Expr {
+        let span = self.question_token.span;
+        let binding = |name: &str| {
+            Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: Ident::new(name, span),
+                subpat: None,
+            })
+        };
+        let variable = |name: &str| {
+            Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: Path::from(Ident::new(name, span)),
+            })
+        };
+        let variant = |name: &str, pat: Pat| {
+            Pat::TupleStruct(PatTupleStruct {
+                attrs: Vec::new(),
+                qself: None,
+                path: global_path(&["core", "result", "Result", name], span),
+                paren_token: token::Paren(span),
+                elems: Punctuated::from_iter([pat]),
+            })
+        };
+        let call = |path: &[&str], arg: Expr| {
+            Expr::Call(ExprCall {
+                attrs: Vec::new(),
+                func: Box::new(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: global_path(path, span),
+                })),
+                paren_token: token::Paren(span),
+                args: Punctuated::from_iter([arg]),
+            })
+        };
+        let arm = |pat: Pat, body: Expr| Arm {
+            attrs: Vec::new(),
+            pat,
+            guard: None,
+            fat_arrow_token: Token![=>](span),
+            body: Box::new(body),
+            comma: Some(Token![,](span)),
+        };
+
+        let convert = call(&["core", "convert", "From", "from"], variable("err"));
+        let err = call(&["core", "result", "Result", "Err"], convert);
+        let ret = Expr::Return(ExprReturn {
+            attrs: Vec::new(),
+            return_token: Token![return](span),
+            expr: Some(Box::new(err)),
+        });
+        Expr::Match(ExprMatch {
+            attrs: self.attrs.clone(),
+            match_token: Token![match](span),
+            expr: self.expr.clone(),
+            brace_token: token::Brace(span),
+            arms: vec![
+                arm(variant("Ok", binding("val")), variable("val")),
+                arm(variant("Err", binding("err")), ret),
+            ],
+        })
+    }
+}
+
+// Builds a path like `::core::result::Result`.
+#[cfg(all(feature = "full", feature = "clone-impls"))]
+fn global_path(segments: &[&str], span: Span) -> Path {
+    Path {
+        leading_colon: Some(Token![::](span)),
+        segments: segments
+            .iter()
+            .map(|segment| PathSegment::from(Ident::new(segment, span)))
+            .collect(),
+    }
+}
+
 ast_struct! {
     /// A try block: `try { ... }`.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
@@ -683,13 +896,14 @@ impl Expr {
         },
     });
 
-    #[cfg(all(feature = "parsing", feature = "full"))]
+    #[cfg(all(any(feature = "parsing", feature = "visit-mut"), feature = "full"))]
     pub(crate) fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
         match self {
             Expr::Array(ExprArray { attrs, .. })
             | Expr::Assign(ExprAssign { attrs, .. })
             | Expr::Async(ExprAsync { attrs, .. })
             | Expr::Await(ExprAwait { attrs, .. })
+            | Expr::Become(ExprBecome { attrs, .. })
             | Expr::Binary(ExprBinary { attrs, .. })
             | Expr::Block(ExprBlock { attrs, .. })
             | Expr::Break(ExprBreak { attrs, .. })
@@ -713,6 +927,7 @@ impl Expr {
             | Expr::Paren(ExprParen { attrs, .. })
             | Expr::Path(ExprPath { attrs, .. })
             | Expr::Range(ExprRange { attrs, .. })
+            | Expr::RawAddr(ExprRawAddr { attrs, .. })
             | Expr::Reference(ExprReference { attrs, .. })
             | Expr::Repeat(ExprRepeat { attrs, .. })
             | Expr::Return(ExprReturn { attrs, .. })
@@ -727,6 +942,530 @@ impl Expr {
             Expr::Verbatim(_) => Vec::new(),
         }
     }
+
+    /// Classifies an expression that names a constant value, as typically
+    /// found in the value of an attribute like `#[attr(n = 5)]` or
+    /// `#[attr(n = MY_CONST)]`.
+    ///
+    /// Returns `None` for any expression that is neither a literal nor a path
+    /// without a qualified self type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ConstRef, Expr};
+    ///
+    /// let expr: Expr = parse_quote!(MY_CONST);
+    /// match expr.as_const_ref() {
+    ///     Some(ConstRef::Lit(lit)) => { /* use the literal value */ }
+    ///     Some(ConstRef::Path(path)) => assert!(path.is_ident("MY_CONST")),
+    ///     None => panic!("expected a literal or a path"),
+    /// }
+    /// ```
+    pub fn as_const_ref(&self) -> Option<ConstRef<'_>> {
+        match self {
+            Expr::Lit(ExprLit { lit, .. }) => Some(ConstRef::Lit(lit)),
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) => Some(ConstRef::Path(path)),
+            _ => None,
+        }
+    }
+
+    /// Returns the expression inside of any parentheses written around this
+    /// one, like the `a + b` in `((a + b))`.
+    ///
+    /// Only [`Expr::Paren`] is peeled, which holds a single parenthesized
+    /// expression. Parentheses containing a comma, as in `(a, b)` or `(a,)`,
+    /// are an [`Expr::Tuple`] and are returned unchanged, as is the empty
+    /// tuple `()`. Invisible [`Expr::Group`] layers, such as those around an
+    /// `$expr` substituted by a `macro_rules!` macro, are peeled as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Expr};
+    ///
+    /// let expr: Expr = parse_quote!(((a + b)));
+    /// assert!(matches!(expr.unparen(), Expr::Binary(_)));
+    ///
+    /// let expr: Expr = parse_quote!(((a, b)));
+    /// assert!(matches!(expr.unparen(), Expr::Tuple(_)));
+    /// ```
+    pub fn unparen(&self) -> &Expr {
+        let mut expr = self;
+        loop {
+            expr = match expr {
+                Expr::Paren(ExprParen { expr, .. }) => expr,
+                #[cfg(feature = "full")]
+                Expr::Group(ExprGroup { expr, .. }) => expr,
+                _ => return expr,
+            };
+        }
+    }
+
+    /// The precedence of the outermost operator of this expression, where a
+    /// higher value binds more tightly, for deciding whether the expression
+    /// needs parentheses when used as the operand of another one.
+    ///
+    /// Binary operators have the precedence given by [`BinOp::precedence`],
+    /// and the other expressions fit into the same scale:
+    ///
+    /// | Precedence | Expressions                                    |
+    /// |------------|------------------------------------------------|
+    /// | 17         | literals, paths, blocks, `(x)` and so on       |
+    /// | 16         | `f()`, `x.f()`, `x.f`, `x[i]`, `x?`, `x.await` |
+    /// | 15         | `-x`, `!x`, `*x`, `&x`                         |
+    /// | 14         | `x as T`                                       |
+    /// | 5 to 13    | binary operators                               |
+    /// | 4          | `a..b`                                         |
+    /// | 2          | `a = b`, `a += b`                              |
+    /// | 0          | closures, `let`, `return`, `break`, and so on  |
+    ///
+    /// The expressions with precedence 0 are closures, `let`, `return`,
+    /// `break`, `continue`, `yield` and `become`. They extend as far to the
+    /// right as possible, so they need parentheses anywhere but as the
+    /// rightmost operand. [`Expr::Verbatim`] also has precedence 0, because
+    /// nothing is known about how it binds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, BinOp, Expr};
+    ///
+    /// // Splicing `a + b` as the left operand of `*` requires parentheses,
+    /// // while `a * b` can go as is.
+    /// let mul: BinOp = parse_quote!(*);
+    /// let sum: Expr = parse_quote!(a + b);
+    /// let product: Expr = parse_quote!(a * b);
+    /// assert!(sum.precedence() < mul.precedence());
+    /// assert!(product.precedence() >= mul.precedence());
+    /// ```
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Expr::Closure(_)
+            | Expr::Let(_)
+            | Expr::Return(_)
+            | Expr::Break(_)
+            | Expr::Continue(_)
+            | Expr::Yield(_)
+            | Expr::Become(_)
+            | Expr::Verbatim(_) => 0,
+            Expr::Assign(_) => 2,
+            Expr::Range(_) => 4,
+            Expr::Binary(ExprBinary { op, .. }) => op.precedence(),
+            Expr::Cast(_) => 14,
+            Expr::Unary(_) | Expr::RawAddr(_) | Expr::Reference(_) => 15,
+            Expr::Await(_)
+            | Expr::Call(_)
+            | Expr::Field(_)
+            | Expr::Index(_)
+            | Expr::MethodCall(_)
+            | Expr::Try(_) => 16,
+            Expr::Array(_)
+            | Expr::Async(_)
+            | Expr::Block(_)
+            | Expr::Const(_)
+            | Expr::ForLoop(_)
+            | Expr::Group(_)
+            | Expr::If(_)
+            | Expr::Infer(_)
+            | Expr::Lit(_)
+            | Expr::Loop(_)
+            | Expr::Macro(_)
+            | Expr::Match(_)
+            | Expr::Paren(_)
+            | Expr::Path(_)
+            | Expr::Repeat(_)
+            | Expr::Struct(_)
+            | Expr::TryBlock(_)
+            | Expr::Tuple(_)
+            | Expr::Unsafe(_)
+            | Expr::While(_) => 17,
+        }
+    }
+
+    /// Removes parentheses from this expression and its subexpressions
+    /// wherever they make no difference to how the expression parses, as
+    /// decided by [`Expr::precedence`].
+    ///
+    /// This is for cleaning up after transformations that parenthesize
+    /// conservatively, like the constructors [`Expr::field`] and
+    /// [`Expr::cast`]. Parentheses are removed around the operands of
+    /// operators, method calls, field accesses and the like, and around the
+    /// elements of a call's arguments, an array, a tuple or a struct literal.
+    /// A few cases where dropping them could change the meaning of the code
+    /// keep their parentheses even if the precedence would allow it:
+    ///
+    /// - everything within the condition of `if` and `while`, the scrutinee
+    ///   of `match` and the iterator of `for`, where a struct literal would
+    ///   be parsed differently without parentheses;
+    /// - the left operand of an operator when it starts with a block-like
+    ///   expression such as `match`, which in statement position would end
+    ///   the statement;
+    /// - `(a.f)()`, which calls the field `f` rather than the method `f`;
+    /// - an operand ending in a cast like `(x as T)`, unless it is the operand
+    ///   of another cast, because a following `<` would begin generic
+    ///   arguments of `T`;
+    /// - a literal receiver of a field access, method call or `.await`, as in
+    ///   `(1).0`.
+    ///
+    /// Parentheses with attributes on them or around the leftmost operand of
+    /// an expression with attributes are also kept, and so is the outermost
+    /// pair around the whole expression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Expr};
+    ///
+    /// let mut expr: Expr = parse_quote!(((a.b)) + (c * d) - f((x)));
+    /// expr.simplify_parens();
+    /// assert_eq!(quote!(#expr).to_string(), quote!(a.b + c * d - f(x)).to_string());
+    ///
+    /// let mut expr: Expr = parse_quote!((a + b) * c);
+    /// expr.simplify_parens();
+    /// assert_eq!(quote!(#expr).to_string(), quote!((a + b) * c).to_string());
+    /// ```
+    #[cfg(all(feature = "full", feature = "visit-mut"))]
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "visit-mut"))))]
+    pub fn simplify_parens(&mut self) {
+        use crate::visit_mut::VisitMut;
+        parens::SimplifyParens {
+            in_condition: false,
+        }
+        .visit_expr_mut(self);
+    }
+
+    /// Constructs an access of the unnamed field `index` of `base`, as in
+    /// `self.0` or `tuple.1`.
+    ///
+    /// The index and the `.` token are given the span `span`. If `base` would
+    /// bind less tightly than a field access, like `a + b`, or is a literal,
+    /// whose `.` would otherwise be read as part of the literal as in `1.0`,
+    /// it is wrapped in parentheses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use quote::quote;
+    /// use syn::{parse_quote, Expr};
+    ///
+    /// let expr = Expr::tuple_index(parse_quote!(self), 0, Span::call_site());
+    /// assert_eq!(quote!(#expr).to_string(), "self . 0");
+    /// ```
+    pub fn tuple_index(base: Expr, index: u32, span: Span) -> Expr {
+        Expr::Field(ExprField {
+            attrs: Vec::new(),
+            base: field_base(base),
+            dot_token: Token![.](span),
+            member: Member::Unnamed(Index { index, span }),
+        })
+    }
+
+    /// Constructs an access of the named field `name` of `base`, as in
+    /// `self.len`.
+    ///
+    /// The `.` token is given the span of `name`. If `base` would bind less
+    /// tightly than a field access, like `a + b`, or is a literal, it is
+    /// wrapped in parentheses.
+    pub fn field(base: Expr, name: Ident) -> Expr {
+        Expr::Field(ExprField {
+            attrs: Vec::new(),
+            base: field_base(base),
+            dot_token: Token![.](name.span()),
+            member: Member::Named(name),
+        })
+    }
+
+    /// Constructs a cast of `expr` to the type `ty`, as in `x as u64`.
+    ///
+    /// If `expr` would bind less tightly than a cast, like `a + b`, it is
+    /// wrapped in parentheses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Expr};
+    ///
+    /// let expr = Expr::cast(parse_quote!(a + b), parse_quote!(u64));
+    /// assert_eq!(quote!(#expr).to_string(), "(a + b) as u64");
+    /// ```
+    pub fn cast(expr: Expr, ty: Type) -> Expr {
+        let expr = match expr {
+            Expr::Cast(_)
+            | Expr::Lit(_)
+            | Expr::RawAddr(_)
+            | Expr::Reference(_)
+            | Expr::Unary(_) => Box::new(expr),
+            _ => field_base(expr),
+        };
+        Expr::Cast(ExprCast {
+            attrs: Vec::new(),
+            expr,
+            as_token: <Token![as]>::default(),
+            ty: Box::new(ty),
+        })
+    }
+}
+
+fn field_base(base: Expr) -> Box<Expr> {
+    match base {
+        Expr::Array(_)
+        | Expr::Await(_)
+        | Expr::Call(_)
+        | Expr::Field(_)
+        | Expr::Group(_)
+        | Expr::Index(_)
+        | Expr::Macro(_)
+        | Expr::MethodCall(_)
+        | Expr::Paren(_)
+        | Expr::Path(_)
+        | Expr::Try(_)
+        | Expr::Tuple(_) => Box::new(base),
+        // A literal is parenthesized too, because `1.0` would lex as a float
+        // literal rather than a field access of `1`.
+        _ => Box::new(Expr::Paren(ExprParen {
+            attrs: Vec::new(),
+            paren_token: token::Paren::default(),
+            expr: Box::new(base),
+        })),
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+mod parens {
+    use super::*;
+    use crate::visit_mut::{self, VisitMut};
+
+    pub(super) struct SimplifyParens {
+        // Whether we are within an expression that is not allowed to contain
+        // a struct literal unless it is parenthesized.
+        pub(super) in_condition: bool,
+    }
+
+    impl SimplifyParens {
+        fn visit_condition(&mut self, expr: &mut Expr) {
+            let in_condition = mem::replace(&mut self.in_condition, true);
+            self.visit_expr_mut(expr);
+            self.in_condition = in_condition;
+        }
+    }
+
+    impl VisitMut for SimplifyParens {
+        fn visit_expr_mut(&mut self, expr: &mut Expr) {
+            visit_mut::visit_expr_mut(self, expr);
+            if self.in_condition {
+                return;
+            }
+            match expr {
+                Expr::Assign(expr) => {
+                    unparen_left(&mut expr.left, |inner| inner.precedence() > 2);
+                    unparen_operand(&mut expr.right, |inner| inner.precedence() >= 2);
+                }
+                Expr::Binary(expr) => {
+                    let precedence = expr.op.precedence();
+                    let (left, right) = match precedence {
+                        // Assignments associate to the right.
+                        2 => (precedence + 1, precedence),
+                        // Comparisons do not associate.
+                        7 => (precedence + 1, precedence + 1),
+                        _ => (precedence, precedence + 1),
+                    };
+                    unparen_left(&mut expr.left, |inner| inner.precedence() >= left);
+                    unparen_operand(&mut expr.right, |inner| inner.precedence() >= right);
+                }
+                Expr::Cast(expr) => unparen(&mut expr.expr, |inner| {
+                    inner.precedence() >= 14 && !starts_with_block(inner) && !has_attrs(inner)
+                }),
+                Expr::Range(expr) => {
+                    if let Some(start) = &mut expr.start {
+                        unparen_left(start, |inner| inner.precedence() > 4);
+                    }
+                    if let Some(end) = &mut expr.end {
+                        unparen_operand(end, |inner| inner.precedence() > 4);
+                    }
+                }
+                Expr::RawAddr(ExprRawAddr { expr, .. })
+                | Expr::Reference(ExprReference { expr, .. })
+                | Expr::Unary(ExprUnary { expr, .. }) => {
+                    unparen_operand(expr, |inner| inner.precedence() >= 15);
+                }
+                Expr::Call(expr) => {
+                    unparen_left(&mut expr.func, |inner| {
+                        inner.precedence() >= 16 && !matches!(inner, Expr::Field(_))
+                    });
+                    expr.args.iter_mut().for_each(|arg| unparen(arg, |_| true));
+                }
+                Expr::MethodCall(expr) => {
+                    unparen_receiver(&mut expr.receiver);
+                    expr.args.iter_mut().for_each(|arg| unparen(arg, |_| true));
+                }
+                Expr::Await(ExprAwait { base, .. }) | Expr::Field(ExprField { base, .. }) => {
+                    unparen_receiver(base);
+                }
+                Expr::Index(expr) => {
+                    unparen_left(&mut expr.expr, |inner| inner.precedence() >= 16);
+                    unparen(&mut expr.index, |_| true);
+                }
+                Expr::Try(expr) => unparen_left(&mut expr.expr, |inner| inner.precedence() >= 16),
+                Expr::Array(ExprArray { elems, .. }) | Expr::Tuple(ExprTuple { elems, .. }) => {
+                    elems.iter_mut().for_each(|elem| unparen(elem, |_| true));
+                }
+                Expr::Struct(expr) => {
+                    for field in &mut expr.fields {
+                        unparen(&mut field.expr, |_| true);
+                    }
+                }
+                Expr::Paren(expr) => unparen(&mut expr.expr, |_| true),
+                _ => {}
+            }
+        }
+
+        fn visit_expr_if_mut(&mut self, expr: &mut ExprIf) {
+            self.visit_condition(&mut expr.cond);
+            self.visit_block_mut(&mut expr.then_branch);
+            if let Some((_else_token, else_branch)) = &mut expr.else_branch {
+                self.visit_expr_mut(else_branch);
+            }
+        }
+
+        fn visit_expr_while_mut(&mut self, expr: &mut ExprWhile) {
+            self.visit_condition(&mut expr.cond);
+            self.visit_block_mut(&mut expr.body);
+        }
+
+        fn visit_expr_match_mut(&mut self, expr: &mut ExprMatch) {
+            self.visit_condition(&mut expr.expr);
+            for arm in &mut expr.arms {
+                self.visit_arm_mut(arm);
+            }
+        }
+
+        fn visit_expr_for_loop_mut(&mut self, expr: &mut ExprForLoop) {
+            self.visit_pat_mut(&mut expr.pat);
+            self.visit_condition(&mut expr.expr);
+            self.visit_block_mut(&mut expr.body);
+        }
+    }
+
+    // Removes a layer of parentheses around `expr` if `allowed` accepts the
+    // expression inside of them.
+    fn unparen(expr: &mut Expr, allowed: impl FnOnce(&mut Expr) -> bool) {
+        if let Expr::Paren(paren) = expr {
+            if paren.attrs.is_empty() && allowed(&mut paren.expr) {
+                *expr = mem::replace(&mut *paren.expr, Expr::Verbatim(TokenStream::new()));
+            }
+        }
+    }
+
+    // Like `unparen` for the operand of an operator. The parentheses are kept
+    // around an expression ending in a cast, since a `<` after it would begin
+    // generic arguments of the type.
+    fn unparen_operand(expr: &mut Expr, allowed: impl FnOnce(&mut Expr) -> bool) {
+        unparen(expr, |inner| allowed(inner) && !ends_with_cast(inner));
+    }
+
+    // Like `unparen_operand` for the leftmost operand of an expression, whose
+    // attributes would otherwise apply to the enclosing expression.
+    fn unparen_left(expr: &mut Expr, allowed: impl FnOnce(&mut Expr) -> bool) {
+        unparen_operand(expr, |inner| {
+            allowed(inner) && !starts_with_block(inner) && !has_attrs(inner)
+        });
+    }
+
+    fn has_attrs(expr: &mut Expr) -> bool {
+        let attrs = expr.replace_attrs(Vec::new());
+        let has_attrs = !attrs.is_empty();
+        expr.replace_attrs(attrs);
+        has_attrs
+    }
+
+    fn unparen_receiver(expr: &mut Expr) {
+        unparen_left(expr, |inner| {
+            inner.precedence() >= 16 && !matches!(inner, Expr::Lit(_))
+        });
+    }
+
+    fn starts_with_block(expr: &Expr) -> bool {
+        match expr {
+            Expr::Async(_)
+            | Expr::Block(_)
+            | Expr::Const(_)
+            | Expr::ForLoop(_)
+            | Expr::If(_)
+            | Expr::Loop(_)
+            | Expr::Match(_)
+            | Expr::TryBlock(_)
+            | Expr::Unsafe(_)
+            | Expr::While(_) => true,
+            Expr::Assign(ExprAssign { left: expr, .. })
+            | Expr::Await(ExprAwait { base: expr, .. })
+            | Expr::Binary(ExprBinary { left: expr, .. })
+            | Expr::Call(ExprCall { func: expr, .. })
+            | Expr::Cast(ExprCast { expr, .. })
+            | Expr::Field(ExprField { base: expr, .. })
+            | Expr::Index(ExprIndex { expr, .. })
+            | Expr::MethodCall(ExprMethodCall { receiver: expr, .. })
+            | Expr::Range(ExprRange {
+                start: Some(expr), ..
+            })
+            | Expr::Try(ExprTry { expr, .. }) => starts_with_block(expr),
+            _ => false,
+        }
+    }
+
+    fn ends_with_cast(expr: &Expr) -> bool {
+        match expr {
+            Expr::Cast(_) => true,
+            Expr::Assign(ExprAssign { right: expr, .. })
+            | Expr::Binary(ExprBinary { right: expr, .. })
+            | Expr::Range(ExprRange {
+                end: Some(expr), ..
+            })
+            | Expr::RawAddr(ExprRawAddr { expr, .. })
+            | Expr::Reference(ExprReference { expr, .. })
+            | Expr::Unary(ExprUnary { expr, .. }) => ends_with_cast(expr),
+            _ => false,
+        }
+    }
+}
+
+/// A borrowed literal or path to a constant, as returned by
+/// [`Expr::as_const_ref`].
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub enum ConstRef<'a> {
+    /// A literal like `5` or `"string"`.
+    Lit(&'a Lit),
+    /// A path to a constant like `MY_CONST` or `Self::MAX`.
+    Path(&'a Path),
+}
+
+impl<'a> Copy for ConstRef<'a> {}
+
+impl<'a> Clone for ConstRef<'a> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+mod debug_impls {
+    use super::*;
+    use std::fmt::{self, Debug};
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+    impl<'a> Debug for ConstRef<'a> {
+        fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ConstRef::Lit(lit) => formatter.debug_tuple("Lit").field(lit).finish(),
+                ConstRef::Path(path) => formatter.debug_tuple("Path").field(path).finish(),
+            }
+        }
+    }
 }
 
 ast_enum! {
@@ -897,6 +1636,37 @@ ast_struct! {
     }
 }
 
+#[cfg(feature = "full")]
+impl Arm {
+    /// Returns the condition of the match guard, like the `n < 10` in
+    /// `Some(n) if n < 10 => ...`, if the arm has one.
+    pub fn guard_expr(&self) -> Option<&Expr> {
+        self.guard.as_ref().map(|(_if_token, guard)| &**guard)
+    }
+
+    /// Sets the match guard of this arm to `if guard`, replacing any guard
+    /// the arm already has.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Arm};
+    ///
+    /// let arm: Arm = parse_quote!(Some(n) => n,);
+    /// let arm = arm.with_guard(parse_quote!(n < 10));
+    /// assert_eq!(quote!(#arm).to_string(), "Some (n) if n < 10 => n ,");
+    /// ```
+    pub fn with_guard(mut self, guard: Expr) -> Arm {
+        let if_token = match self.guard {
+            Some((if_token, _)) => if_token,
+            None => Token![if](self.fat_arrow_token.spans[0]),
+        };
+        self.guard = Some((if_token, Box::new(guard)));
+        self
+    }
+}
+
 #[cfg(feature = "full")]
 ast_enum! {
     /// Limit types of a range, inclusive or exclusive.
@@ -909,6 +1679,29 @@ ast_enum! {
     }
 }
 
+#[cfg(feature = "full")]
+impl RangeLimits {
+    /// Returns true for `..=`, which includes the upper bound, and false
+    /// for `..`, which excludes it.
+    pub fn is_inclusive(&self) -> bool {
+        match self {
+            RangeLimits::HalfOpen(_) => false,
+            RangeLimits::Closed(_) => true,
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+ast_enum! {
+    /// Mutability of a raw pointer (`*const T`, `*mut T`), in which non-mutable
+    /// isn't the implicit default.
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub enum PointerMutability {
+        Const(Token![const]),
+        Mut(Token![mut]),
+    }
+}
+
 #[cfg(any(feature = "parsing", feature = "printing"))]
 #[cfg(feature = "full")]
 pub(crate) fn requires_terminator(expr: &Expr) -> bool {
@@ -926,6 +1719,7 @@ pub(crate) fn requires_terminator(expr: &Expr) -> bool {
         | Expr::Assign(_)
         | Expr::Async(_)
         | Expr::Await(_)
+        | Expr::Become(_)
         | Expr::Binary(_)
         | Expr::Break(_)
         | Expr::Call(_)
@@ -943,6 +1737,7 @@ pub(crate) fn requires_terminator(expr: &Expr) -> bool {
         | Expr::Paren(_)
         | Expr::Path(_)
         | Expr::Range(_)
+        | Expr::RawAddr(_)
         | Expr::Reference(_)
         | Expr::Repeat(_)
         | Expr::Return(_)
@@ -965,8 +1760,6 @@ pub(crate) mod parsing {
     use crate::path;
     use std::cmp::Ordering;
 
-    crate::custom_keyword!(raw);
-
     // When we're parsing expressions which occur before blocks, like in an if
     // statement's condition, we cannot parse a struct literal.
     //
@@ -1342,6 +2135,8 @@ pub(crate) mod parsing {
     // <UnOp> <trailer>
     // & <trailer>
     // &mut <trailer>
+    // &raw const <trailer>
+    // &raw mut <trailer>
     // box <trailer>
     #[cfg(feature = "full")]
     fn unary_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
@@ -1349,19 +2144,31 @@ pub(crate) mod parsing {
         let attrs = input.call(expr_attrs)?;
         if input.peek(Token![&]) {
             let and_token: Token![&] = input.parse()?;
-            let raw: Option<raw> =
-                if input.peek(raw) && (input.peek2(Token![mut]) || input.peek2(Token![const])) {
-                    Some(input.parse()?)
-                } else {
-                    None
-                };
+            let raw: Option<Token![raw]> = if input.peek(Token![raw])
+                && (input.peek2(Token![mut]) || input.peek2(Token![const]))
+            {
+                Some(input.parse()?)
+            } else {
+                None
+            };
             let mutability: Option<Token![mut]> = input.parse()?;
-            if raw.is_some() && mutability.is_none() {
-                input.parse::<Token![const]>()?;
-            }
+            let const_token: Option<Token![const]> = if raw.is_some() && mutability.is_none() {
+                Some(input.parse()?)
+            } else {
+                None
+            };
             let expr = Box::new(unary_expr(input, allow_struct)?);
-            if raw.is_some() {
-                Ok(Expr::Verbatim(verbatim::between(begin, input)))
+            if let Some(raw) = raw {
+                Ok(Expr::RawAddr(ExprRawAddr {
+                    attrs,
+                    and_token,
+                    raw,
+                    mutability: match mutability {
+                        Some(mut_token) => PointerMutability::Mut(mut_token),
+                        None => PointerMutability::Const(const_token.unwrap()),
+                    },
+                    expr,
+                }))
             } else {
                 Ok(Expr::Reference(ExprReference {
                     attrs,
@@ -1598,6 +2405,8 @@ pub(crate) mod parsing {
             input.parse().map(Expr::Continue)
         } else if input.peek(Token![return]) {
             expr_ret(input, allow_struct).map(Expr::Return)
+        } else if input.peek(Token![become]) {
+            expr_become(input, allow_struct).map(Expr::Become)
         } else if input.peek(token::Bracket) {
             array_or_repeat(input)
         } else if input.peek(Token![let]) {
@@ -2159,6 +2968,21 @@ pub(crate) mod parsing {
         }
     }
 
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for ExprRawAddr {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let allow_struct = AllowStruct(true);
+            Ok(ExprRawAddr {
+                attrs: Vec::new(),
+                and_token: input.parse()?,
+                raw: input.parse()?,
+                mutability: input.parse()?,
+                expr: Box::new(unary_expr(input, allow_struct)?),
+            })
+        }
+    }
+
     #[cfg(feature = "full")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     impl Parse for ExprReference {
@@ -2191,6 +3015,15 @@ pub(crate) mod parsing {
         }
     }
 
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for ExprBecome {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let allow_struct = AllowStruct(true);
+            expr_become(input, allow_struct)
+        }
+    }
+
     #[cfg(feature = "full")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     impl Parse for ExprTryBlock {
@@ -2425,6 +3258,15 @@ pub(crate) mod parsing {
         })
     }
 
+    #[cfg(feature = "full")]
+    fn expr_become(input: ParseStream, allow_struct: AllowStruct) -> Result<ExprBecome> {
+        Ok(ExprBecome {
+            attrs: Vec::new(),
+            become_token: input.parse()?,
+            expr: Box::new(ambiguous_expr(input, allow_struct)?),
+        })
+    }
+
     #[cfg(feature = "full")]
     fn expr_ret(input: ParseStream, allow_struct: AllowStruct) -> Result<ExprReturn> {
         Ok(ExprReturn {
@@ -2611,6 +3453,21 @@ pub(crate) mod parsing {
         }
     }
 
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for PointerMutability {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(Token![const]) {
+                Ok(PointerMutability::Const(input.parse()?))
+            } else if lookahead.peek(Token![mut]) {
+                Ok(PointerMutability::Mut(input.parse()?))
+            } else {
+                Err(lookahead.error())
+            }
+        }
+    }
+
     #[cfg(feature = "full")]
     impl RangeLimits {
         pub(crate) fn parse_obsolete(input: ParseStream) -> Result<Self> {
@@ -3124,6 +3981,18 @@ pub(crate) mod printing {
         }
     }
 
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for ExprRawAddr {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            outer_attrs_to_tokens(&self.attrs, tokens);
+            self.and_token.to_tokens(tokens);
+            self.raw.to_tokens(tokens);
+            self.mutability.to_tokens(tokens);
+            self.expr.to_tokens(tokens);
+        }
+    }
+
     #[cfg(feature = "full")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for ExprReference {
@@ -3148,6 +4017,16 @@ pub(crate) mod printing {
         }
     }
 
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for ExprBecome {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            outer_attrs_to_tokens(&self.attrs, tokens);
+            self.become_token.to_tokens(tokens);
+            self.expr.to_tokens(tokens);
+        }
+    }
+
     #[cfg(feature = "full")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for ExprReturn {
@@ -3326,4 +4205,15 @@ pub(crate) mod printing {
             }
         }
     }
+
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for PointerMutability {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                PointerMutability::Const(const_token) => const_token.to_tokens(tokens),
+                PointerMutability::Mut(mut_token) => mut_token.to_tokens(tokens),
+            }
+        }
+    }
 }
@@ -80,6 +80,52 @@ pub trait IdentExt: Sized + private::Sealed {
     /// }
     /// ```
     fn unraw(&self) -> Ident;
+
+    /// Returns true if this identifier is written with the raw marker `r#`.
+    ///
+    ///   - is_raw(`x`) = false
+    ///   - is_raw(`move`) = false
+    ///   - is_raw(`r#move`) = true
+    ///   - is_raw(`r#x`) = true
+    ///
+    /// This is useful for preserving the way an identifier was written, since
+    /// comparing it against a string such as `"move"` is false for `r#move`.
+    fn is_raw(&self) -> bool;
+
+    /// Creates an identifier consisting of `prefix` followed by this
+    /// identifier, with the same span as this identifier.
+    ///
+    /// Keeping the span means that errors about the generated identifier,
+    /// for example a name collision or an unresolved method, point back at
+    /// the identifier it was derived from. If this identifier is raw, the
+    /// result is raw too: `get_` prepended to `r#type` gives `r#get_type`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is not a valid identifier, such as for a prefix
+    /// starting with a digit or containing punctuation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Ident};
+    /// use syn::ext::IdentExt;
+    ///
+    /// let field: Ident = parse_quote!(name);
+    /// assert_eq!(field.prepend("get_"), "get_name");
+    /// assert_eq!(field.append("_mut"), "name_mut");
+    /// ```
+    fn prepend(&self, prefix: &str) -> Ident;
+
+    /// Creates an identifier consisting of this identifier followed by
+    /// `suffix`, with the same span as this identifier.
+    ///
+    /// See [`prepend`][IdentExt::prepend] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is not a valid identifier.
+    fn append(&self, suffix: &str) -> Ident;
 }
 
 impl IdentExt for Ident {
@@ -98,6 +144,35 @@ impl IdentExt for Ident {
             self.clone()
         }
     }
+
+    fn is_raw(&self) -> bool {
+        self.to_string().starts_with("r#")
+    }
+
+    fn prepend(&self, prefix: &str) -> Ident {
+        let string = self.to_string();
+        match string.strip_prefix("r#") {
+            Some(name) => affixed(&format!("{}{}", prefix, name), true, self),
+            None => affixed(&format!("{}{}", prefix, string), false, self),
+        }
+    }
+
+    fn append(&self, suffix: &str) -> Ident {
+        let string = self.to_string();
+        match string.strip_prefix("r#") {
+            Some(name) => affixed(&format!("{}{}", name, suffix), true, self),
+            None => affixed(&format!("{}{}", string, suffix), false, self),
+        }
+    }
+}
+
+fn affixed(name: &str, raw: bool, original: &Ident) -> Ident {
+    // These keywords cannot be written as raw identifiers.
+    if raw && !matches!(name, "_" | "crate" | "self" | "Self" | "super") {
+        Ident::new_raw(name, original.span())
+    } else {
+        Ident::new(name, original.span())
+    }
 }
 
 impl Peek for private::PeekFn {
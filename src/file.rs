@@ -1,4 +1,14 @@
 use super::*;
+#[cfg(feature = "parsing")]
+use crate::buffer::{Cursor, TokenBuffer};
+#[cfg(feature = "parsing")]
+use crate::error::{Error, Result};
+#[cfg(feature = "parsing")]
+use crate::parse::{self, Parse};
+#[cfg(feature = "parsing")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parsing")]
+use std::mem;
 
 ast_struct! {
     /// A complete file of Rust source code.
@@ -79,12 +89,109 @@ ast_struct! {
     /// ```
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
     pub struct File {
+        /// The shebang line of the file, such as `#!/usr/bin/env rustx`,
+        /// without its line ending.
+        ///
+        /// A shebang is not a token, so it is not part of the `ToTokens`
+        /// representation of a `File`. Source code that round-trips the
+        /// shebang needs to write it out on a line of its own ahead of the
+        /// tokens.
         pub shebang: Option<String>,
         pub attrs: Vec<Attribute>,
         pub items: Vec<Item>,
     }
 }
 
+/// An iterator over the items of a file of Rust code, yielding each item as
+/// it is parsed.
+///
+/// This struct is created by [`parse_file_items`]. Iteration ends after the
+/// first error.
+#[cfg(feature = "parsing")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "full"))))]
+pub struct FileItems {
+    shebang: Option<String>,
+    attrs: Vec<Attribute>,
+    // Points into the entries of `_buffer`, which live in a heap allocation
+    // that does not move along with the TokenBuffer and is not freed until
+    // this struct is dropped. The cursor never escapes this struct with a
+    // lifetime longer than a borrow of it.
+    cursor: Cursor<'static>,
+    _buffer: TokenBuffer,
+    error: Option<Error>,
+    finished: bool,
+}
+
+#[cfg(feature = "parsing")]
+impl FileItems {
+    pub(crate) fn new(shebang: Option<String>, tokens: Result<TokenStream>) -> Self {
+        let (buffer, error) = match tokens {
+            Ok(tokens) => (TokenBuffer::new2(tokens), None),
+            Err(err) => (TokenBuffer::new2(TokenStream::new()), Some(err)),
+        };
+        // See comment on `cursor` in the struct definition.
+        let cursor = unsafe { mem::transmute::<Cursor, Cursor<'static>>(buffer.begin()) };
+        let mut items = FileItems {
+            shebang,
+            attrs: Vec::new(),
+            cursor,
+            _buffer: buffer,
+            error,
+            finished: false,
+        };
+        if items.error.is_none() {
+            match parse::parse_prefix(items.cursor, Attribute::parse_inner) {
+                Ok((attrs, rest)) => {
+                    items.attrs = attrs;
+                    items.cursor = rest;
+                }
+                Err(err) => items.error = Some(err),
+            }
+        }
+        items
+    }
+
+    /// The shebang line of the file, such as `#!/usr/bin/env rustx`.
+    pub fn shebang(&self) -> Option<&str> {
+        self.shebang.as_deref()
+    }
+
+    /// The inner attributes at the top of the file, such as
+    /// `#![allow(dead_code)]`.
+    pub fn attrs(&self) -> &[Attribute] {
+        &self.attrs
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Iterator for FileItems {
+    type Item = Result<Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if let Some(err) = self.error.take() {
+            self.finished = true;
+            return Some(Err(err));
+        }
+        if self.cursor.eof() {
+            self.finished = true;
+            return None;
+        }
+        match parse::parse_prefix(self.cursor, Item::parse) {
+            Ok((item, rest)) => {
+                self.cursor = rest;
+                Some(Ok(item))
+            }
+            Err(err) => {
+                self.finished = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
@@ -118,6 +225,7 @@ mod printing {
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for File {
         fn to_tokens(&self, tokens: &mut TokenStream) {
+            // The shebang cannot be represented in a TokenStream.
             tokens.append_all(self.attrs.inner());
             tokens.append_all(&self.items);
         }
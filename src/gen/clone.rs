@@ -39,6 +39,100 @@ impl Clone for Arm {
         }
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AsmArg {
+    fn clone(&self) -> Self {
+        match self {
+            AsmArg::Template(v0) => AsmArg::Template(v0.clone()),
+            AsmArg::Operand(v0) => AsmArg::Operand(v0.clone()),
+            AsmArg::ClobberAbi(v0) => AsmArg::ClobberAbi(v0.clone()),
+            AsmArg::Options(v0) => AsmArg::Options(v0.clone()),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AsmClobberAbi {
+    fn clone(&self) -> Self {
+        AsmClobberAbi {
+            clobber_abi_token: self.clobber_abi_token.clone(),
+            paren_token: self.paren_token.clone(),
+            abis: self.abis.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Copy for AsmDirection {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AsmDirection {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AsmOperand {
+    fn clone(&self) -> Self {
+        AsmOperand {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AsmOperandKind {
+    fn clone(&self) -> Self {
+        match self {
+            AsmOperandKind::Reg(v0) => AsmOperandKind::Reg(v0.clone()),
+            AsmOperandKind::Const(v0, v1) => {
+                AsmOperandKind::Const(v0.clone(), v1.clone())
+            }
+            AsmOperandKind::Sym(v0, v1) => AsmOperandKind::Sym(v0.clone(), v1.clone()),
+            AsmOperandKind::Label(v0, v1) => {
+                AsmOperandKind::Label(v0.clone(), v1.clone())
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AsmOptions {
+    fn clone(&self) -> Self {
+        AsmOptions {
+            options_token: self.options_token.clone(),
+            paren_token: self.paren_token.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AsmReg {
+    fn clone(&self) -> Self {
+        match self {
+            AsmReg::Class(v0) => AsmReg::Class(v0.clone()),
+            AsmReg::Explicit(v0) => AsmReg::Explicit(v0.clone()),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AsmRegOperand {
+    fn clone(&self) -> Self {
+        AsmRegOperand {
+            direction: self.direction.clone(),
+            direction_span: self.direction_span.clone(),
+            paren_token: self.paren_token.clone(),
+            reg: self.reg.clone(),
+            expr: self.expr.clone(),
+            out_expr: self.out_expr.clone(),
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for AssocConst {
@@ -236,6 +330,8 @@ impl Clone for Expr {
             Expr::Async(v0) => Expr::Async(v0.clone()),
             #[cfg(feature = "full")]
             Expr::Await(v0) => Expr::Await(v0.clone()),
+            #[cfg(feature = "full")]
+            Expr::Become(v0) => Expr::Become(v0.clone()),
             Expr::Binary(v0) => Expr::Binary(v0.clone()),
             #[cfg(feature = "full")]
             Expr::Block(v0) => Expr::Block(v0.clone()),
@@ -275,6 +371,8 @@ impl Clone for Expr {
             #[cfg(feature = "full")]
             Expr::Range(v0) => Expr::Range(v0.clone()),
             #[cfg(feature = "full")]
+            Expr::RawAddr(v0) => Expr::RawAddr(v0.clone()),
+            #[cfg(feature = "full")]
             Expr::Reference(v0) => Expr::Reference(v0.clone()),
             #[cfg(feature = "full")]
             Expr::Repeat(v0) => Expr::Repeat(v0.clone()),
@@ -348,6 +446,17 @@ impl Clone for ExprAwait {
         }
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for ExprBecome {
+    fn clone(&self) -> Self {
+        ExprBecome {
+            attrs: self.attrs.clone(),
+            become_token: self.become_token.clone(),
+            expr: self.expr.clone(),
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprBinary {
@@ -630,6 +739,19 @@ impl Clone for ExprRange {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for ExprRawAddr {
+    fn clone(&self) -> Self {
+        ExprRawAddr {
+            attrs: self.attrs.clone(),
+            and_token: self.and_token.clone(),
+            raw: self.raw.clone(),
+            mutability: self.mutability.clone(),
+            expr: self.expr.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprReference {
     fn clone(&self) -> Self {
         ExprReference {
@@ -769,6 +891,7 @@ impl Clone for Field {
             ident: self.ident.clone(),
             colon_token: self.colon_token.clone(),
             ty: self.ty.clone(),
+            default: self.default.clone(),
         }
     }
 }
@@ -1052,6 +1175,15 @@ impl Clone for Index {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for InlineAsm {
+    fn clone(&self) -> Self {
+        InlineAsm {
+            args: self.args.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Item {
     fn clone(&self) -> Self {
         match self {
@@ -1637,6 +1769,16 @@ impl Clone for PathSegment {
         }
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for PointerMutability {
+    fn clone(&self) -> Self {
+        match self {
+            PointerMutability::Const(v0) => PointerMutability::Const(v0.clone()),
+            PointerMutability::Mut(v0) => PointerMutability::Mut(v0.clone()),
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for PredicateLifetime {
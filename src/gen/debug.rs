@@ -44,6 +44,146 @@ impl Debug for Arm {
         formatter.finish()
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AsmArg {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("AsmArg::")?;
+        match self {
+            AsmArg::Template(v0) => {
+                let mut formatter = formatter.debug_tuple("Template");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AsmArg::Operand(v0) => {
+                let mut formatter = formatter.debug_tuple("Operand");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AsmArg::ClobberAbi(v0) => {
+                let mut formatter = formatter.debug_tuple("ClobberAbi");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AsmArg::Options(v0) => {
+                let mut formatter = formatter.debug_tuple("Options");
+                formatter.field(v0);
+                formatter.finish()
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AsmClobberAbi {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("AsmClobberAbi");
+        formatter.field("clobber_abi_token", &self.clobber_abi_token);
+        formatter.field("paren_token", &self.paren_token);
+        formatter.field("abis", &self.abis);
+        formatter.finish()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AsmDirection {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("AsmDirection::")?;
+        match self {
+            AsmDirection::In => formatter.write_str("In"),
+            AsmDirection::Out => formatter.write_str("Out"),
+            AsmDirection::LateOut => formatter.write_str("LateOut"),
+            AsmDirection::InOut => formatter.write_str("InOut"),
+            AsmDirection::InLateOut => formatter.write_str("InLateOut"),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AsmOperand {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("AsmOperand");
+        formatter.field("name", &self.name);
+        formatter.field("kind", &self.kind);
+        formatter.finish()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AsmOperandKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("AsmOperandKind::")?;
+        match self {
+            AsmOperandKind::Reg(v0) => {
+                let mut formatter = formatter.debug_tuple("Reg");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AsmOperandKind::Const(v0, v1) => {
+                let mut formatter = formatter.debug_tuple("Const");
+                formatter.field(v0);
+                formatter.field(v1);
+                formatter.finish()
+            }
+            AsmOperandKind::Sym(v0, v1) => {
+                let mut formatter = formatter.debug_tuple("Sym");
+                formatter.field(v0);
+                formatter.field(v1);
+                formatter.finish()
+            }
+            AsmOperandKind::Label(v0, v1) => {
+                let mut formatter = formatter.debug_tuple("Label");
+                formatter.field(v0);
+                formatter.field(v1);
+                formatter.finish()
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AsmOptions {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("AsmOptions");
+        formatter.field("options_token", &self.options_token);
+        formatter.field("paren_token", &self.paren_token);
+        formatter.field("options", &self.options);
+        formatter.finish()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AsmReg {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("AsmReg::")?;
+        match self {
+            AsmReg::Class(v0) => {
+                let mut formatter = formatter.debug_tuple("Class");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            AsmReg::Explicit(v0) => {
+                let mut formatter = formatter.debug_tuple("Explicit");
+                formatter.field(v0);
+                formatter.finish()
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for AsmRegOperand {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("AsmRegOperand");
+        formatter.field("direction", &self.direction);
+        formatter.field("direction_span", &self.direction_span);
+        formatter.field("paren_token", &self.paren_token);
+        formatter.field("reg", &self.reg);
+        formatter.field("expr", &self.expr);
+        formatter.field("out_expr", &self.out_expr);
+        formatter.finish()
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for AssocConst {
@@ -402,6 +542,8 @@ impl Debug for Expr {
             Expr::Async(v0) => v0.debug(formatter, "Async"),
             #[cfg(feature = "full")]
             Expr::Await(v0) => v0.debug(formatter, "Await"),
+            #[cfg(feature = "full")]
+            Expr::Become(v0) => v0.debug(formatter, "Become"),
             Expr::Binary(v0) => v0.debug(formatter, "Binary"),
             #[cfg(feature = "full")]
             Expr::Block(v0) => v0.debug(formatter, "Block"),
@@ -441,6 +583,8 @@ impl Debug for Expr {
             #[cfg(feature = "full")]
             Expr::Range(v0) => v0.debug(formatter, "Range"),
             #[cfg(feature = "full")]
+            Expr::RawAddr(v0) => v0.debug(formatter, "RawAddr"),
+            #[cfg(feature = "full")]
             Expr::Reference(v0) => v0.debug(formatter, "Reference"),
             #[cfg(feature = "full")]
             Expr::Repeat(v0) => v0.debug(formatter, "Repeat"),
@@ -538,6 +682,22 @@ impl Debug for ExprAwait {
         self.debug(formatter, "ExprAwait")
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for ExprBecome {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        impl ExprBecome {
+            fn debug(&self, formatter: &mut fmt::Formatter, name: &str) -> fmt::Result {
+                let mut formatter = formatter.debug_struct(name);
+                formatter.field("attrs", &self.attrs);
+                formatter.field("become_token", &self.become_token);
+                formatter.field("expr", &self.expr);
+                formatter.finish()
+            }
+        }
+        self.debug(formatter, "ExprBecome")
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprBinary {
@@ -935,6 +1095,24 @@ impl Debug for ExprRange {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for ExprRawAddr {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        impl ExprRawAddr {
+            fn debug(&self, formatter: &mut fmt::Formatter, name: &str) -> fmt::Result {
+                let mut formatter = formatter.debug_struct(name);
+                formatter.field("attrs", &self.attrs);
+                formatter.field("and_token", &self.and_token);
+                formatter.field("raw", &self.raw);
+                formatter.field("mutability", &self.mutability);
+                formatter.field("expr", &self.expr);
+                formatter.finish()
+            }
+        }
+        self.debug(formatter, "ExprRawAddr")
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for ExprReference {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         impl ExprReference {
@@ -1129,6 +1307,7 @@ impl Debug for Field {
         formatter.field("ident", &self.ident);
         formatter.field("colon_token", &self.colon_token);
         formatter.field("ty", &self.ty);
+        formatter.field("default", &self.default);
         formatter.finish()
     }
 }
@@ -1521,6 +1700,15 @@ impl Debug for Index {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for InlineAsm {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("InlineAsm");
+        formatter.field("args", &self.args);
+        formatter.finish()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for Item {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("Item::")?;
@@ -2303,6 +2491,25 @@ impl Debug for PathSegment {
         formatter.finish()
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for PointerMutability {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("PointerMutability::")?;
+        match self {
+            PointerMutability::Const(v0) => {
+                let mut formatter = formatter.debug_tuple("Const");
+                formatter.field(v0);
+                formatter.finish()
+            }
+            PointerMutability::Mut(v0) => {
+                let mut formatter = formatter.debug_tuple("Mut");
+                formatter.field(v0);
+                formatter.finish()
+            }
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Debug for PredicateLifetime {
@@ -2484,6 +2691,12 @@ impl Debug for TraitBoundModifier {
                 formatter.field(v0);
                 formatter.finish()
             }
+            TraitBoundModifier::MaybeConst(v0, v1) => {
+                let mut formatter = formatter.debug_tuple("MaybeConst");
+                formatter.field(v0);
+                formatter.field(v1);
+                formatter.finish()
+            }
         }
     }
 }
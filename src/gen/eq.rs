@@ -35,6 +35,117 @@ impl PartialEq for Arm {
             && self.body == other.body && self.comma == other.comma
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AsmArg {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AsmArg {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AsmArg::Template(self0), AsmArg::Template(other0)) => self0 == other0,
+            (AsmArg::Operand(self0), AsmArg::Operand(other0)) => self0 == other0,
+            (AsmArg::ClobberAbi(self0), AsmArg::ClobberAbi(other0)) => self0 == other0,
+            (AsmArg::Options(self0), AsmArg::Options(other0)) => self0 == other0,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AsmClobberAbi {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AsmClobberAbi {
+    fn eq(&self, other: &Self) -> bool {
+        self.clobber_abi_token == other.clobber_abi_token && self.abis == other.abis
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AsmDirection {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AsmDirection {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AsmDirection::In, AsmDirection::In) => true,
+            (AsmDirection::Out, AsmDirection::Out) => true,
+            (AsmDirection::LateOut, AsmDirection::LateOut) => true,
+            (AsmDirection::InOut, AsmDirection::InOut) => true,
+            (AsmDirection::InLateOut, AsmDirection::InLateOut) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AsmOperand {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AsmOperand {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.kind == other.kind
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AsmOperandKind {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AsmOperandKind {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AsmOperandKind::Reg(self0), AsmOperandKind::Reg(other0)) => self0 == other0,
+            (AsmOperandKind::Const(_, self1), AsmOperandKind::Const(_, other1)) => {
+                self1 == other1
+            }
+            (AsmOperandKind::Sym(self0, self1), AsmOperandKind::Sym(other0, other1)) => {
+                self0 == other0 && self1 == other1
+            }
+            (
+                AsmOperandKind::Label(self0, self1),
+                AsmOperandKind::Label(other0, other1),
+            ) => self0 == other0 && self1 == other1,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AsmOptions {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AsmOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.options_token == other.options_token && self.options == other.options
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AsmReg {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AsmReg {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AsmReg::Class(self0), AsmReg::Class(other0)) => self0 == other0,
+            (AsmReg::Explicit(self0), AsmReg::Explicit(other0)) => self0 == other0,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AsmRegOperand {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AsmRegOperand {
+    fn eq(&self, other: &Self) -> bool {
+        self.direction == other.direction && self.reg == other.reg
+            && self.expr == other.expr && self.out_expr == other.out_expr
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for AssocConst {}
@@ -255,6 +366,8 @@ impl PartialEq for Expr {
             (Expr::Async(self0), Expr::Async(other0)) => self0 == other0,
             #[cfg(feature = "full")]
             (Expr::Await(self0), Expr::Await(other0)) => self0 == other0,
+            #[cfg(feature = "full")]
+            (Expr::Become(self0), Expr::Become(other0)) => self0 == other0,
             (Expr::Binary(self0), Expr::Binary(other0)) => self0 == other0,
             #[cfg(feature = "full")]
             (Expr::Block(self0), Expr::Block(other0)) => self0 == other0,
@@ -294,6 +407,8 @@ impl PartialEq for Expr {
             #[cfg(feature = "full")]
             (Expr::Range(self0), Expr::Range(other0)) => self0 == other0,
             #[cfg(feature = "full")]
+            (Expr::RawAddr(self0), Expr::RawAddr(other0)) => self0 == other0,
+            #[cfg(feature = "full")]
             (Expr::Reference(self0), Expr::Reference(other0)) => self0 == other0,
             #[cfg(feature = "full")]
             (Expr::Repeat(self0), Expr::Repeat(other0)) => self0 == other0,
@@ -362,6 +477,16 @@ impl PartialEq for ExprAwait {
         self.attrs == other.attrs && self.base == other.base
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for ExprBecome {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for ExprBecome {
+    fn eq(&self, other: &Self) -> bool {
+        self.attrs == other.attrs && self.expr == other.expr
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprBinary {}
@@ -607,6 +732,17 @@ impl PartialEq for ExprRange {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for ExprRawAddr {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for ExprRawAddr {
+    fn eq(&self, other: &Self) -> bool {
+        self.attrs == other.attrs && self.mutability == other.mutability
+            && self.expr == other.expr
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprReference {}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
@@ -729,6 +865,7 @@ impl PartialEq for Field {
         self.attrs == other.attrs && self.vis == other.vis
             && self.mutability == other.mutability && self.ident == other.ident
             && self.colon_token == other.colon_token && self.ty == other.ty
+            && self.default == other.default
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
@@ -1025,6 +1162,16 @@ impl PartialEq for ImplRestriction {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for InlineAsm {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for InlineAsm {
+    fn eq(&self, other: &Self) -> bool {
+        self.args == other.args
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Item {}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
@@ -1568,6 +1715,20 @@ impl PartialEq for PathSegment {
         self.ident == other.ident && self.arguments == other.arguments
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for PointerMutability {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for PointerMutability {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PointerMutability::Const(_), PointerMutability::Const(_)) => true,
+            (PointerMutability::Mut(_), PointerMutability::Mut(_)) => true,
+            _ => false,
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for PredicateLifetime {}
@@ -1718,6 +1879,10 @@ impl PartialEq for TraitBoundModifier {
         match (self, other) {
             (TraitBoundModifier::None, TraitBoundModifier::None) => true,
             (TraitBoundModifier::Maybe(_), TraitBoundModifier::Maybe(_)) => true,
+            (
+                TraitBoundModifier::MaybeConst(_, _),
+                TraitBoundModifier::MaybeConst(_, _),
+            ) => true,
             _ => false,
         }
     }
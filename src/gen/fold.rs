@@ -3,12 +3,17 @@
 
 #![allow(unreachable_code, unused_variables)]
 #![allow(clippy::match_wildcard_for_single_variants)]
+#![allow(clippy::needless_match)]
 #[cfg(any(feature = "full", feature = "derive"))]
 use crate::gen::helper::fold::*;
 #[cfg(any(feature = "full", feature = "derive"))]
 use crate::token::{Brace, Bracket, Group, Paren};
+#[cfg(feature = "printing")]
+use crate::tt::TokenStreamHelper;
 use crate::*;
 use proc_macro2::Span;
+#[cfg(feature = "printing")]
+use quote::ToTokens;
 #[cfg(feature = "full")]
 macro_rules! full {
     ($e:expr) => {
@@ -42,6 +47,38 @@ pub trait Fold {
     fn fold_arm(&mut self, i: Arm) -> Arm {
         fold_arm(self, i)
     }
+    #[cfg(feature = "full")]
+    fn fold_asm_arg(&mut self, i: AsmArg) -> AsmArg {
+        fold_asm_arg(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_clobber_abi(&mut self, i: AsmClobberAbi) -> AsmClobberAbi {
+        fold_asm_clobber_abi(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_direction(&mut self, i: AsmDirection) -> AsmDirection {
+        fold_asm_direction(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_operand(&mut self, i: AsmOperand) -> AsmOperand {
+        fold_asm_operand(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_operand_kind(&mut self, i: AsmOperandKind) -> AsmOperandKind {
+        fold_asm_operand_kind(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_options(&mut self, i: AsmOptions) -> AsmOptions {
+        fold_asm_options(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_reg(&mut self, i: AsmReg) -> AsmReg {
+        fold_asm_reg(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_reg_operand(&mut self, i: AsmRegOperand) -> AsmRegOperand {
+        fold_asm_reg_operand(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_assoc_const(&mut self, i: AssocConst) -> AssocConst {
         fold_assoc_const(self, i)
@@ -126,6 +163,10 @@ pub trait Fold {
     fn fold_expr_await(&mut self, i: ExprAwait) -> ExprAwait {
         fold_expr_await(self, i)
     }
+    #[cfg(feature = "full")]
+    fn fold_expr_become(&mut self, i: ExprBecome) -> ExprBecome {
+        fold_expr_become(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_expr_binary(&mut self, i: ExprBinary) -> ExprBinary {
         fold_expr_binary(self, i)
@@ -196,563 +237,1452 @@ pub trait Fold {
     }
     #[cfg(feature = "full")]
     fn fold_expr_macro(&mut self, i: ExprMacro) -> ExprMacro {
-        fold_expr_macro(self, i)
+        fold_expr_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_match(&mut self, i: ExprMatch) -> ExprMatch {
+        fold_expr_match(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_method_call(&mut self, i: ExprMethodCall) -> ExprMethodCall {
+        fold_expr_method_call(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_paren(&mut self, i: ExprParen) -> ExprParen {
+        fold_expr_paren(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_path(&mut self, i: ExprPath) -> ExprPath {
+        fold_expr_path(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_range(&mut self, i: ExprRange) -> ExprRange {
+        fold_expr_range(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_raw_addr(&mut self, i: ExprRawAddr) -> ExprRawAddr {
+        fold_expr_raw_addr(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_reference(&mut self, i: ExprReference) -> ExprReference {
+        fold_expr_reference(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_repeat(&mut self, i: ExprRepeat) -> ExprRepeat {
+        fold_expr_repeat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_return(&mut self, i: ExprReturn) -> ExprReturn {
+        fold_expr_return(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_struct(&mut self, i: ExprStruct) -> ExprStruct {
+        fold_expr_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_try(&mut self, i: ExprTry) -> ExprTry {
+        fold_expr_try(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_try_block(&mut self, i: ExprTryBlock) -> ExprTryBlock {
+        fold_expr_try_block(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_tuple(&mut self, i: ExprTuple) -> ExprTuple {
+        fold_expr_tuple(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_unary(&mut self, i: ExprUnary) -> ExprUnary {
+        fold_expr_unary(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_unsafe(&mut self, i: ExprUnsafe) -> ExprUnsafe {
+        fold_expr_unsafe(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_while(&mut self, i: ExprWhile) -> ExprWhile {
+        fold_expr_while(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_yield(&mut self, i: ExprYield) -> ExprYield {
+        fold_expr_yield(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_field(&mut self, i: Field) -> Field {
+        fold_field(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_field_mutability(&mut self, i: FieldMutability) -> FieldMutability {
+        fold_field_mutability(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_field_pat(&mut self, i: FieldPat) -> FieldPat {
+        fold_field_pat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_field_value(&mut self, i: FieldValue) -> FieldValue {
+        fold_field_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_fields(&mut self, i: Fields) -> Fields {
+        fold_fields(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_fields_named(&mut self, i: FieldsNamed) -> FieldsNamed {
+        fold_fields_named(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_fields_unnamed(&mut self, i: FieldsUnnamed) -> FieldsUnnamed {
+        fold_fields_unnamed(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_file(&mut self, i: File) -> File {
+        fold_file(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_fn_arg(&mut self, i: FnArg) -> FnArg {
+        fold_fn_arg(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item(&mut self, i: ForeignItem) -> ForeignItem {
+        fold_foreign_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item_fn(&mut self, i: ForeignItemFn) -> ForeignItemFn {
+        fold_foreign_item_fn(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item_macro(&mut self, i: ForeignItemMacro) -> ForeignItemMacro {
+        fold_foreign_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item_static(&mut self, i: ForeignItemStatic) -> ForeignItemStatic {
+        fold_foreign_item_static(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item_type(&mut self, i: ForeignItemType) -> ForeignItemType {
+        fold_foreign_item_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_generic_argument(&mut self, i: GenericArgument) -> GenericArgument {
+        fold_generic_argument(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_generic_param(&mut self, i: GenericParam) -> GenericParam {
+        fold_generic_param(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_generics(&mut self, i: Generics) -> Generics {
+        fold_generics(self, i)
+    }
+    fn fold_ident(&mut self, i: Ident) -> Ident {
+        fold_ident(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item(&mut self, i: ImplItem) -> ImplItem {
+        fold_impl_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item_const(&mut self, i: ImplItemConst) -> ImplItemConst {
+        fold_impl_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item_fn(&mut self, i: ImplItemFn) -> ImplItemFn {
+        fold_impl_item_fn(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item_macro(&mut self, i: ImplItemMacro) -> ImplItemMacro {
+        fold_impl_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item_type(&mut self, i: ImplItemType) -> ImplItemType {
+        fold_impl_item_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_restriction(&mut self, i: ImplRestriction) -> ImplRestriction {
+        fold_impl_restriction(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_index(&mut self, i: Index) -> Index {
+        fold_index(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_inline_asm(&mut self, i: InlineAsm) -> InlineAsm {
+        fold_inline_asm(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item(&mut self, i: Item) -> Item {
+        fold_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_const(&mut self, i: ItemConst) -> ItemConst {
+        fold_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_enum(&mut self, i: ItemEnum) -> ItemEnum {
+        fold_item_enum(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_extern_crate(&mut self, i: ItemExternCrate) -> ItemExternCrate {
+        fold_item_extern_crate(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_fn(&mut self, i: ItemFn) -> ItemFn {
+        fold_item_fn(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_foreign_mod(&mut self, i: ItemForeignMod) -> ItemForeignMod {
+        fold_item_foreign_mod(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_impl(&mut self, i: ItemImpl) -> ItemImpl {
+        fold_item_impl(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_macro(&mut self, i: ItemMacro) -> ItemMacro {
+        fold_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_mod(&mut self, i: ItemMod) -> ItemMod {
+        fold_item_mod(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_static(&mut self, i: ItemStatic) -> ItemStatic {
+        fold_item_static(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_struct(&mut self, i: ItemStruct) -> ItemStruct {
+        fold_item_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_trait(&mut self, i: ItemTrait) -> ItemTrait {
+        fold_item_trait(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_trait_alias(&mut self, i: ItemTraitAlias) -> ItemTraitAlias {
+        fold_item_trait_alias(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_type(&mut self, i: ItemType) -> ItemType {
+        fold_item_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_union(&mut self, i: ItemUnion) -> ItemUnion {
+        fold_item_union(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_use(&mut self, i: ItemUse) -> ItemUse {
+        fold_item_use(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_label(&mut self, i: Label) -> Label {
+        fold_label(self, i)
+    }
+    fn fold_lifetime(&mut self, i: Lifetime) -> Lifetime {
+        fold_lifetime(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_lifetime_param(&mut self, i: LifetimeParam) -> LifetimeParam {
+        fold_lifetime_param(self, i)
+    }
+    fn fold_lit(&mut self, i: Lit) -> Lit {
+        fold_lit(self, i)
+    }
+    fn fold_lit_bool(&mut self, i: LitBool) -> LitBool {
+        fold_lit_bool(self, i)
+    }
+    fn fold_lit_byte(&mut self, i: LitByte) -> LitByte {
+        fold_lit_byte(self, i)
+    }
+    fn fold_lit_byte_str(&mut self, i: LitByteStr) -> LitByteStr {
+        fold_lit_byte_str(self, i)
+    }
+    fn fold_lit_char(&mut self, i: LitChar) -> LitChar {
+        fold_lit_char(self, i)
+    }
+    fn fold_lit_float(&mut self, i: LitFloat) -> LitFloat {
+        fold_lit_float(self, i)
+    }
+    fn fold_lit_int(&mut self, i: LitInt) -> LitInt {
+        fold_lit_int(self, i)
+    }
+    fn fold_lit_str(&mut self, i: LitStr) -> LitStr {
+        fold_lit_str(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_local(&mut self, i: Local) -> Local {
+        fold_local(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_local_init(&mut self, i: LocalInit) -> LocalInit {
+        fold_local_init(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_macro(&mut self, i: Macro) -> Macro {
+        fold_macro(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_macro_delimiter(&mut self, i: MacroDelimiter) -> MacroDelimiter {
+        fold_macro_delimiter(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_member(&mut self, i: Member) -> Member {
+        fold_member(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta(&mut self, i: Meta) -> Meta {
+        fold_meta(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta_list(&mut self, i: MetaList) -> MetaList {
+        fold_meta_list(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta_name_value(&mut self, i: MetaNameValue) -> MetaNameValue {
+        fold_meta_name_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_parenthesized_generic_arguments(
+        &mut self,
+        i: ParenthesizedGenericArguments,
+    ) -> ParenthesizedGenericArguments {
+        fold_parenthesized_generic_arguments(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat(&mut self, i: Pat) -> Pat {
+        fold_pat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_ident(&mut self, i: PatIdent) -> PatIdent {
+        fold_pat_ident(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_or(&mut self, i: PatOr) -> PatOr {
+        fold_pat_or(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_paren(&mut self, i: PatParen) -> PatParen {
+        fold_pat_paren(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_reference(&mut self, i: PatReference) -> PatReference {
+        fold_pat_reference(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_rest(&mut self, i: PatRest) -> PatRest {
+        fold_pat_rest(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_slice(&mut self, i: PatSlice) -> PatSlice {
+        fold_pat_slice(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_struct(&mut self, i: PatStruct) -> PatStruct {
+        fold_pat_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_tuple(&mut self, i: PatTuple) -> PatTuple {
+        fold_pat_tuple(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_tuple_struct(&mut self, i: PatTupleStruct) -> PatTupleStruct {
+        fold_pat_tuple_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_type(&mut self, i: PatType) -> PatType {
+        fold_pat_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_wild(&mut self, i: PatWild) -> PatWild {
+        fold_pat_wild(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_path(&mut self, i: Path) -> Path {
+        fold_path(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_path_arguments(&mut self, i: PathArguments) -> PathArguments {
+        fold_path_arguments(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_path_segment(&mut self, i: PathSegment) -> PathSegment {
+        fold_path_segment(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pointer_mutability(&mut self, i: PointerMutability) -> PointerMutability {
+        fold_pointer_mutability(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_predicate_lifetime(&mut self, i: PredicateLifetime) -> PredicateLifetime {
+        fold_predicate_lifetime(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_predicate_type(&mut self, i: PredicateType) -> PredicateType {
+        fold_predicate_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_qself(&mut self, i: QSelf) -> QSelf {
+        fold_qself(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_range_limits(&mut self, i: RangeLimits) -> RangeLimits {
+        fold_range_limits(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_receiver(&mut self, i: Receiver) -> Receiver {
+        fold_receiver(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_return_type(&mut self, i: ReturnType) -> ReturnType {
+        fold_return_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_signature(&mut self, i: Signature) -> Signature {
+        fold_signature(self, i)
+    }
+    fn fold_span(&mut self, i: Span) -> Span {
+        fold_span(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_static_mutability(&mut self, i: StaticMutability) -> StaticMutability {
+        fold_static_mutability(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_stmt(&mut self, i: Stmt) -> Stmt {
+        fold_stmt(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_stmt_macro(&mut self, i: StmtMacro) -> StmtMacro {
+        fold_stmt_macro(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_trait_bound(&mut self, i: TraitBound) -> TraitBound {
+        fold_trait_bound(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_trait_bound_modifier(
+        &mut self,
+        i: TraitBoundModifier,
+    ) -> TraitBoundModifier {
+        fold_trait_bound_modifier(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item(&mut self, i: TraitItem) -> TraitItem {
+        fold_trait_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item_const(&mut self, i: TraitItemConst) -> TraitItemConst {
+        fold_trait_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item_fn(&mut self, i: TraitItemFn) -> TraitItemFn {
+        fold_trait_item_fn(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item_macro(&mut self, i: TraitItemMacro) -> TraitItemMacro {
+        fold_trait_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item_type(&mut self, i: TraitItemType) -> TraitItemType {
+        fold_trait_item_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type(&mut self, i: Type) -> Type {
+        fold_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_array(&mut self, i: TypeArray) -> TypeArray {
+        fold_type_array(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_bare_fn(&mut self, i: TypeBareFn) -> TypeBareFn {
+        fold_type_bare_fn(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_group(&mut self, i: TypeGroup) -> TypeGroup {
+        fold_type_group(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_impl_trait(&mut self, i: TypeImplTrait) -> TypeImplTrait {
+        fold_type_impl_trait(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_infer(&mut self, i: TypeInfer) -> TypeInfer {
+        fold_type_infer(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_macro(&mut self, i: TypeMacro) -> TypeMacro {
+        fold_type_macro(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_never(&mut self, i: TypeNever) -> TypeNever {
+        fold_type_never(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_param(&mut self, i: TypeParam) -> TypeParam {
+        fold_type_param(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_param_bound(&mut self, i: TypeParamBound) -> TypeParamBound {
+        fold_type_param_bound(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_paren(&mut self, i: TypeParen) -> TypeParen {
+        fold_type_paren(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_path(&mut self, i: TypePath) -> TypePath {
+        fold_type_path(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_ptr(&mut self, i: TypePtr) -> TypePtr {
+        fold_type_ptr(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_reference(&mut self, i: TypeReference) -> TypeReference {
+        fold_type_reference(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_slice(&mut self, i: TypeSlice) -> TypeSlice {
+        fold_type_slice(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_trait_object(&mut self, i: TypeTraitObject) -> TypeTraitObject {
+        fold_type_trait_object(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_tuple(&mut self, i: TypeTuple) -> TypeTuple {
+        fold_type_tuple(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_un_op(&mut self, i: UnOp) -> UnOp {
+        fold_un_op(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_glob(&mut self, i: UseGlob) -> UseGlob {
+        fold_use_glob(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_group(&mut self, i: UseGroup) -> UseGroup {
+        fold_use_group(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_name(&mut self, i: UseName) -> UseName {
+        fold_use_name(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_path(&mut self, i: UsePath) -> UsePath {
+        fold_use_path(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_rename(&mut self, i: UseRename) -> UseRename {
+        fold_use_rename(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_tree(&mut self, i: UseTree) -> UseTree {
+        fold_use_tree(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_variadic(&mut self, i: Variadic) -> Variadic {
+        fold_variadic(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_variant(&mut self, i: Variant) -> Variant {
+        fold_variant(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_vis_restricted(&mut self, i: VisRestricted) -> VisRestricted {
+        fold_vis_restricted(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_visibility(&mut self, i: Visibility) -> Visibility {
+        fold_visibility(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_where_clause(&mut self, i: WhereClause) -> WhereClause {
+        fold_where_clause(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_where_predicate(&mut self, i: WherePredicate) -> WherePredicate {
+        fold_where_predicate(self, i)
+    }
+}
+/// A [`Fold`] that records whether folding changed the syntax tree.
+///
+/// Every method delegates to the wrapped fold `inner`. If `changed`
+/// is not already set, the tokens of the node are compared before
+/// and after folding and `changed` is set if they differ. Changes
+/// that only affect spans are not detected.
+///
+/// Only the node passed to a method of `TrackChanges` itself is
+/// compared, because the wrapped fold recurses into the children of
+/// that node without going through the wrapper. The granularity of
+/// the tracking is therefore chosen by the caller: folding a whole
+/// file through `fold_file` compares the file once, while folding
+/// each item through `fold_item` and resetting `changed` in between
+/// tells which of the items changed.
+///
+/// The comparison prints the node to tokens twice, which costs about
+/// as much as printing the tree, so this is worthwhile when the
+/// result is going to be tokenized or processed further only if it
+/// changed. A fold can also set `changed` itself, which skips the
+/// comparison. The few node types that have no token representation,
+/// such as `AttrStyle` and `Data`, are always reported as changed
+/// when folded through the wrapper directly.
+///
+/// # Example
+///
+/// ```
+/// // [dependencies]
+/// // syn = { version = "1.0", features = ["fold", "full"] }
+///
+/// use syn::fold::{Fold, TrackChanges};
+/// use syn::{parse_quote, Ident, ItemFn};
+///
+/// struct RenameFoo;
+///
+/// impl Fold for RenameFoo {
+///     fn fold_ident(&mut self, ident: Ident) -> Ident {
+///         if ident == "foo" {
+///             Ident::new("bar", ident.span())
+///         } else {
+///             ident
+///         }
+///     }
+/// }
+///
+/// let mut fold = TrackChanges::new(RenameFoo);
+///
+/// let item: ItemFn = parse_quote!(fn f() { baz() });
+/// let _ = fold.fold_item_fn(item);
+/// assert!(!fold.changed);
+///
+/// let item: ItemFn = parse_quote!(fn f() { foo() });
+/// let _ = fold.fold_item_fn(item);
+/// assert!(fold.changed);
+/// ```
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "fold", feature = "printing"))))]
+pub struct TrackChanges<F> {
+    pub inner: F,
+    pub changed: bool,
+}
+#[cfg(feature = "printing")]
+impl<F> TrackChanges<F> {
+    /// Wraps `inner`, with `changed` initially unset.
+    pub fn new(inner: F) -> Self {
+        TrackChanges {
+            inner,
+            changed: false,
+        }
+    }
+}
+#[cfg(feature = "printing")]
+impl<F: Fold> Fold for TrackChanges<F> {
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_abi(&mut self, i: Abi) -> Abi {
+        track_changes(self, i, F::fold_abi)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_angle_bracketed_generic_arguments(
+        &mut self,
+        i: AngleBracketedGenericArguments,
+    ) -> AngleBracketedGenericArguments {
+        track_changes(self, i, F::fold_angle_bracketed_generic_arguments)
+    }
+    #[cfg(feature = "full")]
+    fn fold_arm(&mut self, i: Arm) -> Arm {
+        track_changes(self, i, F::fold_arm)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_arg(&mut self, i: AsmArg) -> AsmArg {
+        track_changes(self, i, F::fold_asm_arg)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_clobber_abi(&mut self, i: AsmClobberAbi) -> AsmClobberAbi {
+        track_changes(self, i, F::fold_asm_clobber_abi)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_direction(&mut self, i: AsmDirection) -> AsmDirection {
+        self.changed = true;
+        self.inner.fold_asm_direction(i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_operand(&mut self, i: AsmOperand) -> AsmOperand {
+        track_changes(self, i, F::fold_asm_operand)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_operand_kind(&mut self, i: AsmOperandKind) -> AsmOperandKind {
+        track_changes(self, i, F::fold_asm_operand_kind)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_options(&mut self, i: AsmOptions) -> AsmOptions {
+        track_changes(self, i, F::fold_asm_options)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_reg(&mut self, i: AsmReg) -> AsmReg {
+        track_changes(self, i, F::fold_asm_reg)
+    }
+    #[cfg(feature = "full")]
+    fn fold_asm_reg_operand(&mut self, i: AsmRegOperand) -> AsmRegOperand {
+        track_changes(self, i, F::fold_asm_reg_operand)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_assoc_const(&mut self, i: AssocConst) -> AssocConst {
+        track_changes(self, i, F::fold_assoc_const)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_assoc_type(&mut self, i: AssocType) -> AssocType {
+        track_changes(self, i, F::fold_assoc_type)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attr_style(&mut self, i: AttrStyle) -> AttrStyle {
+        self.changed = true;
+        self.inner.fold_attr_style(i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attribute(&mut self, i: Attribute) -> Attribute {
+        track_changes(self, i, F::fold_attribute)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_bare_fn_arg(&mut self, i: BareFnArg) -> BareFnArg {
+        track_changes(self, i, F::fold_bare_fn_arg)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_bare_variadic(&mut self, i: BareVariadic) -> BareVariadic {
+        track_changes(self, i, F::fold_bare_variadic)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_bin_op(&mut self, i: BinOp) -> BinOp {
+        track_changes(self, i, F::fold_bin_op)
+    }
+    #[cfg(feature = "full")]
+    fn fold_block(&mut self, i: Block) -> Block {
+        track_changes(self, i, F::fold_block)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_bound_lifetimes(&mut self, i: BoundLifetimes) -> BoundLifetimes {
+        track_changes(self, i, F::fold_bound_lifetimes)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_const_param(&mut self, i: ConstParam) -> ConstParam {
+        track_changes(self, i, F::fold_const_param)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_constraint(&mut self, i: Constraint) -> Constraint {
+        track_changes(self, i, F::fold_constraint)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_data(&mut self, i: Data) -> Data {
+        self.changed = true;
+        self.inner.fold_data(i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_data_enum(&mut self, i: DataEnum) -> DataEnum {
+        self.changed = true;
+        self.inner.fold_data_enum(i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_data_struct(&mut self, i: DataStruct) -> DataStruct {
+        self.changed = true;
+        self.inner.fold_data_struct(i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_data_union(&mut self, i: DataUnion) -> DataUnion {
+        self.changed = true;
+        self.inner.fold_data_union(i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_derive_input(&mut self, i: DeriveInput) -> DeriveInput {
+        track_changes(self, i, F::fold_derive_input)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr(&mut self, i: Expr) -> Expr {
+        track_changes(self, i, F::fold_expr)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_array(&mut self, i: ExprArray) -> ExprArray {
+        track_changes(self, i, F::fold_expr_array)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_assign(&mut self, i: ExprAssign) -> ExprAssign {
+        track_changes(self, i, F::fold_expr_assign)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_async(&mut self, i: ExprAsync) -> ExprAsync {
+        track_changes(self, i, F::fold_expr_async)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_await(&mut self, i: ExprAwait) -> ExprAwait {
+        track_changes(self, i, F::fold_expr_await)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_become(&mut self, i: ExprBecome) -> ExprBecome {
+        track_changes(self, i, F::fold_expr_become)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_binary(&mut self, i: ExprBinary) -> ExprBinary {
+        track_changes(self, i, F::fold_expr_binary)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_block(&mut self, i: ExprBlock) -> ExprBlock {
+        track_changes(self, i, F::fold_expr_block)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_break(&mut self, i: ExprBreak) -> ExprBreak {
+        track_changes(self, i, F::fold_expr_break)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_call(&mut self, i: ExprCall) -> ExprCall {
+        track_changes(self, i, F::fold_expr_call)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_cast(&mut self, i: ExprCast) -> ExprCast {
+        track_changes(self, i, F::fold_expr_cast)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_closure(&mut self, i: ExprClosure) -> ExprClosure {
+        track_changes(self, i, F::fold_expr_closure)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_const(&mut self, i: ExprConst) -> ExprConst {
+        track_changes(self, i, F::fold_expr_const)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_continue(&mut self, i: ExprContinue) -> ExprContinue {
+        track_changes(self, i, F::fold_expr_continue)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_field(&mut self, i: ExprField) -> ExprField {
+        track_changes(self, i, F::fold_expr_field)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_for_loop(&mut self, i: ExprForLoop) -> ExprForLoop {
+        track_changes(self, i, F::fold_expr_for_loop)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_group(&mut self, i: ExprGroup) -> ExprGroup {
+        track_changes(self, i, F::fold_expr_group)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_if(&mut self, i: ExprIf) -> ExprIf {
+        track_changes(self, i, F::fold_expr_if)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_index(&mut self, i: ExprIndex) -> ExprIndex {
+        track_changes(self, i, F::fold_expr_index)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_infer(&mut self, i: ExprInfer) -> ExprInfer {
+        track_changes(self, i, F::fold_expr_infer)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_let(&mut self, i: ExprLet) -> ExprLet {
+        track_changes(self, i, F::fold_expr_let)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_lit(&mut self, i: ExprLit) -> ExprLit {
+        track_changes(self, i, F::fold_expr_lit)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_loop(&mut self, i: ExprLoop) -> ExprLoop {
+        track_changes(self, i, F::fold_expr_loop)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_macro(&mut self, i: ExprMacro) -> ExprMacro {
+        track_changes(self, i, F::fold_expr_macro)
     }
     #[cfg(feature = "full")]
     fn fold_expr_match(&mut self, i: ExprMatch) -> ExprMatch {
-        fold_expr_match(self, i)
+        track_changes(self, i, F::fold_expr_match)
     }
     #[cfg(feature = "full")]
     fn fold_expr_method_call(&mut self, i: ExprMethodCall) -> ExprMethodCall {
-        fold_expr_method_call(self, i)
+        track_changes(self, i, F::fold_expr_method_call)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_expr_paren(&mut self, i: ExprParen) -> ExprParen {
-        fold_expr_paren(self, i)
+        track_changes(self, i, F::fold_expr_paren)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_expr_path(&mut self, i: ExprPath) -> ExprPath {
-        fold_expr_path(self, i)
+        track_changes(self, i, F::fold_expr_path)
     }
     #[cfg(feature = "full")]
     fn fold_expr_range(&mut self, i: ExprRange) -> ExprRange {
-        fold_expr_range(self, i)
+        track_changes(self, i, F::fold_expr_range)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_raw_addr(&mut self, i: ExprRawAddr) -> ExprRawAddr {
+        track_changes(self, i, F::fold_expr_raw_addr)
     }
     #[cfg(feature = "full")]
     fn fold_expr_reference(&mut self, i: ExprReference) -> ExprReference {
-        fold_expr_reference(self, i)
+        track_changes(self, i, F::fold_expr_reference)
     }
     #[cfg(feature = "full")]
     fn fold_expr_repeat(&mut self, i: ExprRepeat) -> ExprRepeat {
-        fold_expr_repeat(self, i)
+        track_changes(self, i, F::fold_expr_repeat)
     }
     #[cfg(feature = "full")]
     fn fold_expr_return(&mut self, i: ExprReturn) -> ExprReturn {
-        fold_expr_return(self, i)
+        track_changes(self, i, F::fold_expr_return)
     }
     #[cfg(feature = "full")]
     fn fold_expr_struct(&mut self, i: ExprStruct) -> ExprStruct {
-        fold_expr_struct(self, i)
+        track_changes(self, i, F::fold_expr_struct)
     }
     #[cfg(feature = "full")]
     fn fold_expr_try(&mut self, i: ExprTry) -> ExprTry {
-        fold_expr_try(self, i)
+        track_changes(self, i, F::fold_expr_try)
     }
     #[cfg(feature = "full")]
     fn fold_expr_try_block(&mut self, i: ExprTryBlock) -> ExprTryBlock {
-        fold_expr_try_block(self, i)
+        track_changes(self, i, F::fold_expr_try_block)
     }
     #[cfg(feature = "full")]
     fn fold_expr_tuple(&mut self, i: ExprTuple) -> ExprTuple {
-        fold_expr_tuple(self, i)
+        track_changes(self, i, F::fold_expr_tuple)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_expr_unary(&mut self, i: ExprUnary) -> ExprUnary {
-        fold_expr_unary(self, i)
+        track_changes(self, i, F::fold_expr_unary)
     }
     #[cfg(feature = "full")]
     fn fold_expr_unsafe(&mut self, i: ExprUnsafe) -> ExprUnsafe {
-        fold_expr_unsafe(self, i)
+        track_changes(self, i, F::fold_expr_unsafe)
     }
     #[cfg(feature = "full")]
     fn fold_expr_while(&mut self, i: ExprWhile) -> ExprWhile {
-        fold_expr_while(self, i)
+        track_changes(self, i, F::fold_expr_while)
     }
     #[cfg(feature = "full")]
     fn fold_expr_yield(&mut self, i: ExprYield) -> ExprYield {
-        fold_expr_yield(self, i)
+        track_changes(self, i, F::fold_expr_yield)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_field(&mut self, i: Field) -> Field {
-        fold_field(self, i)
+        track_changes(self, i, F::fold_field)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_field_mutability(&mut self, i: FieldMutability) -> FieldMutability {
-        fold_field_mutability(self, i)
+        self.changed = true;
+        self.inner.fold_field_mutability(i)
     }
     #[cfg(feature = "full")]
     fn fold_field_pat(&mut self, i: FieldPat) -> FieldPat {
-        fold_field_pat(self, i)
+        track_changes(self, i, F::fold_field_pat)
     }
     #[cfg(feature = "full")]
     fn fold_field_value(&mut self, i: FieldValue) -> FieldValue {
-        fold_field_value(self, i)
+        track_changes(self, i, F::fold_field_value)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_fields(&mut self, i: Fields) -> Fields {
-        fold_fields(self, i)
+        track_changes(self, i, F::fold_fields)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_fields_named(&mut self, i: FieldsNamed) -> FieldsNamed {
-        fold_fields_named(self, i)
+        track_changes(self, i, F::fold_fields_named)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_fields_unnamed(&mut self, i: FieldsUnnamed) -> FieldsUnnamed {
-        fold_fields_unnamed(self, i)
+        track_changes(self, i, F::fold_fields_unnamed)
     }
     #[cfg(feature = "full")]
     fn fold_file(&mut self, i: File) -> File {
-        fold_file(self, i)
+        track_changes(self, i, F::fold_file)
     }
     #[cfg(feature = "full")]
     fn fold_fn_arg(&mut self, i: FnArg) -> FnArg {
-        fold_fn_arg(self, i)
+        track_changes(self, i, F::fold_fn_arg)
     }
     #[cfg(feature = "full")]
     fn fold_foreign_item(&mut self, i: ForeignItem) -> ForeignItem {
-        fold_foreign_item(self, i)
+        track_changes(self, i, F::fold_foreign_item)
     }
     #[cfg(feature = "full")]
     fn fold_foreign_item_fn(&mut self, i: ForeignItemFn) -> ForeignItemFn {
-        fold_foreign_item_fn(self, i)
+        track_changes(self, i, F::fold_foreign_item_fn)
     }
     #[cfg(feature = "full")]
     fn fold_foreign_item_macro(&mut self, i: ForeignItemMacro) -> ForeignItemMacro {
-        fold_foreign_item_macro(self, i)
+        track_changes(self, i, F::fold_foreign_item_macro)
     }
     #[cfg(feature = "full")]
     fn fold_foreign_item_static(&mut self, i: ForeignItemStatic) -> ForeignItemStatic {
-        fold_foreign_item_static(self, i)
+        track_changes(self, i, F::fold_foreign_item_static)
     }
     #[cfg(feature = "full")]
     fn fold_foreign_item_type(&mut self, i: ForeignItemType) -> ForeignItemType {
-        fold_foreign_item_type(self, i)
+        track_changes(self, i, F::fold_foreign_item_type)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_generic_argument(&mut self, i: GenericArgument) -> GenericArgument {
-        fold_generic_argument(self, i)
+        track_changes(self, i, F::fold_generic_argument)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_generic_param(&mut self, i: GenericParam) -> GenericParam {
-        fold_generic_param(self, i)
+        track_changes(self, i, F::fold_generic_param)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_generics(&mut self, i: Generics) -> Generics {
-        fold_generics(self, i)
+        track_changes(self, i, F::fold_generics)
     }
     fn fold_ident(&mut self, i: Ident) -> Ident {
-        fold_ident(self, i)
+        track_changes(self, i, F::fold_ident)
     }
     #[cfg(feature = "full")]
     fn fold_impl_item(&mut self, i: ImplItem) -> ImplItem {
-        fold_impl_item(self, i)
+        track_changes(self, i, F::fold_impl_item)
     }
     #[cfg(feature = "full")]
     fn fold_impl_item_const(&mut self, i: ImplItemConst) -> ImplItemConst {
-        fold_impl_item_const(self, i)
+        track_changes(self, i, F::fold_impl_item_const)
     }
     #[cfg(feature = "full")]
     fn fold_impl_item_fn(&mut self, i: ImplItemFn) -> ImplItemFn {
-        fold_impl_item_fn(self, i)
+        track_changes(self, i, F::fold_impl_item_fn)
     }
     #[cfg(feature = "full")]
     fn fold_impl_item_macro(&mut self, i: ImplItemMacro) -> ImplItemMacro {
-        fold_impl_item_macro(self, i)
+        track_changes(self, i, F::fold_impl_item_macro)
     }
     #[cfg(feature = "full")]
     fn fold_impl_item_type(&mut self, i: ImplItemType) -> ImplItemType {
-        fold_impl_item_type(self, i)
+        track_changes(self, i, F::fold_impl_item_type)
     }
     #[cfg(feature = "full")]
     fn fold_impl_restriction(&mut self, i: ImplRestriction) -> ImplRestriction {
-        fold_impl_restriction(self, i)
+        self.changed = true;
+        self.inner.fold_impl_restriction(i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_index(&mut self, i: Index) -> Index {
-        fold_index(self, i)
+        track_changes(self, i, F::fold_index)
+    }
+    #[cfg(feature = "full")]
+    fn fold_inline_asm(&mut self, i: InlineAsm) -> InlineAsm {
+        track_changes(self, i, F::fold_inline_asm)
     }
     #[cfg(feature = "full")]
     fn fold_item(&mut self, i: Item) -> Item {
-        fold_item(self, i)
+        track_changes(self, i, F::fold_item)
     }
     #[cfg(feature = "full")]
     fn fold_item_const(&mut self, i: ItemConst) -> ItemConst {
-        fold_item_const(self, i)
+        track_changes(self, i, F::fold_item_const)
     }
     #[cfg(feature = "full")]
     fn fold_item_enum(&mut self, i: ItemEnum) -> ItemEnum {
-        fold_item_enum(self, i)
+        track_changes(self, i, F::fold_item_enum)
     }
     #[cfg(feature = "full")]
     fn fold_item_extern_crate(&mut self, i: ItemExternCrate) -> ItemExternCrate {
-        fold_item_extern_crate(self, i)
+        track_changes(self, i, F::fold_item_extern_crate)
     }
     #[cfg(feature = "full")]
     fn fold_item_fn(&mut self, i: ItemFn) -> ItemFn {
-        fold_item_fn(self, i)
+        track_changes(self, i, F::fold_item_fn)
     }
     #[cfg(feature = "full")]
     fn fold_item_foreign_mod(&mut self, i: ItemForeignMod) -> ItemForeignMod {
-        fold_item_foreign_mod(self, i)
+        track_changes(self, i, F::fold_item_foreign_mod)
     }
     #[cfg(feature = "full")]
     fn fold_item_impl(&mut self, i: ItemImpl) -> ItemImpl {
-        fold_item_impl(self, i)
+        track_changes(self, i, F::fold_item_impl)
     }
     #[cfg(feature = "full")]
     fn fold_item_macro(&mut self, i: ItemMacro) -> ItemMacro {
-        fold_item_macro(self, i)
+        track_changes(self, i, F::fold_item_macro)
     }
     #[cfg(feature = "full")]
     fn fold_item_mod(&mut self, i: ItemMod) -> ItemMod {
-        fold_item_mod(self, i)
+        track_changes(self, i, F::fold_item_mod)
     }
     #[cfg(feature = "full")]
     fn fold_item_static(&mut self, i: ItemStatic) -> ItemStatic {
-        fold_item_static(self, i)
+        track_changes(self, i, F::fold_item_static)
     }
     #[cfg(feature = "full")]
     fn fold_item_struct(&mut self, i: ItemStruct) -> ItemStruct {
-        fold_item_struct(self, i)
+        track_changes(self, i, F::fold_item_struct)
     }
     #[cfg(feature = "full")]
     fn fold_item_trait(&mut self, i: ItemTrait) -> ItemTrait {
-        fold_item_trait(self, i)
+        track_changes(self, i, F::fold_item_trait)
     }
     #[cfg(feature = "full")]
     fn fold_item_trait_alias(&mut self, i: ItemTraitAlias) -> ItemTraitAlias {
-        fold_item_trait_alias(self, i)
+        track_changes(self, i, F::fold_item_trait_alias)
     }
     #[cfg(feature = "full")]
     fn fold_item_type(&mut self, i: ItemType) -> ItemType {
-        fold_item_type(self, i)
+        track_changes(self, i, F::fold_item_type)
     }
     #[cfg(feature = "full")]
     fn fold_item_union(&mut self, i: ItemUnion) -> ItemUnion {
-        fold_item_union(self, i)
+        track_changes(self, i, F::fold_item_union)
     }
     #[cfg(feature = "full")]
     fn fold_item_use(&mut self, i: ItemUse) -> ItemUse {
-        fold_item_use(self, i)
+        track_changes(self, i, F::fold_item_use)
     }
     #[cfg(feature = "full")]
     fn fold_label(&mut self, i: Label) -> Label {
-        fold_label(self, i)
+        track_changes(self, i, F::fold_label)
     }
     fn fold_lifetime(&mut self, i: Lifetime) -> Lifetime {
-        fold_lifetime(self, i)
+        track_changes(self, i, F::fold_lifetime)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_lifetime_param(&mut self, i: LifetimeParam) -> LifetimeParam {
-        fold_lifetime_param(self, i)
+        track_changes(self, i, F::fold_lifetime_param)
     }
     fn fold_lit(&mut self, i: Lit) -> Lit {
-        fold_lit(self, i)
+        track_changes(self, i, F::fold_lit)
     }
     fn fold_lit_bool(&mut self, i: LitBool) -> LitBool {
-        fold_lit_bool(self, i)
+        track_changes(self, i, F::fold_lit_bool)
     }
     fn fold_lit_byte(&mut self, i: LitByte) -> LitByte {
-        fold_lit_byte(self, i)
+        track_changes(self, i, F::fold_lit_byte)
     }
     fn fold_lit_byte_str(&mut self, i: LitByteStr) -> LitByteStr {
-        fold_lit_byte_str(self, i)
+        track_changes(self, i, F::fold_lit_byte_str)
     }
     fn fold_lit_char(&mut self, i: LitChar) -> LitChar {
-        fold_lit_char(self, i)
+        track_changes(self, i, F::fold_lit_char)
     }
     fn fold_lit_float(&mut self, i: LitFloat) -> LitFloat {
-        fold_lit_float(self, i)
+        track_changes(self, i, F::fold_lit_float)
     }
     fn fold_lit_int(&mut self, i: LitInt) -> LitInt {
-        fold_lit_int(self, i)
+        track_changes(self, i, F::fold_lit_int)
     }
     fn fold_lit_str(&mut self, i: LitStr) -> LitStr {
-        fold_lit_str(self, i)
+        track_changes(self, i, F::fold_lit_str)
     }
     #[cfg(feature = "full")]
     fn fold_local(&mut self, i: Local) -> Local {
-        fold_local(self, i)
+        track_changes(self, i, F::fold_local)
     }
     #[cfg(feature = "full")]
     fn fold_local_init(&mut self, i: LocalInit) -> LocalInit {
-        fold_local_init(self, i)
+        self.changed = true;
+        self.inner.fold_local_init(i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_macro(&mut self, i: Macro) -> Macro {
-        fold_macro(self, i)
+        track_changes(self, i, F::fold_macro)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_macro_delimiter(&mut self, i: MacroDelimiter) -> MacroDelimiter {
-        fold_macro_delimiter(self, i)
+        self.changed = true;
+        self.inner.fold_macro_delimiter(i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_member(&mut self, i: Member) -> Member {
-        fold_member(self, i)
+        track_changes(self, i, F::fold_member)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_meta(&mut self, i: Meta) -> Meta {
-        fold_meta(self, i)
+        track_changes(self, i, F::fold_meta)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_meta_list(&mut self, i: MetaList) -> MetaList {
-        fold_meta_list(self, i)
+        track_changes(self, i, F::fold_meta_list)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_meta_name_value(&mut self, i: MetaNameValue) -> MetaNameValue {
-        fold_meta_name_value(self, i)
+        track_changes(self, i, F::fold_meta_name_value)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_parenthesized_generic_arguments(
         &mut self,
         i: ParenthesizedGenericArguments,
     ) -> ParenthesizedGenericArguments {
-        fold_parenthesized_generic_arguments(self, i)
+        track_changes(self, i, F::fold_parenthesized_generic_arguments)
     }
     #[cfg(feature = "full")]
     fn fold_pat(&mut self, i: Pat) -> Pat {
-        fold_pat(self, i)
+        track_changes(self, i, F::fold_pat)
     }
     #[cfg(feature = "full")]
     fn fold_pat_ident(&mut self, i: PatIdent) -> PatIdent {
-        fold_pat_ident(self, i)
+        track_changes(self, i, F::fold_pat_ident)
     }
     #[cfg(feature = "full")]
     fn fold_pat_or(&mut self, i: PatOr) -> PatOr {
-        fold_pat_or(self, i)
+        track_changes(self, i, F::fold_pat_or)
     }
     #[cfg(feature = "full")]
     fn fold_pat_paren(&mut self, i: PatParen) -> PatParen {
-        fold_pat_paren(self, i)
+        track_changes(self, i, F::fold_pat_paren)
     }
     #[cfg(feature = "full")]
     fn fold_pat_reference(&mut self, i: PatReference) -> PatReference {
-        fold_pat_reference(self, i)
+        track_changes(self, i, F::fold_pat_reference)
     }
     #[cfg(feature = "full")]
     fn fold_pat_rest(&mut self, i: PatRest) -> PatRest {
-        fold_pat_rest(self, i)
+        track_changes(self, i, F::fold_pat_rest)
     }
     #[cfg(feature = "full")]
     fn fold_pat_slice(&mut self, i: PatSlice) -> PatSlice {
-        fold_pat_slice(self, i)
+        track_changes(self, i, F::fold_pat_slice)
     }
     #[cfg(feature = "full")]
     fn fold_pat_struct(&mut self, i: PatStruct) -> PatStruct {
-        fold_pat_struct(self, i)
+        track_changes(self, i, F::fold_pat_struct)
     }
     #[cfg(feature = "full")]
     fn fold_pat_tuple(&mut self, i: PatTuple) -> PatTuple {
-        fold_pat_tuple(self, i)
+        track_changes(self, i, F::fold_pat_tuple)
     }
     #[cfg(feature = "full")]
     fn fold_pat_tuple_struct(&mut self, i: PatTupleStruct) -> PatTupleStruct {
-        fold_pat_tuple_struct(self, i)
+        track_changes(self, i, F::fold_pat_tuple_struct)
     }
     #[cfg(feature = "full")]
     fn fold_pat_type(&mut self, i: PatType) -> PatType {
-        fold_pat_type(self, i)
+        track_changes(self, i, F::fold_pat_type)
     }
     #[cfg(feature = "full")]
     fn fold_pat_wild(&mut self, i: PatWild) -> PatWild {
-        fold_pat_wild(self, i)
+        track_changes(self, i, F::fold_pat_wild)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_path(&mut self, i: Path) -> Path {
-        fold_path(self, i)
+        track_changes(self, i, F::fold_path)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_path_arguments(&mut self, i: PathArguments) -> PathArguments {
-        fold_path_arguments(self, i)
+        track_changes(self, i, F::fold_path_arguments)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_path_segment(&mut self, i: PathSegment) -> PathSegment {
-        fold_path_segment(self, i)
+        track_changes(self, i, F::fold_path_segment)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pointer_mutability(&mut self, i: PointerMutability) -> PointerMutability {
+        track_changes(self, i, F::fold_pointer_mutability)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_predicate_lifetime(&mut self, i: PredicateLifetime) -> PredicateLifetime {
-        fold_predicate_lifetime(self, i)
+        track_changes(self, i, F::fold_predicate_lifetime)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_predicate_type(&mut self, i: PredicateType) -> PredicateType {
-        fold_predicate_type(self, i)
+        track_changes(self, i, F::fold_predicate_type)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_qself(&mut self, i: QSelf) -> QSelf {
-        fold_qself(self, i)
+        self.changed = true;
+        self.inner.fold_qself(i)
     }
     #[cfg(feature = "full")]
     fn fold_range_limits(&mut self, i: RangeLimits) -> RangeLimits {
-        fold_range_limits(self, i)
+        track_changes(self, i, F::fold_range_limits)
     }
     #[cfg(feature = "full")]
     fn fold_receiver(&mut self, i: Receiver) -> Receiver {
-        fold_receiver(self, i)
+        track_changes(self, i, F::fold_receiver)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_return_type(&mut self, i: ReturnType) -> ReturnType {
-        fold_return_type(self, i)
+        track_changes(self, i, F::fold_return_type)
     }
     #[cfg(feature = "full")]
     fn fold_signature(&mut self, i: Signature) -> Signature {
-        fold_signature(self, i)
+        track_changes(self, i, F::fold_signature)
     }
     fn fold_span(&mut self, i: Span) -> Span {
-        fold_span(self, i)
+        self.changed = true;
+        self.inner.fold_span(i)
     }
     #[cfg(feature = "full")]
     fn fold_static_mutability(&mut self, i: StaticMutability) -> StaticMutability {
-        fold_static_mutability(self, i)
+        track_changes(self, i, F::fold_static_mutability)
     }
     #[cfg(feature = "full")]
     fn fold_stmt(&mut self, i: Stmt) -> Stmt {
-        fold_stmt(self, i)
+        track_changes(self, i, F::fold_stmt)
     }
     #[cfg(feature = "full")]
     fn fold_stmt_macro(&mut self, i: StmtMacro) -> StmtMacro {
-        fold_stmt_macro(self, i)
+        track_changes(self, i, F::fold_stmt_macro)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_trait_bound(&mut self, i: TraitBound) -> TraitBound {
-        fold_trait_bound(self, i)
+        track_changes(self, i, F::fold_trait_bound)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_trait_bound_modifier(
         &mut self,
         i: TraitBoundModifier,
     ) -> TraitBoundModifier {
-        fold_trait_bound_modifier(self, i)
+        track_changes(self, i, F::fold_trait_bound_modifier)
     }
     #[cfg(feature = "full")]
     fn fold_trait_item(&mut self, i: TraitItem) -> TraitItem {
-        fold_trait_item(self, i)
+        track_changes(self, i, F::fold_trait_item)
     }
     #[cfg(feature = "full")]
     fn fold_trait_item_const(&mut self, i: TraitItemConst) -> TraitItemConst {
-        fold_trait_item_const(self, i)
+        track_changes(self, i, F::fold_trait_item_const)
     }
     #[cfg(feature = "full")]
     fn fold_trait_item_fn(&mut self, i: TraitItemFn) -> TraitItemFn {
-        fold_trait_item_fn(self, i)
+        track_changes(self, i, F::fold_trait_item_fn)
     }
     #[cfg(feature = "full")]
     fn fold_trait_item_macro(&mut self, i: TraitItemMacro) -> TraitItemMacro {
-        fold_trait_item_macro(self, i)
+        track_changes(self, i, F::fold_trait_item_macro)
     }
     #[cfg(feature = "full")]
     fn fold_trait_item_type(&mut self, i: TraitItemType) -> TraitItemType {
-        fold_trait_item_type(self, i)
+        track_changes(self, i, F::fold_trait_item_type)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type(&mut self, i: Type) -> Type {
-        fold_type(self, i)
+        track_changes(self, i, F::fold_type)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_array(&mut self, i: TypeArray) -> TypeArray {
-        fold_type_array(self, i)
+        track_changes(self, i, F::fold_type_array)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_bare_fn(&mut self, i: TypeBareFn) -> TypeBareFn {
-        fold_type_bare_fn(self, i)
+        track_changes(self, i, F::fold_type_bare_fn)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_group(&mut self, i: TypeGroup) -> TypeGroup {
-        fold_type_group(self, i)
+        track_changes(self, i, F::fold_type_group)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_impl_trait(&mut self, i: TypeImplTrait) -> TypeImplTrait {
-        fold_type_impl_trait(self, i)
+        track_changes(self, i, F::fold_type_impl_trait)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_infer(&mut self, i: TypeInfer) -> TypeInfer {
-        fold_type_infer(self, i)
+        track_changes(self, i, F::fold_type_infer)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_macro(&mut self, i: TypeMacro) -> TypeMacro {
-        fold_type_macro(self, i)
+        track_changes(self, i, F::fold_type_macro)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_never(&mut self, i: TypeNever) -> TypeNever {
-        fold_type_never(self, i)
+        track_changes(self, i, F::fold_type_never)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_param(&mut self, i: TypeParam) -> TypeParam {
-        fold_type_param(self, i)
+        track_changes(self, i, F::fold_type_param)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_param_bound(&mut self, i: TypeParamBound) -> TypeParamBound {
-        fold_type_param_bound(self, i)
+        track_changes(self, i, F::fold_type_param_bound)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_paren(&mut self, i: TypeParen) -> TypeParen {
-        fold_type_paren(self, i)
+        track_changes(self, i, F::fold_type_paren)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_path(&mut self, i: TypePath) -> TypePath {
-        fold_type_path(self, i)
+        track_changes(self, i, F::fold_type_path)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_ptr(&mut self, i: TypePtr) -> TypePtr {
-        fold_type_ptr(self, i)
+        track_changes(self, i, F::fold_type_ptr)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_reference(&mut self, i: TypeReference) -> TypeReference {
-        fold_type_reference(self, i)
+        track_changes(self, i, F::fold_type_reference)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_slice(&mut self, i: TypeSlice) -> TypeSlice {
-        fold_type_slice(self, i)
+        track_changes(self, i, F::fold_type_slice)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_trait_object(&mut self, i: TypeTraitObject) -> TypeTraitObject {
-        fold_type_trait_object(self, i)
+        track_changes(self, i, F::fold_type_trait_object)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_type_tuple(&mut self, i: TypeTuple) -> TypeTuple {
-        fold_type_tuple(self, i)
+        track_changes(self, i, F::fold_type_tuple)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_un_op(&mut self, i: UnOp) -> UnOp {
-        fold_un_op(self, i)
+        track_changes(self, i, F::fold_un_op)
     }
     #[cfg(feature = "full")]
     fn fold_use_glob(&mut self, i: UseGlob) -> UseGlob {
-        fold_use_glob(self, i)
+        track_changes(self, i, F::fold_use_glob)
     }
     #[cfg(feature = "full")]
     fn fold_use_group(&mut self, i: UseGroup) -> UseGroup {
-        fold_use_group(self, i)
+        track_changes(self, i, F::fold_use_group)
     }
     #[cfg(feature = "full")]
     fn fold_use_name(&mut self, i: UseName) -> UseName {
-        fold_use_name(self, i)
+        track_changes(self, i, F::fold_use_name)
     }
     #[cfg(feature = "full")]
     fn fold_use_path(&mut self, i: UsePath) -> UsePath {
-        fold_use_path(self, i)
+        track_changes(self, i, F::fold_use_path)
     }
     #[cfg(feature = "full")]
     fn fold_use_rename(&mut self, i: UseRename) -> UseRename {
-        fold_use_rename(self, i)
+        track_changes(self, i, F::fold_use_rename)
     }
     #[cfg(feature = "full")]
     fn fold_use_tree(&mut self, i: UseTree) -> UseTree {
-        fold_use_tree(self, i)
+        track_changes(self, i, F::fold_use_tree)
     }
     #[cfg(feature = "full")]
     fn fold_variadic(&mut self, i: Variadic) -> Variadic {
-        fold_variadic(self, i)
+        track_changes(self, i, F::fold_variadic)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_variant(&mut self, i: Variant) -> Variant {
-        fold_variant(self, i)
+        track_changes(self, i, F::fold_variant)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_vis_restricted(&mut self, i: VisRestricted) -> VisRestricted {
-        fold_vis_restricted(self, i)
+        track_changes(self, i, F::fold_vis_restricted)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_visibility(&mut self, i: Visibility) -> Visibility {
-        fold_visibility(self, i)
+        track_changes(self, i, F::fold_visibility)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_where_clause(&mut self, i: WhereClause) -> WhereClause {
-        fold_where_clause(self, i)
+        track_changes(self, i, F::fold_where_clause)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_where_predicate(&mut self, i: WherePredicate) -> WherePredicate {
-        fold_where_predicate(self, i)
+        track_changes(self, i, F::fold_where_predicate)
+    }
+}
+#[cfg(feature = "printing")]
+fn track_changes<F, T>(
+    tracker: &mut TrackChanges<F>,
+    node: T,
+    fold: impl FnOnce(&mut F, T) -> T,
+) -> T
+where
+    T: ToTokens,
+{
+    if tracker.changed {
+        return fold(&mut tracker.inner, node);
     }
+    let before = node.to_token_stream();
+    let node = fold(&mut tracker.inner, node);
+    let after = node.to_token_stream();
+    tracker.changed = TokenStreamHelper(&before) != TokenStreamHelper(&after);
+    node
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_abi<F>(f: &mut F, node: Abi) -> Abi
@@ -798,6 +1728,117 @@ where
         comma: (node.comma).map(|it| Token![,](tokens_helper(f, &it.spans))),
     }
 }
+#[cfg(feature = "full")]
+pub fn fold_asm_arg<F>(f: &mut F, node: AsmArg) -> AsmArg
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        AsmArg::Template(_binding_0) => AsmArg::Template(f.fold_lit_str(_binding_0)),
+        AsmArg::Operand(_binding_0) => AsmArg::Operand(f.fold_asm_operand(_binding_0)),
+        AsmArg::ClobberAbi(_binding_0) => {
+            AsmArg::ClobberAbi(f.fold_asm_clobber_abi(_binding_0))
+        }
+        AsmArg::Options(_binding_0) => AsmArg::Options(f.fold_asm_options(_binding_0)),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_asm_clobber_abi<F>(f: &mut F, node: AsmClobberAbi) -> AsmClobberAbi
+where
+    F: Fold + ?Sized,
+{
+    AsmClobberAbi {
+        clobber_abi_token: f.fold_ident(node.clobber_abi_token),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)),
+        abis: FoldHelper::lift(node.abis, |it| f.fold_lit_str(it)),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_asm_direction<F>(f: &mut F, node: AsmDirection) -> AsmDirection
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        AsmDirection::In => AsmDirection::In,
+        AsmDirection::Out => AsmDirection::Out,
+        AsmDirection::LateOut => AsmDirection::LateOut,
+        AsmDirection::InOut => AsmDirection::InOut,
+        AsmDirection::InLateOut => AsmDirection::InLateOut,
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_asm_operand<F>(f: &mut F, node: AsmOperand) -> AsmOperand
+where
+    F: Fold + ?Sized,
+{
+    AsmOperand {
+        name: (node.name)
+            .map(|it| (
+                f.fold_ident((it).0),
+                Token![=](tokens_helper(f, &(it).1.spans)),
+            )),
+        kind: f.fold_asm_operand_kind(node.kind),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_asm_operand_kind<F>(f: &mut F, node: AsmOperandKind) -> AsmOperandKind
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        AsmOperandKind::Reg(_binding_0) => {
+            AsmOperandKind::Reg(f.fold_asm_reg_operand(_binding_0))
+        }
+        AsmOperandKind::Const(_binding_0, _binding_1) => {
+            AsmOperandKind::Const(
+                Token![const](tokens_helper(f, &_binding_0.span)),
+                f.fold_expr(_binding_1),
+            )
+        }
+        AsmOperandKind::Sym(_binding_0, _binding_1) => {
+            AsmOperandKind::Sym(f.fold_ident(_binding_0), f.fold_path(_binding_1))
+        }
+        AsmOperandKind::Label(_binding_0, _binding_1) => {
+            AsmOperandKind::Label(f.fold_ident(_binding_0), f.fold_block(_binding_1))
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_asm_options<F>(f: &mut F, node: AsmOptions) -> AsmOptions
+where
+    F: Fold + ?Sized,
+{
+    AsmOptions {
+        options_token: f.fold_ident(node.options_token),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)),
+        options: FoldHelper::lift(node.options, |it| f.fold_ident(it)),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_asm_reg<F>(f: &mut F, node: AsmReg) -> AsmReg
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        AsmReg::Class(_binding_0) => AsmReg::Class(f.fold_ident(_binding_0)),
+        AsmReg::Explicit(_binding_0) => AsmReg::Explicit(f.fold_lit_str(_binding_0)),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_asm_reg_operand<F>(f: &mut F, node: AsmRegOperand) -> AsmRegOperand
+where
+    F: Fold + ?Sized,
+{
+    AsmRegOperand {
+        direction: f.fold_asm_direction(node.direction),
+        direction_span: f.fold_span(node.direction_span),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)),
+        reg: f.fold_asm_reg(node.reg),
+        expr: f.fold_expr(node.expr),
+        out_expr: (node.out_expr)
+            .map(|it| (Token![=>](tokens_helper(f, &(it).0.spans)), f.fold_expr((it).1))),
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_assoc_const<F>(f: &mut F, node: AssocConst) -> AssocConst
 where
@@ -1084,6 +2125,7 @@ where
         Expr::Assign(_binding_0) => Expr::Assign(full!(f.fold_expr_assign(_binding_0))),
         Expr::Async(_binding_0) => Expr::Async(full!(f.fold_expr_async(_binding_0))),
         Expr::Await(_binding_0) => Expr::Await(full!(f.fold_expr_await(_binding_0))),
+        Expr::Become(_binding_0) => Expr::Become(full!(f.fold_expr_become(_binding_0))),
         Expr::Binary(_binding_0) => Expr::Binary(f.fold_expr_binary(_binding_0)),
         Expr::Block(_binding_0) => Expr::Block(full!(f.fold_expr_block(_binding_0))),
         Expr::Break(_binding_0) => Expr::Break(full!(f.fold_expr_break(_binding_0))),
@@ -1115,6 +2157,9 @@ where
         Expr::Paren(_binding_0) => Expr::Paren(f.fold_expr_paren(_binding_0)),
         Expr::Path(_binding_0) => Expr::Path(f.fold_expr_path(_binding_0)),
         Expr::Range(_binding_0) => Expr::Range(full!(f.fold_expr_range(_binding_0))),
+        Expr::RawAddr(_binding_0) => {
+            Expr::RawAddr(full!(f.fold_expr_raw_addr(_binding_0)))
+        }
         Expr::Reference(_binding_0) => {
             Expr::Reference(full!(f.fold_expr_reference(_binding_0)))
         }
@@ -1180,6 +2225,17 @@ where
         await_token: Token![await](tokens_helper(f, &node.await_token.span)),
     }
 }
+#[cfg(feature = "full")]
+pub fn fold_expr_become<F>(f: &mut F, node: ExprBecome) -> ExprBecome
+where
+    F: Fold + ?Sized,
+{
+    ExprBecome {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        become_token: Token![become](tokens_helper(f, &node.become_token.span)),
+        expr: Box::new(f.fold_expr(*node.expr)),
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_expr_binary<F>(f: &mut F, node: ExprBinary) -> ExprBinary
 where
@@ -1467,6 +2523,19 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_expr_raw_addr<F>(f: &mut F, node: ExprRawAddr) -> ExprRawAddr
+where
+    F: Fold + ?Sized,
+{
+    ExprRawAddr {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        and_token: Token![&](tokens_helper(f, &node.and_token.spans)),
+        raw: Token![raw](tokens_helper(f, &node.raw.span)),
+        mutability: f.fold_pointer_mutability(node.mutability),
+        expr: Box::new(f.fold_expr(*node.expr)),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_expr_reference<F>(f: &mut F, node: ExprReference) -> ExprReference
 where
     F: Fold + ?Sized,
@@ -1608,6 +2677,8 @@ where
         ident: (node.ident).map(|it| f.fold_ident(it)),
         colon_token: (node.colon_token).map(|it| Token![:](tokens_helper(f, &it.spans))),
         ty: f.fold_type(node.ty),
+        default: (node.default)
+            .map(|it| (Token![=](tokens_helper(f, &(it).0.spans)), f.fold_expr((it).1))),
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
@@ -1934,6 +3005,15 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_inline_asm<F>(f: &mut F, node: InlineAsm) -> InlineAsm
+where
+    F: Fold + ?Sized,
+{
+    InlineAsm {
+        args: FoldHelper::lift(node.args, |it| f.fold_asm_arg(it)),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_item<F>(f: &mut F, node: Item) -> Item
 where
     F: Fold + ?Sized,
@@ -2636,6 +3716,23 @@ where
         arguments: f.fold_path_arguments(node.arguments),
     }
 }
+#[cfg(feature = "full")]
+pub fn fold_pointer_mutability<F>(
+    f: &mut F,
+    node: PointerMutability,
+) -> PointerMutability
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            PointerMutability::Const(Token![const](tokens_helper(f, &_binding_0.span)))
+        }
+        PointerMutability::Mut(_binding_0) => {
+            PointerMutability::Mut(Token![mut](tokens_helper(f, &_binding_0.span)))
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_predicate_lifetime<F>(
     f: &mut F,
@@ -2812,6 +3909,12 @@ where
         TraitBoundModifier::Maybe(_binding_0) => {
             TraitBoundModifier::Maybe(Token![?](tokens_helper(f, &_binding_0.spans)))
         }
+        TraitBoundModifier::MaybeConst(_binding_0, _binding_1) => {
+            TraitBoundModifier::MaybeConst(
+                Token![~](tokens_helper(f, &_binding_0.spans)),
+                Token![const](tokens_helper(f, &_binding_1.span)),
+            )
+        }
     }
 }
 #[cfg(feature = "full")]
@@ -40,6 +40,153 @@ impl Hash for Arm {
         self.comma.hash(state);
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AsmArg {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            AsmArg::Template(v0) => {
+                state.write_u8(0u8);
+                v0.hash(state);
+            }
+            AsmArg::Operand(v0) => {
+                state.write_u8(1u8);
+                v0.hash(state);
+            }
+            AsmArg::ClobberAbi(v0) => {
+                state.write_u8(2u8);
+                v0.hash(state);
+            }
+            AsmArg::Options(v0) => {
+                state.write_u8(3u8);
+                v0.hash(state);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AsmClobberAbi {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.clobber_abi_token.hash(state);
+        self.abis.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AsmDirection {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            AsmDirection::In => {
+                state.write_u8(0u8);
+            }
+            AsmDirection::Out => {
+                state.write_u8(1u8);
+            }
+            AsmDirection::LateOut => {
+                state.write_u8(2u8);
+            }
+            AsmDirection::InOut => {
+                state.write_u8(3u8);
+            }
+            AsmDirection::InLateOut => {
+                state.write_u8(4u8);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AsmOperand {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.name.hash(state);
+        self.kind.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AsmOperandKind {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            AsmOperandKind::Reg(v0) => {
+                state.write_u8(0u8);
+                v0.hash(state);
+            }
+            AsmOperandKind::Const(_, v1) => {
+                state.write_u8(1u8);
+                v1.hash(state);
+            }
+            AsmOperandKind::Sym(v0, v1) => {
+                state.write_u8(2u8);
+                v0.hash(state);
+                v1.hash(state);
+            }
+            AsmOperandKind::Label(v0, v1) => {
+                state.write_u8(3u8);
+                v0.hash(state);
+                v1.hash(state);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AsmOptions {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.options_token.hash(state);
+        self.options.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AsmReg {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            AsmReg::Class(v0) => {
+                state.write_u8(0u8);
+                v0.hash(state);
+            }
+            AsmReg::Explicit(v0) => {
+                state.write_u8(1u8);
+                v0.hash(state);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AsmRegOperand {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.direction.hash(state);
+        self.reg.hash(state);
+        self.expr.hash(state);
+        self.out_expr.hash(state);
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for AssocConst {
@@ -353,169 +500,179 @@ impl Hash for Expr {
                 state.write_u8(3u8);
                 v0.hash(state);
             }
-            Expr::Binary(v0) => {
+            #[cfg(feature = "full")]
+            Expr::Become(v0) => {
                 state.write_u8(4u8);
                 v0.hash(state);
             }
+            Expr::Binary(v0) => {
+                state.write_u8(5u8);
+                v0.hash(state);
+            }
             #[cfg(feature = "full")]
             Expr::Block(v0) => {
-                state.write_u8(5u8);
+                state.write_u8(6u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Break(v0) => {
-                state.write_u8(6u8);
+                state.write_u8(7u8);
                 v0.hash(state);
             }
             Expr::Call(v0) => {
-                state.write_u8(7u8);
+                state.write_u8(8u8);
                 v0.hash(state);
             }
             Expr::Cast(v0) => {
-                state.write_u8(8u8);
+                state.write_u8(9u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Closure(v0) => {
-                state.write_u8(9u8);
+                state.write_u8(10u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Const(v0) => {
-                state.write_u8(10u8);
+                state.write_u8(11u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Continue(v0) => {
-                state.write_u8(11u8);
+                state.write_u8(12u8);
                 v0.hash(state);
             }
             Expr::Field(v0) => {
-                state.write_u8(12u8);
+                state.write_u8(13u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::ForLoop(v0) => {
-                state.write_u8(13u8);
+                state.write_u8(14u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Group(v0) => {
-                state.write_u8(14u8);
+                state.write_u8(15u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::If(v0) => {
-                state.write_u8(15u8);
+                state.write_u8(16u8);
                 v0.hash(state);
             }
             Expr::Index(v0) => {
-                state.write_u8(16u8);
+                state.write_u8(17u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Infer(v0) => {
-                state.write_u8(17u8);
+                state.write_u8(18u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Let(v0) => {
-                state.write_u8(18u8);
+                state.write_u8(19u8);
                 v0.hash(state);
             }
             Expr::Lit(v0) => {
-                state.write_u8(19u8);
+                state.write_u8(20u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Loop(v0) => {
-                state.write_u8(20u8);
+                state.write_u8(21u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Macro(v0) => {
-                state.write_u8(21u8);
+                state.write_u8(22u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Match(v0) => {
-                state.write_u8(22u8);
+                state.write_u8(23u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::MethodCall(v0) => {
-                state.write_u8(23u8);
+                state.write_u8(24u8);
                 v0.hash(state);
             }
             Expr::Paren(v0) => {
-                state.write_u8(24u8);
+                state.write_u8(25u8);
                 v0.hash(state);
             }
             Expr::Path(v0) => {
-                state.write_u8(25u8);
+                state.write_u8(26u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Range(v0) => {
-                state.write_u8(26u8);
+                state.write_u8(27u8);
+                v0.hash(state);
+            }
+            #[cfg(feature = "full")]
+            Expr::RawAddr(v0) => {
+                state.write_u8(28u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Reference(v0) => {
-                state.write_u8(27u8);
+                state.write_u8(29u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Repeat(v0) => {
-                state.write_u8(28u8);
+                state.write_u8(30u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Return(v0) => {
-                state.write_u8(29u8);
+                state.write_u8(31u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Struct(v0) => {
-                state.write_u8(30u8);
+                state.write_u8(32u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Try(v0) => {
-                state.write_u8(31u8);
+                state.write_u8(33u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::TryBlock(v0) => {
-                state.write_u8(32u8);
+                state.write_u8(34u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Tuple(v0) => {
-                state.write_u8(33u8);
+                state.write_u8(35u8);
                 v0.hash(state);
             }
             Expr::Unary(v0) => {
-                state.write_u8(34u8);
+                state.write_u8(36u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Unsafe(v0) => {
-                state.write_u8(35u8);
+                state.write_u8(37u8);
                 v0.hash(state);
             }
             Expr::Verbatim(v0) => {
-                state.write_u8(36u8);
+                state.write_u8(38u8);
                 TokenStreamHelper(v0).hash(state);
             }
             #[cfg(feature = "full")]
             Expr::While(v0) => {
-                state.write_u8(37u8);
+                state.write_u8(39u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Yield(v0) => {
-                state.write_u8(38u8);
+                state.write_u8(40u8);
                 v0.hash(state);
             }
             #[cfg(not(feature = "full"))]
@@ -569,6 +726,17 @@ impl Hash for ExprAwait {
         self.base.hash(state);
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for ExprBecome {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.attrs.hash(state);
+        self.expr.hash(state);
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprBinary {
@@ -852,6 +1020,18 @@ impl Hash for ExprRange {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for ExprRawAddr {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.attrs.hash(state);
+        self.mutability.hash(state);
+        self.expr.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprReference {
     fn hash<H>(&self, state: &mut H)
     where
@@ -993,6 +1173,7 @@ impl Hash for Field {
         self.ident.hash(state);
         self.colon_token.hash(state);
         self.ty.hash(state);
+        self.default.hash(state);
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
@@ -1361,6 +1542,16 @@ impl Hash for ImplRestriction {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for InlineAsm {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.args.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Item {
     fn hash<H>(&self, state: &mut H)
     where
@@ -2089,6 +2280,23 @@ impl Hash for PathSegment {
         self.arguments.hash(state);
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for PointerMutability {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            PointerMutability::Const(_) => {
+                state.write_u8(0u8);
+            }
+            PointerMutability::Mut(_) => {
+                state.write_u8(1u8);
+            }
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for PredicateLifetime {
@@ -2275,6 +2483,9 @@ impl Hash for TraitBoundModifier {
             TraitBoundModifier::Maybe(_) => {
                 state.write_u8(1u8);
             }
+            TraitBoundModifier::MaybeConst(_, _) => {
+                state.write_u8(2u8);
+            }
         }
     }
 }
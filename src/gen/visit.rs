@@ -8,6 +8,7 @@ use crate::gen::helper::visit::*;
 use crate::punctuated::Punctuated;
 use crate::*;
 use proc_macro2::Span;
+use std::marker::PhantomData;
 #[cfg(feature = "full")]
 macro_rules! full {
     ($e:expr) => {
@@ -44,6 +45,38 @@ pub trait Visit<'ast> {
     fn visit_arm(&mut self, i: &'ast Arm) {
         visit_arm(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_asm_arg(&mut self, i: &'ast AsmArg) {
+        visit_asm_arg(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_clobber_abi(&mut self, i: &'ast AsmClobberAbi) {
+        visit_asm_clobber_abi(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_direction(&mut self, i: &'ast AsmDirection) {
+        visit_asm_direction(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_operand(&mut self, i: &'ast AsmOperand) {
+        visit_asm_operand(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_operand_kind(&mut self, i: &'ast AsmOperandKind) {
+        visit_asm_operand_kind(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_options(&mut self, i: &'ast AsmOptions) {
+        visit_asm_options(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_reg(&mut self, i: &'ast AsmReg) {
+        visit_asm_reg(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_reg_operand(&mut self, i: &'ast AsmRegOperand) {
+        visit_asm_reg_operand(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_assoc_const(&mut self, i: &'ast AssocConst) {
         visit_assoc_const(self, i);
@@ -128,6 +161,10 @@ pub trait Visit<'ast> {
     fn visit_expr_await(&mut self, i: &'ast ExprAwait) {
         visit_expr_await(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_expr_become(&mut self, i: &'ast ExprBecome) {
+        visit_expr_become(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_expr_binary(&mut self, i: &'ast ExprBinary) {
         visit_expr_binary(self, i);
@@ -221,6 +258,10 @@ pub trait Visit<'ast> {
         visit_expr_range(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_expr_raw_addr(&mut self, i: &'ast ExprRawAddr) {
+        visit_expr_raw_addr(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_reference(&mut self, i: &'ast ExprReference) {
         visit_expr_reference(self, i);
     }
@@ -364,6 +405,10 @@ pub trait Visit<'ast> {
         visit_index(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_inline_asm(&mut self, i: &'ast InlineAsm) {
+        visit_inline_asm(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_item(&mut self, i: &'ast Item) {
         visit_item(self, i);
     }
@@ -561,6 +606,10 @@ pub trait Visit<'ast> {
     fn visit_path_segment(&mut self, i: &'ast PathSegment) {
         visit_path_segment(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_pointer_mutability(&mut self, i: &'ast PointerMutability) {
+        visit_pointer_mutability(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_predicate_lifetime(&mut self, i: &'ast PredicateLifetime) {
         visit_predicate_lifetime(self, i);
@@ -752,6 +801,20 @@ pub trait Visit<'ast> {
     fn visit_where_predicate(&mut self, i: &'ast WherePredicate) {
         visit_where_predicate(self, i);
     }
+    /// Visits the separator that follows an element of a
+    /// [`Punctuated`] sequence, such as either `,` in `a, b,`.
+    ///
+    /// `punct` is the separator as written in the source, like `","`
+    /// or `"::"`, and `spans` holds one span per character of it. The
+    /// separator is visited right after the element it follows and
+    /// before the next element, so a trailing separator is the last
+    /// thing visited in the sequence. The default implementation
+    /// visits each span with [`Visit::visit_span`].
+    ///
+    /// [`Punctuated`]: crate::punctuated::Punctuated
+    fn visit_punct(&mut self, punct: &str, spans: &[Span]) {
+        visit_punct(self, punct, spans);
+    }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_abi<'ast, V>(v: &mut V, node: &'ast Abi)
@@ -779,7 +842,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_generic_argument(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     tokens_helper(v, &node.gt_token.spans);
@@ -803,6 +866,132 @@ where
         tokens_helper(v, &it.spans);
     }
 }
+#[cfg(feature = "full")]
+pub fn visit_asm_arg<'ast, V>(v: &mut V, node: &'ast AsmArg)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        AsmArg::Template(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+        AsmArg::Operand(_binding_0) => {
+            v.visit_asm_operand(_binding_0);
+        }
+        AsmArg::ClobberAbi(_binding_0) => {
+            v.visit_asm_clobber_abi(_binding_0);
+        }
+        AsmArg::Options(_binding_0) => {
+            v.visit_asm_options(_binding_0);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_clobber_abi<'ast, V>(v: &mut V, node: &'ast AsmClobberAbi)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.clobber_abi_token);
+    tokens_helper(v, &node.paren_token.span);
+    for el in Punctuated::pairs(&node.abis) {
+        let (it, p) = el.into_tuple();
+        v.visit_lit_str(it);
+        if let Some(p) = p {
+            v.visit_punct(",", &p.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_direction<'ast, V>(v: &mut V, node: &'ast AsmDirection)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        AsmDirection::In => {}
+        AsmDirection::Out => {}
+        AsmDirection::LateOut => {}
+        AsmDirection::InOut => {}
+        AsmDirection::InLateOut => {}
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_operand<'ast, V>(v: &mut V, node: &'ast AsmOperand)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.name {
+        v.visit_ident(&(it).0);
+        tokens_helper(v, &(it).1.spans);
+    }
+    v.visit_asm_operand_kind(&node.kind);
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_operand_kind<'ast, V>(v: &mut V, node: &'ast AsmOperandKind)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        AsmOperandKind::Reg(_binding_0) => {
+            v.visit_asm_reg_operand(_binding_0);
+        }
+        AsmOperandKind::Const(_binding_0, _binding_1) => {
+            tokens_helper(v, &_binding_0.span);
+            v.visit_expr(_binding_1);
+        }
+        AsmOperandKind::Sym(_binding_0, _binding_1) => {
+            v.visit_ident(_binding_0);
+            v.visit_path(_binding_1);
+        }
+        AsmOperandKind::Label(_binding_0, _binding_1) => {
+            v.visit_ident(_binding_0);
+            v.visit_block(_binding_1);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_options<'ast, V>(v: &mut V, node: &'ast AsmOptions)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.options_token);
+    tokens_helper(v, &node.paren_token.span);
+    for el in Punctuated::pairs(&node.options) {
+        let (it, p) = el.into_tuple();
+        v.visit_ident(it);
+        if let Some(p) = p {
+            v.visit_punct(",", &p.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_reg<'ast, V>(v: &mut V, node: &'ast AsmReg)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        AsmReg::Class(_binding_0) => {
+            v.visit_ident(_binding_0);
+        }
+        AsmReg::Explicit(_binding_0) => {
+            v.visit_lit_str(_binding_0);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_reg_operand<'ast, V>(v: &mut V, node: &'ast AsmRegOperand)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_asm_direction(&node.direction);
+    v.visit_span(&node.direction_span);
+    tokens_helper(v, &node.paren_token.span);
+    v.visit_asm_reg(&node.reg);
+    v.visit_expr(&node.expr);
+    if let Some(it) = &node.out_expr {
+        tokens_helper(v, &(it).0.spans);
+        v.visit_expr(&(it).1);
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_assoc_const<'ast, V>(v: &mut V, node: &'ast AssocConst)
 where
@@ -993,7 +1182,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_generic_param(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     tokens_helper(v, &node.gt_token.spans);
@@ -1031,7 +1220,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
 }
@@ -1063,7 +1252,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_variant(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -1117,6 +1306,9 @@ where
         Expr::Await(_binding_0) => {
             full!(v.visit_expr_await(_binding_0));
         }
+        Expr::Become(_binding_0) => {
+            full!(v.visit_expr_become(_binding_0));
+        }
         Expr::Binary(_binding_0) => {
             v.visit_expr_binary(_binding_0);
         }
@@ -1186,6 +1378,9 @@ where
         Expr::Range(_binding_0) => {
             full!(v.visit_expr_range(_binding_0));
         }
+        Expr::RawAddr(_binding_0) => {
+            full!(v.visit_expr_raw_addr(_binding_0));
+        }
         Expr::Reference(_binding_0) => {
             full!(v.visit_expr_reference(_binding_0));
         }
@@ -1237,7 +1432,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_expr(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -1279,6 +1474,17 @@ where
     tokens_helper(v, &node.dot_token.spans);
     tokens_helper(v, &node.await_token.span);
 }
+#[cfg(feature = "full")]
+pub fn visit_expr_become<'ast, V>(v: &mut V, node: &'ast ExprBecome)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it);
+    }
+    tokens_helper(v, &node.become_token.span);
+    v.visit_expr(&*node.expr);
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_expr_binary<'ast, V>(v: &mut V, node: &'ast ExprBinary)
 where
@@ -1334,7 +1540,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_expr(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -1378,7 +1584,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     tokens_helper(v, &node.or2_token.spans);
@@ -1568,7 +1774,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_expr(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -1613,6 +1819,19 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_raw_addr<'ast, V>(v: &mut V, node: &'ast ExprRawAddr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it);
+    }
+    tokens_helper(v, &node.and_token.spans);
+    tokens_helper(v, &node.raw.span);
+    v.visit_pointer_mutability(&node.mutability);
+    v.visit_expr(&*node.expr);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_reference<'ast, V>(v: &mut V, node: &'ast ExprReference)
 where
     V: Visit<'ast> + ?Sized,
@@ -1669,7 +1888,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_field_value(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     if let Some(it) = &node.dot2_token {
@@ -1714,7 +1933,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_expr(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -1785,6 +2004,10 @@ where
         tokens_helper(v, &it.spans);
     }
     v.visit_type(&node.ty);
+    if let Some(it) = &node.default {
+        tokens_helper(v, &(it).0.spans);
+        v.visit_expr(&(it).1);
+    }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_field_mutability<'ast, V>(v: &mut V, node: &'ast FieldMutability)
@@ -1848,7 +2071,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_field(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -1862,7 +2085,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_field(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -2026,7 +2249,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_generic_param(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     if let Some(it) = &node.gt_token {
@@ -2149,6 +2372,19 @@ where
     v.visit_span(&node.span);
 }
 #[cfg(feature = "full")]
+pub fn visit_inline_asm<'ast, V>(v: &mut V, node: &'ast InlineAsm)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for el in Punctuated::pairs(&node.args) {
+        let (it, p) = el.into_tuple();
+        v.visit_asm_arg(it);
+        if let Some(p) = p {
+            v.visit_punct(",", &p.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
 pub fn visit_item<'ast, V>(v: &mut V, node: &'ast Item)
 where
     V: Visit<'ast> + ?Sized,
@@ -2239,7 +2475,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_variant(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -2422,7 +2658,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
     tokens_helper(v, &node.brace_token.span);
@@ -2447,7 +2683,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
     tokens_helper(v, &node.semi_token.spans);
@@ -2529,7 +2765,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_lifetime(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
 }
@@ -2711,7 +2947,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     v.visit_return_type(&node.output);
@@ -2810,7 +3046,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("|", &p.spans);
         }
     }
 }
@@ -2862,7 +3098,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -2883,7 +3119,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_field_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     if let Some(it) = &node.rest {
@@ -2903,7 +3139,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -2924,7 +3160,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -2962,7 +3198,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_path_segment(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("::", &p.spans);
         }
     }
 }
@@ -2989,6 +3225,20 @@ where
     v.visit_ident(&node.ident);
     v.visit_path_arguments(&node.arguments);
 }
+#[cfg(feature = "full")]
+pub fn visit_pointer_mutability<'ast, V>(v: &mut V, node: &'ast PointerMutability)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            tokens_helper(v, &_binding_0.span);
+        }
+        PointerMutability::Mut(_binding_0) => {
+            tokens_helper(v, &_binding_0.span);
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_predicate_lifetime<'ast, V>(v: &mut V, node: &'ast PredicateLifetime)
 where
@@ -3000,7 +3250,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_lifetime(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
 }
@@ -3018,7 +3268,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
 }
@@ -3110,7 +3360,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_fn_arg(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     if let Some(it) = &node.variadic {
@@ -3194,6 +3444,10 @@ where
         TraitBoundModifier::Maybe(_binding_0) => {
             tokens_helper(v, &_binding_0.spans);
         }
+        TraitBoundModifier::MaybeConst(_binding_0, _binding_1) => {
+            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, &_binding_1.span);
+        }
     }
 }
 #[cfg(feature = "full")]
@@ -3285,7 +3539,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
     if let Some(it) = &node.default {
@@ -3377,7 +3631,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_bare_fn_arg(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
     if let Some(it) = &node.variadic {
@@ -3403,7 +3657,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
 }
@@ -3444,7 +3698,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
     if let Some(it) = &node.eq_token {
@@ -3537,7 +3791,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct("+", &p.spans);
         }
     }
 }
@@ -3551,7 +3805,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -3589,7 +3843,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_use_tree(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -3710,7 +3964,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_where_predicate(it);
         if let Some(p) = p {
-            tokens_helper(v, &p.spans);
+            v.visit_punct(",", &p.spans);
         }
     }
 }
@@ -3728,3 +3982,1957 @@ where
         }
     }
 }
+pub fn visit_punct<'ast, V>(v: &mut V, punct: &str, spans: &[Span])
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for span in spans {
+        v.visit_span(span);
+    }
+}
+/// A syntax tree node that can be walked by a [`Visit`] implementation.
+///
+/// This is implemented for every syntax tree type that has a method in
+/// [`Visit`], and is what lets the helpers [`for_each`], [`collect`],
+/// [`find_first`] and [`collect_idents`] accept any node as their root.
+/// Taking the node itself, as in `visit::collect_idents(&item)`, spares
+/// callers a closure like `|v: &mut dyn Visit| v.visit_item_fn(&item)`
+/// that would have to name the right method for the node's type and
+/// would make every call of the walk dynamically dispatched.
+pub trait Visitable<'ast> {
+    /// Invokes the method of `v` corresponding to this node's type, for
+    /// example [`Visit::visit_item_fn`] for an [`ItemFn`].
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized;
+}
+impl<'ast, T> Visitable<'ast> for Box<T>
+where
+    T: Visitable<'ast> + ?Sized,
+{
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        (**self).visit(v);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Abi {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_abi(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for AngleBracketedGenericArguments {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_angle_bracketed_generic_arguments(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Arm {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_arm(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for AsmArg {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_asm_arg(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for AsmClobberAbi {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_asm_clobber_abi(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for AsmDirection {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_asm_direction(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for AsmOperand {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_asm_operand(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for AsmOperandKind {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_asm_operand_kind(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for AsmOptions {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_asm_options(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for AsmReg {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_asm_reg(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for AsmRegOperand {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_asm_reg_operand(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for AssocConst {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_assoc_const(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for AssocType {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_assoc_type(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for AttrStyle {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_attr_style(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Attribute {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_attribute(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for BareFnArg {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_bare_fn_arg(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for BareVariadic {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_bare_variadic(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for BinOp {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_bin_op(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Block {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_block(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for BoundLifetimes {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_bound_lifetimes(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ConstParam {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_const_param(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Constraint {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_constraint(self);
+    }
+}
+#[cfg(feature = "derive")]
+impl<'ast> Visitable<'ast> for Data {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_data(self);
+    }
+}
+#[cfg(feature = "derive")]
+impl<'ast> Visitable<'ast> for DataEnum {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_data_enum(self);
+    }
+}
+#[cfg(feature = "derive")]
+impl<'ast> Visitable<'ast> for DataStruct {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_data_struct(self);
+    }
+}
+#[cfg(feature = "derive")]
+impl<'ast> Visitable<'ast> for DataUnion {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_data_union(self);
+    }
+}
+#[cfg(feature = "derive")]
+impl<'ast> Visitable<'ast> for DeriveInput {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_derive_input(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Expr {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprArray {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_array(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprAssign {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_assign(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprAsync {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_async(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprAwait {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_await(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprBecome {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_become(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprBinary {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_binary(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprBlock {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_block(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprBreak {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_break(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprCall {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_call(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprCast {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_cast(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprClosure {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_closure(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprConst {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_const(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprContinue {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_continue(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprField {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_field(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprForLoop {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_for_loop(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprGroup {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_group(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprIf {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_if(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprIndex {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_index(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprInfer {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_infer(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprLet {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_let(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprLit {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_lit(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprLoop {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_loop(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprMacro {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_macro(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprMatch {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_match(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprMethodCall {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_method_call(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprParen {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_paren(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprPath {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_path(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprRange {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_range(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprRawAddr {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_raw_addr(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprReference {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_reference(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprRepeat {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_repeat(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprReturn {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_return(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprStruct {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_struct(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprTry {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_try(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprTryBlock {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_try_block(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprTuple {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_tuple(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ExprUnary {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_unary(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprUnsafe {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_unsafe(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprWhile {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_while(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ExprYield {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_expr_yield(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Field {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_field(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for FieldMutability {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_field_mutability(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for FieldPat {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_field_pat(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for FieldValue {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_field_value(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Fields {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_fields(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for FieldsNamed {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_fields_named(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for FieldsUnnamed {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_fields_unnamed(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for File {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_file(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for FnArg {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_fn_arg(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ForeignItem {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_foreign_item(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ForeignItemFn {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_foreign_item_fn(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ForeignItemMacro {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_foreign_item_macro(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ForeignItemStatic {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_foreign_item_static(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ForeignItemType {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_foreign_item_type(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for GenericArgument {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_generic_argument(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for GenericParam {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_generic_param(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Generics {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_generics(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ImplItem {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_impl_item(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ImplItemConst {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_impl_item_const(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ImplItemFn {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_impl_item_fn(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ImplItemMacro {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_impl_item_macro(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ImplItemType {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_impl_item_type(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ImplRestriction {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_impl_restriction(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Index {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_index(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for InlineAsm {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_inline_asm(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Item {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemConst {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_const(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemEnum {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_enum(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemExternCrate {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_extern_crate(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemFn {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_fn(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemForeignMod {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_foreign_mod(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemImpl {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_impl(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemMacro {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_macro(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemMod {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_mod(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemStatic {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_static(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemStruct {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_struct(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemTrait {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_trait(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemTraitAlias {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_trait_alias(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemType {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_type(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemUnion {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_union(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for ItemUse {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_item_use(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Label {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_label(self);
+    }
+}
+impl<'ast> Visitable<'ast> for Lifetime {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lifetime(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for LifetimeParam {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lifetime_param(self);
+    }
+}
+impl<'ast> Visitable<'ast> for Lit {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lit(self);
+    }
+}
+impl<'ast> Visitable<'ast> for LitBool {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lit_bool(self);
+    }
+}
+impl<'ast> Visitable<'ast> for LitByte {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lit_byte(self);
+    }
+}
+impl<'ast> Visitable<'ast> for LitByteStr {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lit_byte_str(self);
+    }
+}
+impl<'ast> Visitable<'ast> for LitChar {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lit_char(self);
+    }
+}
+impl<'ast> Visitable<'ast> for LitFloat {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lit_float(self);
+    }
+}
+impl<'ast> Visitable<'ast> for LitInt {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lit_int(self);
+    }
+}
+impl<'ast> Visitable<'ast> for LitStr {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_lit_str(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Local {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_local(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for LocalInit {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_local_init(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Macro {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_macro(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for MacroDelimiter {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_macro_delimiter(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Member {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_member(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Meta {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_meta(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for MetaList {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_meta_list(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for MetaNameValue {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_meta_name_value(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ParenthesizedGenericArguments {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_parenthesized_generic_arguments(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Pat {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatIdent {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_ident(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatOr {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_or(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatParen {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_paren(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatReference {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_reference(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatRest {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_rest(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatSlice {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_slice(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatStruct {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_struct(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatTuple {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_tuple(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatTupleStruct {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_tuple_struct(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatType {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_type(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PatWild {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pat_wild(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Path {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_path(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for PathArguments {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_path_arguments(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for PathSegment {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_path_segment(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for PointerMutability {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_pointer_mutability(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for PredicateLifetime {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_predicate_lifetime(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for PredicateType {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_predicate_type(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for QSelf {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_qself(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for RangeLimits {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_range_limits(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Receiver {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_receiver(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for ReturnType {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_return_type(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Signature {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_signature(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for StaticMutability {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_static_mutability(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Stmt {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_stmt(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for StmtMacro {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_stmt_macro(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TraitBound {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_trait_bound(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TraitBoundModifier {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_trait_bound_modifier(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for TraitItem {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_trait_item(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for TraitItemConst {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_trait_item_const(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for TraitItemFn {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_trait_item_fn(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for TraitItemMacro {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_trait_item_macro(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for TraitItemType {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_trait_item_type(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Type {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeArray {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_array(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeBareFn {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_bare_fn(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeGroup {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_group(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeImplTrait {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_impl_trait(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeInfer {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_infer(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeMacro {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_macro(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeNever {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_never(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeParam {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_param(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeParamBound {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_param_bound(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeParen {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_paren(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypePath {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_path(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypePtr {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_ptr(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeReference {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_reference(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeSlice {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_slice(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeTraitObject {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_trait_object(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for TypeTuple {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_type_tuple(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for UnOp {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_un_op(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for UseGlob {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_use_glob(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for UseGroup {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_use_group(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for UseName {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_use_name(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for UsePath {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_use_path(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for UseRename {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_use_rename(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for UseTree {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_use_tree(self);
+    }
+}
+#[cfg(feature = "full")]
+impl<'ast> Visitable<'ast> for Variadic {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_variadic(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Variant {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_variant(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for VisRestricted {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_vis_restricted(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for Visibility {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_visibility(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for WhereClause {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_where_clause(self);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast> Visitable<'ast> for WherePredicate {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_where_predicate(self);
+    }
+}
+impl<'ast> Visitable<'ast> for Ident {
+    fn visit<V>(&'ast self, v: &mut V)
+    where
+        V: Visit<'ast> + ?Sized,
+    {
+        v.visit_ident(self);
+    }
+}
+/// A [`Visit`] that calls a closure on every node of type `T` in a syntax
+/// tree.
+///
+/// The supported node types are:
+///
+/// - [`Ident`]: every identifier, in the order they appear. The
+///   identifier inside a [`Lifetime`] is not included, so `'a` does not
+///   produce an identifier `a`.
+/// - [`Lifetime`]: every lifetime, including those in generic parameter
+///   lists, bounds and labels.
+/// - [`ExprPath`]: every path expression, such as `x` or `Vec::new` in
+///   `Vec::new()`, including ones nested within other path expressions'
+///   generic arguments.
+///
+/// The nodes are reached in the same order as by the default [`Visit`]
+/// methods, which is source order. Nodes within the tokens of a macro
+/// invocation or of a `Verbatim` node are not syntax trees and are not
+/// visited.
+///
+/// # Example
+///
+/// ```
+/// // [dependencies]
+/// // syn = { version = "1.0", features = ["full", "visit"] }
+///
+/// use syn::visit::{Collector, Visit};
+/// use syn::{parse_quote, ExprPath, ItemFn};
+///
+/// let item: ItemFn = parse_quote! {
+///     fn f() {
+///         let v = Vec::new();
+///         drop(v);
+///     }
+/// };
+///
+/// let mut paths = Vec::new();
+/// let mut collector = Collector::new(|path: &ExprPath| {
+///     paths.push(path.path.segments.last().unwrap().ident.to_string());
+/// });
+/// collector.visit_item_fn(&item);
+/// assert_eq!(paths, ["new", "drop", "v"]);
+/// ```
+pub struct Collector<T, F> {
+    f: F,
+    marker: PhantomData<fn(&T)>,
+}
+impl<T, F> Collector<T, F> {
+    /// Creates a visitor that calls `f` on every node of type `T`.
+    pub fn new(f: F) -> Self {
+        Collector {
+            f,
+            marker: PhantomData,
+        }
+    }
+    /// Returns the closure, along with any state it has captured.
+    pub fn into_inner(self) -> F {
+        self.f
+    }
+}
+impl<'ast, F> Visit<'ast> for Collector<Ident, F>
+where
+    F: FnMut(&'ast Ident),
+{
+    fn visit_ident(&mut self, i: &'ast Ident) {
+        (self.f)(i);
+    }
+    fn visit_lifetime(&mut self, i: &'ast Lifetime) {}
+}
+impl<'ast, F> Visit<'ast> for Collector<Lifetime, F>
+where
+    F: FnMut(&'ast Lifetime),
+{
+    fn visit_lifetime(&mut self, i: &'ast Lifetime) {
+        (self.f)(i);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'ast, F> Visit<'ast> for Collector<ExprPath, F>
+where
+    F: FnMut(&'ast ExprPath),
+{
+    fn visit_expr_path(&mut self, i: &'ast ExprPath) {
+        (self.f)(i);
+        visit_expr_path(self, i);
+    }
+}
+/// Calls `f` on every node of type `T` within `node`.
+///
+/// See [`Collector`] for the supported node types.
+pub fn for_each<'ast, T, N, F>(node: &'ast N, mut f: F)
+where
+    T: 'ast,
+    N: Visitable<'ast> + ?Sized,
+    F: FnMut(&'ast T),
+    for<'f> Collector<T, &'f mut dyn FnMut(&'ast T)>: Visit<'ast>,
+{
+    let mut collector = Collector::<T, &mut dyn FnMut(&'ast T)>::new(&mut f);
+    node.visit(&mut collector);
+}
+/// Returns every node of type `T` within `node`, in source order.
+///
+/// See [`Collector`] for the supported node types.
+///
+/// # Example
+///
+/// ```
+/// // [dependencies]
+/// // syn = { version = "1.0", features = ["full", "visit"] }
+///
+/// use syn::{parse_quote, visit, ItemFn, Lifetime};
+///
+/// let item: ItemFn = parse_quote! {
+///     fn longest<'a, 'b: 'a>(x: &'a str, y: &'b str) -> &'a str {}
+/// };
+///
+/// let lifetimes = visit::collect::<Lifetime, _>(&item);
+/// assert_eq!(lifetimes.len(), 6);
+/// ```
+pub fn collect<'ast, T, N>(node: &'ast N) -> Vec<&'ast T>
+where
+    T: 'ast,
+    N: Visitable<'ast> + ?Sized,
+    for<'f> Collector<T, &'f mut dyn FnMut(&'ast T)>: Visit<'ast>,
+{
+    let mut nodes = Vec::new();
+    for_each(node, |t| nodes.push(t));
+    nodes
+}
+/// Returns the first node of type `T` within `node`, in source order.
+///
+/// A [`Visit`] cannot end a traversal early, so the rest of `node` is
+/// still walked after the first match. This takes time proportional to
+/// the size of `node`, like [`collect`], but does not allocate.
+///
+/// See [`Collector`] for the supported node types.
+///
+/// # Example
+///
+/// ```
+/// // [dependencies]
+/// // syn = { version = "1.0", features = ["full", "visit"] }
+///
+/// use syn::{parse_quote, visit, Expr, ExprPath};
+///
+/// let expr: Expr = parse_quote!(1 + len(&items));
+/// let first = visit::find_first::<ExprPath, _>(&expr).unwrap();
+/// assert!(first.path.is_ident("len"));
+/// ```
+pub fn find_first<'ast, T, N>(node: &'ast N) -> Option<&'ast T>
+where
+    T: 'ast,
+    N: Visitable<'ast> + ?Sized,
+    for<'f> Collector<T, &'f mut dyn FnMut(&'ast T)>: Visit<'ast>,
+{
+    let mut first = None;
+    for_each(
+        node,
+        |t| {
+            if first.is_none() {
+                first = Some(t);
+            }
+        },
+    );
+    first
+}
+/// Returns a copy of every identifier within `node`, in source order.
+///
+/// Identifiers of lifetimes are not included.
+///
+/// # Example
+///
+/// ```
+/// // [dependencies]
+/// // syn = { version = "1.0", features = ["full", "visit"] }
+///
+/// use syn::{parse_quote, visit, ItemStruct};
+///
+/// let item: ItemStruct = parse_quote! {
+///     struct Wrapper<'a, T>(&'a T);
+/// };
+///
+/// let idents = visit::collect_idents(&item);
+/// assert_eq!(idents, ["Wrapper", "T", "T"]);
+/// ```
+pub fn collect_idents<'ast, N>(node: &'ast N) -> Vec<Ident>
+where
+    N: Visitable<'ast> + ?Sized,
+{
+    let mut idents = Vec::new();
+    for_each(node, |ident: &Ident| idents.push(ident.clone()));
+    idents
+}
@@ -45,6 +45,38 @@ pub trait VisitMut {
     fn visit_arm_mut(&mut self, i: &mut Arm) {
         visit_arm_mut(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_asm_arg_mut(&mut self, i: &mut AsmArg) {
+        visit_asm_arg_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_clobber_abi_mut(&mut self, i: &mut AsmClobberAbi) {
+        visit_asm_clobber_abi_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_direction_mut(&mut self, i: &mut AsmDirection) {
+        visit_asm_direction_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_operand_mut(&mut self, i: &mut AsmOperand) {
+        visit_asm_operand_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_operand_kind_mut(&mut self, i: &mut AsmOperandKind) {
+        visit_asm_operand_kind_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_options_mut(&mut self, i: &mut AsmOptions) {
+        visit_asm_options_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_reg_mut(&mut self, i: &mut AsmReg) {
+        visit_asm_reg_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_asm_reg_operand_mut(&mut self, i: &mut AsmRegOperand) {
+        visit_asm_reg_operand_mut(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_assoc_const_mut(&mut self, i: &mut AssocConst) {
         visit_assoc_const_mut(self, i);
@@ -129,6 +161,10 @@ pub trait VisitMut {
     fn visit_expr_await_mut(&mut self, i: &mut ExprAwait) {
         visit_expr_await_mut(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_expr_become_mut(&mut self, i: &mut ExprBecome) {
+        visit_expr_become_mut(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_expr_binary_mut(&mut self, i: &mut ExprBinary) {
         visit_expr_binary_mut(self, i);
@@ -222,6 +258,10 @@ pub trait VisitMut {
         visit_expr_range_mut(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_expr_raw_addr_mut(&mut self, i: &mut ExprRawAddr) {
+        visit_expr_raw_addr_mut(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_reference_mut(&mut self, i: &mut ExprReference) {
         visit_expr_reference_mut(self, i);
     }
@@ -365,6 +405,10 @@ pub trait VisitMut {
         visit_index_mut(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_inline_asm_mut(&mut self, i: &mut InlineAsm) {
+        visit_inline_asm_mut(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_item_mut(&mut self, i: &mut Item) {
         visit_item_mut(self, i);
     }
@@ -562,6 +606,10 @@ pub trait VisitMut {
     fn visit_path_segment_mut(&mut self, i: &mut PathSegment) {
         visit_path_segment_mut(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_pointer_mutability_mut(&mut self, i: &mut PointerMutability) {
+        visit_pointer_mutability_mut(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_predicate_lifetime_mut(&mut self, i: &mut PredicateLifetime) {
         visit_predicate_lifetime_mut(self, i);
@@ -753,6 +801,548 @@ pub trait VisitMut {
     fn visit_where_predicate_mut(&mut self, i: &mut WherePredicate) {
         visit_where_predicate_mut(self, i);
     }
+    /// Visits the separator that follows an element of a
+    /// [`Punctuated`] sequence, such as either `,` in `a, b,`.
+    ///
+    /// `punct` is the separator as written in the source, like `","`
+    /// or `"::"`, and `spans` holds one span per character of it. The
+    /// separator is visited right after the element it follows and
+    /// before the next element, so a trailing separator is the last
+    /// thing visited in the sequence. The default implementation
+    /// visits each span with [`VisitMut::visit_span_mut`].
+    ///
+    /// [`Punctuated`]: crate::punctuated::Punctuated
+    fn visit_punct_mut(&mut self, punct: &str, spans: &mut [Span]) {
+        visit_punct_mut(self, punct, spans);
+    }
+}
+/// A [`VisitMut`] that removes every attribute for which a predicate
+/// returns true from the syntax tree.
+///
+/// This is typically used by a derive or attribute macro to remove its
+/// helper attributes from the code it emits after reading them. All
+/// attributes of every node in the tree are checked, including those of
+/// nested items, but not attributes within the tokens of a macro
+/// invocation or of a `Verbatim` node, which are not syntax trees.
+///
+/// # Example
+///
+/// ```
+/// // [dependencies]
+/// // quote = "1.0"
+/// // syn = { version = "1.0", features = ["full", "visit-mut"] }
+///
+/// use quote::quote;
+/// use syn::visit_mut::{AttrStripper, VisitMut};
+/// use syn::{parse_quote, ItemStruct};
+///
+/// let mut item: ItemStruct = parse_quote! {
+///     #[derive(MyDerive)]
+///     #[my_helper(skip)]
+///     struct S {
+///         #[my_helper(rename = "b")]
+///         a: u8,
+///     }
+/// };
+///
+/// let mut stripper = AttrStripper::new(|attr| attr.path().is_ident("my_helper"));
+/// stripper.visit_item_struct_mut(&mut item);
+/// assert_eq!(
+///     quote!(#item).to_string(),
+///     quote!(#[derive(MyDerive)] struct S { a: u8, }).to_string(),
+/// );
+/// ```
+#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub struct AttrStripper<F> {
+    pred: F,
+}
+#[cfg(any(feature = "full", feature = "derive"))]
+impl<F> AttrStripper<F>
+where
+    F: FnMut(&Attribute) -> bool,
+{
+    /// Creates a visitor that removes the attributes for which `pred`
+    /// returns true.
+    pub fn new(pred: F) -> Self {
+        AttrStripper { pred }
+    }
+}
+#[cfg(any(feature = "full", feature = "derive"))]
+impl<F> VisitMut for AttrStripper<F>
+where
+    F: FnMut(&Attribute) -> bool,
+{
+    #[cfg(feature = "full")]
+    fn visit_arm_mut(&mut self, node: &mut Arm) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_arm_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bare_fn_arg_mut(&mut self, node: &mut BareFnArg) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_bare_fn_arg_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bare_variadic_mut(&mut self, node: &mut BareVariadic) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_bare_variadic_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_const_param_mut(&mut self, node: &mut ConstParam) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_const_param_mut(self, node);
+    }
+    #[cfg(feature = "derive")]
+    fn visit_derive_input_mut(&mut self, node: &mut DeriveInput) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_derive_input_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_array_mut(&mut self, node: &mut ExprArray) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_array_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_mut(&mut self, node: &mut ExprAssign) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_assign_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_async_mut(&mut self, node: &mut ExprAsync) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_async_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_await_mut(&mut self, node: &mut ExprAwait) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_await_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_become_mut(&mut self, node: &mut ExprBecome) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_become_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_binary_mut(&mut self, node: &mut ExprBinary) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_binary_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_block_mut(&mut self, node: &mut ExprBlock) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_block_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_break_mut(&mut self, node: &mut ExprBreak) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_break_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_call_mut(&mut self, node: &mut ExprCall) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_call_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_cast_mut(&mut self, node: &mut ExprCast) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_cast_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_closure_mut(&mut self, node: &mut ExprClosure) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_closure_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_const_mut(&mut self, node: &mut ExprConst) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_const_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_continue_mut(&mut self, node: &mut ExprContinue) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_continue_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_field_mut(&mut self, node: &mut ExprField) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_field_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_for_loop_mut(&mut self, node: &mut ExprForLoop) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_for_loop_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_group_mut(&mut self, node: &mut ExprGroup) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_group_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_if_mut(&mut self, node: &mut ExprIf) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_if_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_index_mut(&mut self, node: &mut ExprIndex) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_index_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_infer_mut(&mut self, node: &mut ExprInfer) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_infer_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_let_mut(&mut self, node: &mut ExprLet) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_let_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_lit_mut(&mut self, node: &mut ExprLit) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_lit_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_loop_mut(&mut self, node: &mut ExprLoop) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_loop_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_macro_mut(&mut self, node: &mut ExprMacro) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_macro_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_match_mut(&mut self, node: &mut ExprMatch) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_match_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_method_call_mut(&mut self, node: &mut ExprMethodCall) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_method_call_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_paren_mut(&mut self, node: &mut ExprParen) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_paren_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_path_mut(&mut self, node: &mut ExprPath) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_path_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_range_mut(&mut self, node: &mut ExprRange) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_range_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_raw_addr_mut(&mut self, node: &mut ExprRawAddr) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_raw_addr_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_reference_mut(&mut self, node: &mut ExprReference) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_reference_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_repeat_mut(&mut self, node: &mut ExprRepeat) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_repeat_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_return_mut(&mut self, node: &mut ExprReturn) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_return_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_struct_mut(&mut self, node: &mut ExprStruct) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_struct_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_mut(&mut self, node: &mut ExprTry) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_try_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_block_mut(&mut self, node: &mut ExprTryBlock) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_try_block_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_tuple_mut(&mut self, node: &mut ExprTuple) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_tuple_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_unary_mut(&mut self, node: &mut ExprUnary) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_unary_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_unsafe_mut(&mut self, node: &mut ExprUnsafe) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_unsafe_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_while_mut(&mut self, node: &mut ExprWhile) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_while_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_yield_mut(&mut self, node: &mut ExprYield) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_expr_yield_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_field_mut(&mut self, node: &mut Field) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_field_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_pat_mut(&mut self, node: &mut FieldPat) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_field_pat_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_value_mut(&mut self, node: &mut FieldValue) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_field_value_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_file_mut(&mut self, node: &mut File) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_file_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_fn_mut(&mut self, node: &mut ForeignItemFn) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_foreign_item_fn_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_macro_mut(&mut self, node: &mut ForeignItemMacro) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_foreign_item_macro_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_static_mut(&mut self, node: &mut ForeignItemStatic) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_foreign_item_static_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_type_mut(&mut self, node: &mut ForeignItemType) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_foreign_item_type_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_const_mut(&mut self, node: &mut ImplItemConst) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_impl_item_const_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_fn_mut(&mut self, node: &mut ImplItemFn) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_impl_item_fn_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_macro_mut(&mut self, node: &mut ImplItemMacro) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_impl_item_macro_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_type_mut(&mut self, node: &mut ImplItemType) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_impl_item_type_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_const_mut(&mut self, node: &mut ItemConst) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_const_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_enum_mut(&mut self, node: &mut ItemEnum) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_enum_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_extern_crate_mut(&mut self, node: &mut ItemExternCrate) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_extern_crate_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn_mut(&mut self, node: &mut ItemFn) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_fn_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_foreign_mod_mut(&mut self, node: &mut ItemForeignMod) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_foreign_mod_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_impl_mut(&mut self, node: &mut ItemImpl) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_impl_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro_mut(&mut self, node: &mut ItemMacro) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_macro_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mod_mut(&mut self, node: &mut ItemMod) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_mod_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_static_mut(&mut self, node: &mut ItemStatic) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_static_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_struct_mut(&mut self, node: &mut ItemStruct) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_struct_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_mut(&mut self, node: &mut ItemTrait) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_trait_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_alias_mut(&mut self, node: &mut ItemTraitAlias) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_trait_alias_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_type_mut(&mut self, node: &mut ItemType) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_type_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_union_mut(&mut self, node: &mut ItemUnion) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_union_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_use_mut(&mut self, node: &mut ItemUse) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_item_use_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lifetime_param_mut(&mut self, node: &mut LifetimeParam) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_lifetime_param_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_local_mut(&mut self, node: &mut Local) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_local_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_ident_mut(&mut self, node: &mut PatIdent) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_ident_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_or_mut(&mut self, node: &mut PatOr) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_or_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_paren_mut(&mut self, node: &mut PatParen) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_paren_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_reference_mut(&mut self, node: &mut PatReference) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_reference_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_rest_mut(&mut self, node: &mut PatRest) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_rest_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_slice_mut(&mut self, node: &mut PatSlice) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_slice_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_struct_mut(&mut self, node: &mut PatStruct) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_struct_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_mut(&mut self, node: &mut PatTuple) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_tuple_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_struct_mut(&mut self, node: &mut PatTupleStruct) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_tuple_struct_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_type_mut(&mut self, node: &mut PatType) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_type_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_wild_mut(&mut self, node: &mut PatWild) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_pat_wild_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_receiver_mut(&mut self, node: &mut Receiver) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_receiver_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_stmt_macro_mut(&mut self, node: &mut StmtMacro) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_stmt_macro_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_const_mut(&mut self, node: &mut TraitItemConst) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_trait_item_const_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_fn_mut(&mut self, node: &mut TraitItemFn) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_trait_item_fn_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_macro_mut(&mut self, node: &mut TraitItemMacro) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_trait_item_macro_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_type_mut(&mut self, node: &mut TraitItemType) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_trait_item_type_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param_mut(&mut self, node: &mut TypeParam) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_type_param_mut(self, node);
+    }
+    #[cfg(feature = "full")]
+    fn visit_variadic_mut(&mut self, node: &mut Variadic) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_variadic_mut(self, node);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variant_mut(&mut self, node: &mut Variant) {
+        node.attrs.retain(|attr| !(self.pred)(attr));
+        visit_variant_mut(self, node);
+    }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_abi_mut<V>(v: &mut V, node: &mut Abi)
@@ -780,7 +1370,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_generic_argument_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     tokens_helper(v, &mut node.gt_token.spans);
@@ -804,6 +1394,132 @@ where
         tokens_helper(v, &mut it.spans);
     }
 }
+#[cfg(feature = "full")]
+pub fn visit_asm_arg_mut<V>(v: &mut V, node: &mut AsmArg)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        AsmArg::Template(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+        AsmArg::Operand(_binding_0) => {
+            v.visit_asm_operand_mut(_binding_0);
+        }
+        AsmArg::ClobberAbi(_binding_0) => {
+            v.visit_asm_clobber_abi_mut(_binding_0);
+        }
+        AsmArg::Options(_binding_0) => {
+            v.visit_asm_options_mut(_binding_0);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_clobber_abi_mut<V>(v: &mut V, node: &mut AsmClobberAbi)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.clobber_abi_token);
+    tokens_helper(v, &mut node.paren_token.span);
+    for el in Punctuated::pairs_mut(&mut node.abis) {
+        let (it, p) = el.into_tuple();
+        v.visit_lit_str_mut(it);
+        if let Some(p) = p {
+            v.visit_punct_mut(",", &mut p.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_direction_mut<V>(v: &mut V, node: &mut AsmDirection)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        AsmDirection::In => {}
+        AsmDirection::Out => {}
+        AsmDirection::LateOut => {}
+        AsmDirection::InOut => {}
+        AsmDirection::InLateOut => {}
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_operand_mut<V>(v: &mut V, node: &mut AsmOperand)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.name {
+        v.visit_ident_mut(&mut (it).0);
+        tokens_helper(v, &mut (it).1.spans);
+    }
+    v.visit_asm_operand_kind_mut(&mut node.kind);
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_operand_kind_mut<V>(v: &mut V, node: &mut AsmOperandKind)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        AsmOperandKind::Reg(_binding_0) => {
+            v.visit_asm_reg_operand_mut(_binding_0);
+        }
+        AsmOperandKind::Const(_binding_0, _binding_1) => {
+            tokens_helper(v, &mut _binding_0.span);
+            v.visit_expr_mut(_binding_1);
+        }
+        AsmOperandKind::Sym(_binding_0, _binding_1) => {
+            v.visit_ident_mut(_binding_0);
+            v.visit_path_mut(_binding_1);
+        }
+        AsmOperandKind::Label(_binding_0, _binding_1) => {
+            v.visit_ident_mut(_binding_0);
+            v.visit_block_mut(_binding_1);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_options_mut<V>(v: &mut V, node: &mut AsmOptions)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.options_token);
+    tokens_helper(v, &mut node.paren_token.span);
+    for el in Punctuated::pairs_mut(&mut node.options) {
+        let (it, p) = el.into_tuple();
+        v.visit_ident_mut(it);
+        if let Some(p) = p {
+            v.visit_punct_mut(",", &mut p.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_reg_mut<V>(v: &mut V, node: &mut AsmReg)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        AsmReg::Class(_binding_0) => {
+            v.visit_ident_mut(_binding_0);
+        }
+        AsmReg::Explicit(_binding_0) => {
+            v.visit_lit_str_mut(_binding_0);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_asm_reg_operand_mut<V>(v: &mut V, node: &mut AsmRegOperand)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_asm_direction_mut(&mut node.direction);
+    v.visit_span_mut(&mut node.direction_span);
+    tokens_helper(v, &mut node.paren_token.span);
+    v.visit_asm_reg_mut(&mut node.reg);
+    v.visit_expr_mut(&mut node.expr);
+    if let Some(it) = &mut node.out_expr {
+        tokens_helper(v, &mut (it).0.spans);
+        v.visit_expr_mut(&mut (it).1);
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_assoc_const_mut<V>(v: &mut V, node: &mut AssocConst)
 where
@@ -994,7 +1710,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_generic_param_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     tokens_helper(v, &mut node.gt_token.spans);
@@ -1032,7 +1748,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
 }
@@ -1064,7 +1780,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_variant_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -1118,6 +1834,9 @@ where
         Expr::Await(_binding_0) => {
             full!(v.visit_expr_await_mut(_binding_0));
         }
+        Expr::Become(_binding_0) => {
+            full!(v.visit_expr_become_mut(_binding_0));
+        }
         Expr::Binary(_binding_0) => {
             v.visit_expr_binary_mut(_binding_0);
         }
@@ -1187,6 +1906,9 @@ where
         Expr::Range(_binding_0) => {
             full!(v.visit_expr_range_mut(_binding_0));
         }
+        Expr::RawAddr(_binding_0) => {
+            full!(v.visit_expr_raw_addr_mut(_binding_0));
+        }
         Expr::Reference(_binding_0) => {
             full!(v.visit_expr_reference_mut(_binding_0));
         }
@@ -1238,7 +1960,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_expr_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -1280,6 +2002,17 @@ where
     tokens_helper(v, &mut node.dot_token.spans);
     tokens_helper(v, &mut node.await_token.span);
 }
+#[cfg(feature = "full")]
+pub fn visit_expr_become_mut<V>(v: &mut V, node: &mut ExprBecome)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it);
+    }
+    tokens_helper(v, &mut node.become_token.span);
+    v.visit_expr_mut(&mut *node.expr);
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_expr_binary_mut<V>(v: &mut V, node: &mut ExprBinary)
 where
@@ -1335,7 +2068,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_expr_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -1379,7 +2112,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     tokens_helper(v, &mut node.or2_token.spans);
@@ -1569,7 +2302,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_expr_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -1614,6 +2347,19 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_raw_addr_mut<V>(v: &mut V, node: &mut ExprRawAddr)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it);
+    }
+    tokens_helper(v, &mut node.and_token.spans);
+    tokens_helper(v, &mut node.raw.span);
+    v.visit_pointer_mutability_mut(&mut node.mutability);
+    v.visit_expr_mut(&mut *node.expr);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_reference_mut<V>(v: &mut V, node: &mut ExprReference)
 where
     V: VisitMut + ?Sized,
@@ -1670,7 +2416,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_field_value_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.dot2_token {
@@ -1715,7 +2461,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_expr_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -1786,6 +2532,10 @@ where
         tokens_helper(v, &mut it.spans);
     }
     v.visit_type_mut(&mut node.ty);
+    if let Some(it) = &mut node.default {
+        tokens_helper(v, &mut (it).0.spans);
+        v.visit_expr_mut(&mut (it).1);
+    }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_field_mutability_mut<V>(v: &mut V, node: &mut FieldMutability)
@@ -1849,7 +2599,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_field_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -1863,7 +2613,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_field_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -2027,7 +2777,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_generic_param_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.gt_token {
@@ -2152,6 +2902,19 @@ where
     v.visit_span_mut(&mut node.span);
 }
 #[cfg(feature = "full")]
+pub fn visit_inline_asm_mut<V>(v: &mut V, node: &mut InlineAsm)
+where
+    V: VisitMut + ?Sized,
+{
+    for el in Punctuated::pairs_mut(&mut node.args) {
+        let (it, p) = el.into_tuple();
+        v.visit_asm_arg_mut(it);
+        if let Some(p) = p {
+            v.visit_punct_mut(",", &mut p.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
 pub fn visit_item_mut<V>(v: &mut V, node: &mut Item)
 where
     V: VisitMut + ?Sized,
@@ -2242,7 +3005,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_variant_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -2425,7 +3188,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
     tokens_helper(v, &mut node.brace_token.span);
@@ -2450,7 +3213,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
     tokens_helper(v, &mut node.semi_token.spans);
@@ -2532,7 +3295,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_lifetime_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
 }
@@ -2714,7 +3477,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     v.visit_return_type_mut(&mut node.output);
@@ -2813,7 +3576,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("|", &mut p.spans);
         }
     }
 }
@@ -2865,7 +3628,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -2886,7 +3649,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_field_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.rest {
@@ -2906,7 +3669,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -2927,7 +3690,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_pat_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -2965,7 +3728,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_path_segment_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("::", &mut p.spans);
         }
     }
 }
@@ -2992,6 +3755,20 @@ where
     v.visit_ident_mut(&mut node.ident);
     v.visit_path_arguments_mut(&mut node.arguments);
 }
+#[cfg(feature = "full")]
+pub fn visit_pointer_mutability_mut<V>(v: &mut V, node: &mut PointerMutability)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.span);
+        }
+        PointerMutability::Mut(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.span);
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_predicate_lifetime_mut<V>(v: &mut V, node: &mut PredicateLifetime)
 where
@@ -3003,7 +3780,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_lifetime_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
 }
@@ -3021,7 +3798,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
 }
@@ -3113,7 +3890,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_fn_arg_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.variadic {
@@ -3197,6 +3974,10 @@ where
         TraitBoundModifier::Maybe(_binding_0) => {
             tokens_helper(v, &mut _binding_0.spans);
         }
+        TraitBoundModifier::MaybeConst(_binding_0, _binding_1) => {
+            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, &mut _binding_1.span);
+        }
     }
 }
 #[cfg(feature = "full")]
@@ -3288,7 +4069,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.default {
@@ -3380,7 +4161,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_bare_fn_arg_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.variadic {
@@ -3406,7 +4187,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
 }
@@ -3447,7 +4228,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
     if let Some(it) = &mut node.eq_token {
@@ -3540,7 +4321,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_param_bound_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut("+", &mut p.spans);
         }
     }
 }
@@ -3554,7 +4335,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_type_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -3592,7 +4373,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_use_tree_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -3713,7 +4494,7 @@ where
         let (it, p) = el.into_tuple();
         v.visit_where_predicate_mut(it);
         if let Some(p) = p {
-            tokens_helper(v, &mut p.spans);
+            v.visit_punct_mut(",", &mut p.spans);
         }
     }
 }
@@ -3731,3 +4512,11 @@ where
         }
     }
 }
+pub fn visit_punct_mut<V>(v: &mut V, punct: &str, spans: &mut [Span])
+where
+    V: VisitMut + ?Sized,
+{
+    for span in spans {
+        v.visit_span_mut(span);
+    }
+}
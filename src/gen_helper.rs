@@ -2,6 +2,8 @@
 pub(crate) mod fold {
     use crate::fold::Fold;
     use crate::punctuated::{Pair, Punctuated};
+    use crate::span::IntoSpans;
+    use proc_macro2::extra::DelimSpan;
     use proc_macro2::Span;
 
     pub(crate) trait FoldHelper {
@@ -69,11 +71,20 @@ pub(crate) mod fold {
             ]
         }
     }
+
+    // A DelimSpan cannot be assembled from separate open and close spans, so
+    // the folded group gets the folded span of the whole group throughout.
+    impl Spans for DelimSpan {
+        fn fold<F: Fold + ?Sized>(&self, folder: &mut F) -> Self {
+            folder.fold_span(self.join()).into_spans()
+        }
+    }
 }
 
 #[cfg(feature = "visit")]
 pub(crate) mod visit {
     use crate::visit::Visit;
+    use proc_macro2::extra::DelimSpan;
     use proc_macro2::Span;
 
     pub(crate) fn tokens_helper<'ast, V: Visit<'ast> + ?Sized, S: Spans>(
@@ -113,11 +124,19 @@ pub(crate) mod visit {
             visitor.visit_span(&self[2]);
         }
     }
+
+    impl Spans for DelimSpan {
+        fn visit<'ast, V: Visit<'ast> + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_span(&self.join());
+        }
+    }
 }
 
 #[cfg(feature = "visit-mut")]
 pub(crate) mod visit_mut {
+    use crate::span::IntoSpans;
     use crate::visit_mut::VisitMut;
+    use proc_macro2::extra::DelimSpan;
     use proc_macro2::Span;
 
     pub(crate) fn tokens_helper<V: VisitMut + ?Sized, S: Spans>(visitor: &mut V, spans: &mut S) {
@@ -154,4 +173,13 @@ pub(crate) mod visit_mut {
             visitor.visit_span_mut(&mut self[2]);
         }
     }
+
+    // See the corresponding comment in the fold helper.
+    impl Spans for DelimSpan {
+        fn visit_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            let mut span = self.join();
+            visitor.visit_span_mut(&mut span);
+            *self = span.into_spans();
+        }
+    }
 }
@@ -1,10 +1,19 @@
 use super::*;
 use crate::punctuated::{Iter, IterMut, Punctuated};
-use proc_macro2::TokenStream;
+use crate::ty::references::{self, Target};
+use proc_macro2::{Span, TokenStream};
+#[cfg(feature = "printing")]
+use quote::ToTokens;
+#[cfg(feature = "extra-traits")]
+use std::collections::hash_map::{DefaultHasher, HashMap};
+#[cfg(feature = "extra-traits")]
+use std::collections::HashSet;
 #[cfg(all(feature = "printing", feature = "extra-traits"))]
 use std::fmt::{self, Debug};
-#[cfg(all(feature = "printing", feature = "extra-traits"))]
+#[cfg(feature = "extra-traits")]
 use std::hash::{Hash, Hasher};
+#[cfg(any(feature = "clone-impls", feature = "extra-traits"))]
+use std::mem;
 
 ast_struct! {
     /// Lifetimes and type parameters attached to a declaration of a function,
@@ -170,6 +179,373 @@ impl Generics {
             predicates: Punctuated::new(),
         })
     }
+
+    /// Appends the generic parameters and `where` predicates of `other` to
+    /// these generics.
+    ///
+    /// Lifetime parameters are put ahead of type and const parameters, as the
+    /// language requires, and the parameters otherwise keep their order with
+    /// those of `self` first. The predicates of `other` are added at the end
+    /// of the `where` clause, which is created if needed.
+    ///
+    /// Parameters are not deduplicated: if both sides declare a parameter of
+    /// the same name, such as `T`, the result declares it twice. Renaming or
+    /// skipping such parameters is up to the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Generics};
+    ///
+    /// let mut generics: Generics = parse_quote!(<T: Clone>);
+    /// generics.where_clause = Some(parse_quote!(where T: Send));
+    ///
+    /// let mut extra: Generics = parse_quote!(<'a, U: 'a>);
+    /// extra.where_clause = Some(parse_quote!(where U: Debug));
+    ///
+    /// generics.merge(&extra);
+    ///
+    /// let (impl_generics, _, where_clause) = generics.split_for_impl();
+    /// let expected = quote! {
+    ///     <'a, T: Clone, U: 'a> where T: Send, U: Debug
+    /// };
+    /// assert_eq!(
+    ///     quote!(#impl_generics #where_clause).to_string(),
+    ///     expected.to_string(),
+    /// );
+    /// ```
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "clone-impls")))
+    )]
+    pub fn merge(&mut self, other: &Generics) {
+        if !other.params.is_empty() {
+            let params = mem::take(&mut self.params);
+            let (lifetimes, others): (Vec<GenericParam>, Vec<GenericParam>) = params
+                .into_iter()
+                .chain(other.params.iter().cloned())
+                .partition(|param| matches!(param, GenericParam::Lifetime(_)));
+            self.params.extend(lifetimes);
+            self.params.extend(others);
+            self.lt_token = self
+                .lt_token
+                .or(other.lt_token)
+                .or_else(|| Some(Default::default()));
+            self.gt_token = self
+                .gt_token
+                .or(other.gt_token)
+                .or_else(|| Some(Default::default()));
+        }
+        if let Some(where_clause) = &other.where_clause {
+            self.make_where_clause()
+                .predicates
+                .extend(where_clause.predicates.iter().cloned());
+        }
+    }
+
+    /// Stores every distinct bound only once, folding together repeated
+    /// bounds of a parameter and `where` predicates that bound the same type
+    /// or lifetime.
+    ///
+    /// Each bound on a type or lifetime parameter that is identical to an
+    /// earlier bound on the same parameter is removed. In the `where` clause,
+    /// a predicate whose bounded type (including its `for<...>` binder) or
+    /// lifetime was already bounded by an earlier predicate has its bounds
+    /// appended to that predicate instead, and repeated bounds are then
+    /// removed the same way, so `where T: Clone, T: Send + Clone` becomes
+    /// `where T: Clone + Send`. The bounds of a parameter and those of a
+    /// predicate on it are not combined.
+    ///
+    /// This is meant to follow [`merge`][Generics::merge] when combining many
+    /// generics that repeat the same bounds, as generated code often does.
+    /// Bounds are compared through their [`Hash`] and [`Eq`] impls, so the
+    /// work is linear in the number of bounds, and nothing is cloned: the
+    /// first occurrence of each bound is kept and the rest are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Generics};
+    ///
+    /// let mut generics: Generics = parse_quote!(<T: Clone + Clone, U>);
+    /// generics.where_clause = Some(parse_quote!(where U: Send, U: Send + Sync));
+    ///
+    /// generics.intern_bounds();
+    ///
+    /// let mut expected: Generics = parse_quote!(<T: Clone, U>);
+    /// expected.where_clause = Some(parse_quote!(where U: Send + Sync));
+    /// assert_eq!(generics, expected);
+    /// ```
+    #[cfg(feature = "extra-traits")]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "extra-traits")))
+    )]
+    pub fn intern_bounds(&mut self) {
+        for param in &mut self.params {
+            match param {
+                GenericParam::Type(param) => dedup_punctuated(&mut param.bounds),
+                GenericParam::Lifetime(param) => dedup_punctuated(&mut param.bounds),
+                GenericParam::Const(_) => {}
+            }
+        }
+
+        let where_clause = match &mut self.where_clause {
+            Some(where_clause) => where_clause,
+            None => return,
+        };
+        let mut predicates: Vec<WherePredicate> = Vec::new();
+        let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+        for predicate in mem::take(&mut where_clause.predicates) {
+            let mut hasher = DefaultHasher::new();
+            match &predicate {
+                WherePredicate::Type(predicate) => {
+                    (&predicate.lifetimes, &predicate.bounded_ty).hash(&mut hasher);
+                }
+                WherePredicate::Lifetime(predicate) => predicate.lifetime.hash(&mut hasher),
+            }
+            let candidates = seen.entry(hasher.finish()).or_default();
+            let earlier =
+                candidates
+                    .iter()
+                    .copied()
+                    .find(|&i| match (&predicates[i], &predicate) {
+                        (WherePredicate::Type(a), WherePredicate::Type(b)) => {
+                            a.lifetimes == b.lifetimes && a.bounded_ty == b.bounded_ty
+                        }
+                        (WherePredicate::Lifetime(a), WherePredicate::Lifetime(b)) => {
+                            a.lifetime == b.lifetime
+                        }
+                        _ => false,
+                    });
+            match (earlier.map(|i| &mut predicates[i]), predicate) {
+                (Some(WherePredicate::Type(earlier)), WherePredicate::Type(predicate)) => {
+                    earlier.bounds.extend(predicate.bounds);
+                }
+                (Some(WherePredicate::Lifetime(earlier)), WherePredicate::Lifetime(predicate)) => {
+                    earlier.bounds.extend(predicate.bounds);
+                }
+                (_, predicate) => {
+                    candidates.push(predicates.len());
+                    predicates.push(predicate);
+                }
+            }
+        }
+        for predicate in &mut predicates {
+            match predicate {
+                WherePredicate::Type(predicate) => dedup_punctuated(&mut predicate.bounds),
+                WherePredicate::Lifetime(predicate) => dedup_punctuated(&mut predicate.bounds),
+            }
+        }
+        where_clause.predicates = predicates.into_iter().collect();
+    }
+
+    /// Builds a `::core::marker::PhantomData` type mentioning every lifetime
+    /// and type parameter, with the given variance over each of them.
+    ///
+    /// This is the type of a marker field for a generated struct that needs
+    /// to be generic over the same parameters as the input but does not
+    /// otherwise use all of them. Each parameter is wrapped in a tuple
+    /// element according to `variance`: a type parameter `T` becomes
+    /// `*const T`, `fn(T)` or `*mut T`, and a lifetime `'a` is first turned
+    /// into the type `&'a ()`. Const parameters are left out, since they have
+    /// no variance.
+    ///
+    /// The raw pointers make the marker neither `Send` nor `Sync`, so a
+    /// generated type containing it may need an explicit impl of those
+    /// traits.
+    ///
+    /// ```
+    /// use syn::{parse_quote, Generics, Type, Variance};
+    ///
+    /// let generics: Generics = parse_quote!(<'a, T, const N: usize>);
+    ///
+    /// let marker = generics.phantom_data_variant(Variance::Invariant);
+    /// let expected: Type = parse_quote! {
+    ///     ::core::marker::PhantomData<(*mut &'a (), *mut T)>
+    /// };
+    /// assert_eq!(marker, expected);
+    /// ```
+    pub fn phantom_data_variant(&self, variance: Variance) -> Type {
+        let mut elems = Punctuated::new();
+        for param in &self.params {
+            let elem = match param {
+                GenericParam::Lifetime(param) => Type::Reference(TypeReference {
+                    and_token: <Token![&]>::default(),
+                    lifetime: Some(param.lifetime.clone()),
+                    mutability: None,
+                    elem: Box::new(Type::Tuple(TypeTuple {
+                        paren_token: token::Paren::default(),
+                        elems: Punctuated::new(),
+                    })),
+                }),
+                GenericParam::Type(param) => Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(param.ident.clone()),
+                }),
+                GenericParam::Const(_) => continue,
+            };
+            elems.push(variance.encode(elem));
+        }
+        if elems.len() == 1 {
+            elems.push_punct(<Token![,]>::default());
+        }
+
+        let mut args = Punctuated::new();
+        args.push(GenericArgument::Type(Type::Tuple(TypeTuple {
+            paren_token: token::Paren::default(),
+            elems,
+        })));
+
+        let span = Span::call_site();
+        let mut path = Path {
+            leading_colon: Some(<Token![::]>::default()),
+            segments: Punctuated::new(),
+        };
+        path.segments
+            .push(PathSegment::from(Ident::new("core", span)));
+        path.segments
+            .push(PathSegment::from(Ident::new("marker", span)));
+        path.segments.push(PathSegment {
+            ident: Ident::new("PhantomData", span),
+            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: <Token![<]>::default(),
+                args,
+                gt_token: <Token![>]>::default(),
+            }),
+        });
+        Type::Path(TypePath { qself: None, path })
+    }
+
+    /// Finds the generic parameters that are not mentioned by any of `tys`,
+    /// for example to report a parameter that a generated impl never uses or
+    /// to add a `PhantomData` field for it.
+    ///
+    /// Type parameters are searched for like by
+    /// [`Type::references_ident`] and lifetimes like by
+    /// [`Type::references_lifetime`], so the `T` of an associated type like
+    /// `<X as Trait>::T` is not a use of a parameter `T`. Const parameters
+    /// are also searched for in array lengths and const generic arguments
+    /// made of paths, literals, operators and blocks, like `N + 1`. Macros
+    /// are not searched. Parameters are returned in declaration order.
+    ///
+    /// ```
+    /// use syn::{parse_quote, Generics, GenericParam, Type};
+    ///
+    /// let generics: Generics = parse_quote!(<'a, 'b, T, U, const N: usize>);
+    /// let field: Type = parse_quote!(&'a [T; N]);
+    ///
+    /// let unused: Vec<String> = generics
+    ///     .unused_params_in(&[&field])
+    ///     .into_iter()
+    ///     .map(|param| match param {
+    ///         GenericParam::Lifetime(param) => param.lifetime.to_string(),
+    ///         GenericParam::Type(param) => param.ident.to_string(),
+    ///         GenericParam::Const(param) => param.ident.to_string(),
+    ///     })
+    ///     .collect();
+    /// assert_eq!(unused, ["'b", "U"]);
+    /// ```
+    pub fn unused_params_in(&self, tys: &[&Type]) -> Vec<&GenericParam> {
+        self.params
+            .iter()
+            .filter(|param| {
+                let target = match param {
+                    GenericParam::Lifetime(param) => Target::Lifetime(&param.lifetime),
+                    GenericParam::Type(param) => Target::Ident(&param.ident),
+                    GenericParam::Const(param) => Target::Const(&param.ident),
+                };
+                !tys.iter().any(|ty| references::ty(ty, &target))
+            })
+            .collect()
+    }
+}
+
+/// The variance of a marker type built by [`Generics::phantom_data_variant`]
+/// over the generic parameters.
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub enum Variance {
+    /// `*const T`: the marker is a subtype of the marker for a subtype of
+    /// `T`, like for a field of type `T`.
+    Covariant,
+
+    /// `fn(T)`: the subtyping relation of `T` is reversed, like for a field
+    /// holding a function that takes a `T`.
+    Contravariant,
+
+    /// `*mut T`: no subtyping is allowed, like for a field of type
+    /// `Cell<T>`.
+    Invariant,
+}
+
+// Removes every element equal to an earlier one, keeping the order of the
+// remaining elements.
+#[cfg(feature = "extra-traits")]
+fn dedup_punctuated<T: Hash + Eq, P: Default>(punctuated: &mut Punctuated<T, P>) {
+    let keep: Vec<bool> = {
+        let mut seen = HashSet::new();
+        punctuated
+            .iter()
+            .map(|element| seen.insert(element))
+            .collect()
+    };
+    if keep.iter().all(|&keep| keep) {
+        return;
+    }
+    for (element, keep) in mem::take(punctuated).into_iter().zip(keep) {
+        if keep {
+            punctuated.push(element);
+        }
+    }
+}
+
+impl Copy for Variance {}
+
+impl Clone for Variance {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Variance {
+    fn encode(self, elem: Type) -> Type {
+        let elem = Box::new(elem);
+        match self {
+            Variance::Covariant => Type::Ptr(TypePtr {
+                star_token: <Token![*]>::default(),
+                const_token: Some(<Token![const]>::default()),
+                mutability: None,
+                elem,
+            }),
+            Variance::Contravariant => {
+                let mut inputs = Punctuated::new();
+                inputs.push(BareFnArg {
+                    attrs: Vec::new(),
+                    name: None,
+                    ty: *elem,
+                });
+                Type::BareFn(TypeBareFn {
+                    lifetimes: None,
+                    unsafety: None,
+                    abi: None,
+                    fn_token: <Token![fn]>::default(),
+                    paren_token: token::Paren::default(),
+                    inputs,
+                    variadic: None,
+                    output: ReturnType::Default,
+                })
+            }
+            Variance::Invariant => Type::Ptr(TypePtr {
+                star_token: <Token![*]>::default(),
+                const_token: None,
+                mutability: Some(<Token![mut]>::default()),
+                elem,
+            }),
+        }
+    }
 }
 
 pub struct Lifetimes<'a>(Iter<'a, GenericParam>);
@@ -335,6 +711,51 @@ impl Generics {
             self.where_clause.as_ref(),
         )
     }
+
+    /// Assemble the header of an impl block for a type with these generics,
+    /// up to but not including the braces.
+    ///
+    /// Produces `impl #impl_generics #trait_ for #self_ty #where_clause`, or
+    /// `impl #impl_generics #self_ty #where_clause` for an inherent impl if
+    /// `trait_` is `None`. The `self_ty` is expected to already carry its
+    /// generic arguments, as in `MyType<T>`.
+    ///
+    /// ```
+    /// # use proc_macro2::{Span, Ident};
+    /// # use quote::quote;
+    /// # use syn::{parse_quote, Path, Type};
+    /// #
+    /// # let generics: syn::Generics = Default::default();
+    /// # let name = Ident::new("MyType", Span::call_site());
+    /// #
+    /// let (_, ty_generics, _) = generics.split_for_impl();
+    /// let trait_: Path = parse_quote!(MyTrait);
+    /// let self_ty: Type = parse_quote!(#name #ty_generics);
+    /// let header = generics.impl_header(Some(&trait_), &self_ty);
+    /// quote! {
+    ///     #header {
+    ///         // ...
+    ///     }
+    /// }
+    /// # ;
+    /// ```
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    )]
+    pub fn impl_header(&self, trait_: Option<&Path>, self_ty: &Type) -> TokenStream {
+        let (impl_generics, _ty_generics, where_clause) = self.split_for_impl();
+        let mut tokens = TokenStream::new();
+        <Token![impl]>::default().to_tokens(&mut tokens);
+        impl_generics.to_tokens(&mut tokens);
+        if let Some(trait_) = trait_ {
+            trait_.to_tokens(&mut tokens);
+            <Token![for]>::default().to_tokens(&mut tokens);
+        }
+        self_ty.to_tokens(&mut tokens);
+        where_clause.to_tokens(&mut tokens);
+        tokens
+    }
 }
 
 #[cfg(feature = "printing")]
@@ -418,6 +839,33 @@ impl Default for BoundLifetimes {
     }
 }
 
+impl BoundLifetimes {
+    /// Creates a `for<...>` binder introducing the given lifetimes, for
+    /// building higher-ranked trait bounds.
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use quote::quote;
+    /// use syn::{BoundLifetimes, Lifetime};
+    ///
+    /// let a = Lifetime::new("'a", Span::call_site());
+    /// let b = Lifetime::new("'b", Span::call_site());
+    /// let binder = BoundLifetimes::new(vec![a, b]);
+    /// assert_eq!(quote!(#binder).to_string(), "for < 'a , 'b >");
+    /// ```
+    pub fn new(lifetimes: impl IntoIterator<Item = Lifetime>) -> Self {
+        BoundLifetimes {
+            for_token: Default::default(),
+            lt_token: Default::default(),
+            lifetimes: lifetimes
+                .into_iter()
+                .map(|lifetime| GenericParam::Lifetime(LifetimeParam::new(lifetime)))
+                .collect(),
+            gt_token: Default::default(),
+        }
+    }
+}
+
 impl LifetimeParam {
     pub fn new(lifetime: Lifetime) -> Self {
         LifetimeParam {
@@ -442,6 +890,57 @@ impl From<Ident> for TypeParam {
     }
 }
 
+impl TypeParam {
+    /// Adds `bound` after the existing bounds of this type parameter.
+    ///
+    /// If the parameter had no bounds, the `:` separating them from the name
+    /// is inserted as well, spanned like the name of the parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, TypeParam};
+    ///
+    /// let mut param: TypeParam = parse_quote!(T = ());
+    /// param.add_bound(parse_quote!(Clone));
+    /// param.add_bound(parse_quote!('static));
+    ///
+    /// let expected: TypeParam = parse_quote!(T: Clone + 'static = ());
+    /// assert_eq!(param, expected);
+    /// ```
+    pub fn add_bound(&mut self, bound: TypeParamBound) {
+        if self.colon_token.is_none() {
+            self.colon_token = Some(Token![:](self.ident.span()));
+        }
+        self.bounds.push(bound);
+    }
+
+    /// Determines whether one of the bounds of this type parameter is the
+    /// trait `path`.
+    ///
+    /// The trait paths are compared by the names of their segments and the
+    /// presence of a leading `::`, ignoring generic arguments, so the path
+    /// `Into` matches the bound `Into<String>` but not `std::convert::Into`.
+    /// A relaxed bound like `?Sized` does not count as a bound on `Sized`.
+    /// Bounds given in a `where` clause are not considered.
+    pub fn has_bound(&self, path: &Path) -> bool {
+        self.bounds.iter().any(|bound| match bound {
+            TypeParamBound::Trait(bound) => {
+                !matches!(bound.modifier, TraitBoundModifier::Maybe(_))
+                    && bound.path.leading_colon.is_some() == path.leading_colon.is_some()
+                    && bound.path.segments.len() == path.segments.len()
+                    && bound
+                        .path
+                        .segments
+                        .iter()
+                        .zip(&path.segments)
+                        .all(|(a, b)| a.ident == b.ident)
+            }
+            _ => false,
+        })
+    }
+}
+
 ast_enum_of_structs! {
     /// A trait or lifetime used as a bound on a type parameter.
     #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
@@ -466,13 +965,29 @@ ast_struct! {
     }
 }
 
+impl TraitBound {
+    /// Returns the `for<'a>` binder of a higher-ranked bound, if any.
+    pub fn lifetimes(&self) -> Option<&BoundLifetimes> {
+        self.lifetimes.as_ref()
+    }
+}
+
 ast_enum! {
-    /// A modifier on a trait bound, currently only used for the `?` in
-    /// `?Sized`.
+    /// A modifier on a trait bound: the `?` in `?Sized` or the `~const` in
+    /// `~const Default`.
+    ///
+    /// This enum is non-exhaustive because the set of bound modifiers is
+    /// still evolving in unstable Rust, like `~const` did, and new ones need
+    /// to be representable without a breaking change.
     #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    #[non_exhaustive]
     pub enum TraitBoundModifier {
         None,
         Maybe(Token![?]),
+        /// A bound that is only required to be const in a const context:
+        /// `~const Trait`. This is an unstable language feature, which is
+        /// only parsed with the `"full"` feature.
+        MaybeConst(Token![~], Token![const]),
     }
 }
 
@@ -486,6 +1001,79 @@ ast_struct! {
     }
 }
 
+#[cfg(feature = "printing")]
+impl WhereClause {
+    /// Returns an iterator over the type predicates whose bounded type is
+    /// `ty`, in the order they appear in the `where` clause.
+    ///
+    /// Types are compared with [`Type::eq_with`] using the default
+    /// [`TypeEqOptions`], so parentheses and invisible groups around the
+    /// bounded type do not matter but lifetimes and paths must match exactly.
+    /// A predicate with a `for<'a>` binder is included if its bounded type
+    /// matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, WhereClause};
+    ///
+    /// let where_clause: WhereClause = parse_quote! {
+    ///     where T: Clone, U: Copy, 'a: 'b, T: Send + 'a
+    /// };
+    ///
+    /// let bounds: Vec<_> = where_clause
+    ///     .predicates_for(&parse_quote!(T))
+    ///     .map(|predicate| predicate.bounds.len())
+    ///     .collect();
+    /// assert_eq!(bounds, [1, 2]);
+    /// ```
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    )]
+    pub fn predicates_for<'a>(
+        &'a self,
+        ty: &'a Type,
+    ) -> impl Iterator<Item = &'a PredicateType> + 'a {
+        self.predicates
+            .iter()
+            .filter_map(move |predicate| match predicate {
+                WherePredicate::Type(predicate)
+                    if predicate.bounded_ty.eq_with(ty, TypeEqOptions::default()) =>
+                {
+                    Some(predicate)
+                }
+                _ => None,
+            })
+    }
+
+    /// Returns an iterator over mutable references to the type predicates
+    /// whose bounded type is `ty`, as found by
+    /// [`predicates_for`][WhereClause::predicates_for].
+    ///
+    /// This allows adding bounds to an existing predicate instead of
+    /// repeating the bounded type in a new one.
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    )]
+    pub fn predicates_for_mut<'a>(
+        &'a mut self,
+        ty: &'a Type,
+    ) -> impl Iterator<Item = &'a mut PredicateType> + 'a {
+        self.predicates
+            .iter_mut()
+            .filter_map(move |predicate| match predicate {
+                WherePredicate::Type(predicate)
+                    if predicate.bounded_ty.eq_with(ty, TypeEqOptions::default()) =>
+                {
+                    Some(predicate)
+                }
+                _ => None,
+            })
+    }
+}
+
 ast_enum_of_structs! {
     /// A single predicate in a `where` clause: `T: Deserialize<'de>`.
     ///
@@ -529,6 +1117,49 @@ ast_struct! {
     }
 }
 
+impl WherePredicate {
+    /// Creates a type predicate `ty: bounds` without a `for<...>` binder.
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Type, TypeParamBound, WherePredicate};
+    ///
+    /// let ty: Type = parse_quote!(Foo<T>);
+    /// let bounds: Vec<TypeParamBound> = vec![parse_quote!(Clone), parse_quote!(Send)];
+    /// let predicate = WherePredicate::bound(ty, bounds);
+    /// assert_eq!(quote!(#predicate).to_string(), "Foo < T > : Clone + Send");
+    /// ```
+    pub fn bound(ty: Type, bounds: impl IntoIterator<Item = TypeParamBound>) -> Self {
+        WherePredicate::Type(PredicateType {
+            lifetimes: None,
+            bounded_ty: ty,
+            colon_token: Default::default(),
+            bounds: bounds.into_iter().collect(),
+        })
+    }
+
+    /// Creates a lifetime predicate `lifetime: bounds`, which requires
+    /// `lifetime` to outlive each of `bounds`.
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use quote::quote;
+    /// use syn::{Lifetime, WherePredicate};
+    ///
+    /// let a = Lifetime::new("'a", Span::call_site());
+    /// let b = Lifetime::new("'b", Span::call_site());
+    /// let predicate = WherePredicate::outlives(a, vec![b]);
+    /// assert_eq!(quote!(#predicate).to_string(), "'a : 'b");
+    /// ```
+    pub fn outlives(lifetime: Lifetime, bounds: impl IntoIterator<Item = Lifetime>) -> Self {
+        WherePredicate::Lifetime(PredicateLifetime {
+            lifetime,
+            colon_token: Default::default(),
+            bounds: bounds.into_iter().collect(),
+        })
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
@@ -751,8 +1382,6 @@ pub(crate) mod parsing {
                 return input.parse().map(TypeParamBound::Lifetime);
             }
 
-            let begin = input.fork();
-
             let content;
             let (paren_token, content) = if input.peek(token::Paren) {
                 (Some(parenthesized!(content in input)), &content)
@@ -760,21 +1389,9 @@ pub(crate) mod parsing {
                 (None, input)
             };
 
-            let is_tilde_const =
-                cfg!(feature = "full") && content.peek(Token![~]) && content.peek2(Token![const]);
-            if is_tilde_const {
-                content.parse::<Token![~]>()?;
-                content.parse::<Token![const]>()?;
-            }
-
             let mut bound: TraitBound = content.parse()?;
             bound.paren_token = paren_token;
-
-            if is_tilde_const {
-                Ok(TypeParamBound::Verbatim(verbatim::between(begin, input)))
-            } else {
-                Ok(TypeParamBound::Trait(bound))
-            }
+            Ok(TypeParamBound::Trait(bound))
         }
     }
 
@@ -834,6 +1451,12 @@ pub(crate) mod parsing {
         fn parse(input: ParseStream) -> Result<Self> {
             if input.peek(Token![?]) {
                 input.parse().map(TraitBoundModifier::Maybe)
+            } else if cfg!(feature = "full") && input.peek(Token![~]) && input.peek2(Token![const])
+            {
+                Ok(TraitBoundModifier::MaybeConst(
+                    input.parse()?,
+                    input.parse()?,
+                ))
             } else {
                 Ok(TraitBoundModifier::None)
             }
@@ -1177,6 +1800,10 @@ mod printing {
             match self {
                 TraitBoundModifier::None => {}
                 TraitBoundModifier::Maybe(t) => t.to_tokens(tokens),
+                TraitBoundModifier::MaybeConst(tilde, const_token) => {
+                    tilde.to_tokens(tokens);
+                    const_token.to_tokens(tokens);
+                }
             }
         }
     }
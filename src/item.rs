@@ -2,8 +2,6 @@ use super::*;
 use crate::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveInput};
 use crate::punctuated::Punctuated;
 use proc_macro2::TokenStream;
-
-#[cfg(feature = "parsing")]
 use std::mem;
 
 ast_enum_of_structs! {
@@ -199,6 +197,43 @@ ast_struct! {
     }
 }
 
+impl ItemMod {
+    /// Removes the inner attributes of this module, like `//! ...` doc
+    /// comments and `#![allow(...)]`, and returns them in order.
+    ///
+    /// This is useful when inlining the content of a module elsewhere, such
+    /// as into the `attrs` of a [`File`], where the inner attributes need to
+    /// be carried along. The outer attributes of the module are left in
+    /// place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ItemMod};
+    ///
+    /// let mut item: ItemMod = parse_quote! {
+    ///     /// Outer.
+    ///     mod m {
+    ///         //! Inner.
+    ///         #![allow(dead_code)]
+    ///     }
+    /// };
+    ///
+    /// let inner = item.take_inner_attrs();
+    /// assert_eq!(inner.len(), 2);
+    /// assert_eq!(item.attrs.len(), 1);
+    /// ```
+    pub fn take_inner_attrs(&mut self) -> Vec<Attribute> {
+        let attrs = mem::take(&mut self.attrs);
+        let (inner, outer) = attrs.into_iter().partition(|attr| match attr.style {
+            AttrStyle::Inner(_) => true,
+            AttrStyle::Outer => false,
+        });
+        self.attrs = outer;
+        inner
+    }
+}
+
 ast_struct! {
     /// A static item: `static BIKE: Shed = Shed(42)`.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
@@ -306,6 +341,86 @@ ast_struct! {
 }
 
 impl Item {
+    /// Returns the name declared by this item, if any.
+    ///
+    /// For `extern crate` items this is the name under which the crate is
+    /// brought into scope, i.e. the `b` in `extern crate a as b;`. Macro
+    /// items are named only if they are a `macro_rules!` definition.
+    ///
+    /// Returns `None` for items that do not declare a name, such as `impl`
+    /// blocks, `use` declarations and `extern` blocks.
+    pub fn ident(&self) -> Option<&Ident> {
+        match self {
+            Item::Const(item) => Some(&item.ident),
+            Item::Enum(item) => Some(&item.ident),
+            Item::ExternCrate(item) => match &item.rename {
+                Some((_as_token, rename)) => Some(rename),
+                None => Some(&item.ident),
+            },
+            Item::Fn(item) => Some(&item.sig.ident),
+            Item::Macro(item) => item.ident.as_ref(),
+            Item::Mod(item) => Some(&item.ident),
+            Item::Static(item) => Some(&item.ident),
+            Item::Struct(item) => Some(&item.ident),
+            Item::Trait(item) => Some(&item.ident),
+            Item::TraitAlias(item) => Some(&item.ident),
+            Item::Type(item) => Some(&item.ident),
+            Item::Union(item) => Some(&item.ident),
+            Item::ForeignMod(_) | Item::Impl(_) | Item::Use(_) | Item::Verbatim(_) => None,
+        }
+    }
+
+    /// Returns the attributes of this item, regardless of its kind.
+    ///
+    /// `Item::Verbatim` has no attributes that Syn knows about, so for it an
+    /// empty slice is returned.
+    pub fn attrs(&self) -> &[Attribute] {
+        match self {
+            Item::Const(ItemConst { attrs, .. })
+            | Item::Enum(ItemEnum { attrs, .. })
+            | Item::ExternCrate(ItemExternCrate { attrs, .. })
+            | Item::Fn(ItemFn { attrs, .. })
+            | Item::ForeignMod(ItemForeignMod { attrs, .. })
+            | Item::Impl(ItemImpl { attrs, .. })
+            | Item::Macro(ItemMacro { attrs, .. })
+            | Item::Mod(ItemMod { attrs, .. })
+            | Item::Static(ItemStatic { attrs, .. })
+            | Item::Struct(ItemStruct { attrs, .. })
+            | Item::Trait(ItemTrait { attrs, .. })
+            | Item::TraitAlias(ItemTraitAlias { attrs, .. })
+            | Item::Type(ItemType { attrs, .. })
+            | Item::Union(ItemUnion { attrs, .. })
+            | Item::Use(ItemUse { attrs, .. }) => attrs,
+            Item::Verbatim(_) => &[],
+        }
+    }
+
+    /// Returns a mutable reference to the attributes of this item, regardless
+    /// of its kind.
+    ///
+    /// Returns `None` for `Item::Verbatim`, which has no attribute list that
+    /// could be modified.
+    pub fn attrs_mut(&mut self) -> Option<&mut Vec<Attribute>> {
+        match self {
+            Item::Const(ItemConst { attrs, .. })
+            | Item::Enum(ItemEnum { attrs, .. })
+            | Item::ExternCrate(ItemExternCrate { attrs, .. })
+            | Item::Fn(ItemFn { attrs, .. })
+            | Item::ForeignMod(ItemForeignMod { attrs, .. })
+            | Item::Impl(ItemImpl { attrs, .. })
+            | Item::Macro(ItemMacro { attrs, .. })
+            | Item::Mod(ItemMod { attrs, .. })
+            | Item::Static(ItemStatic { attrs, .. })
+            | Item::Struct(ItemStruct { attrs, .. })
+            | Item::Trait(ItemTrait { attrs, .. })
+            | Item::TraitAlias(ItemTraitAlias { attrs, .. })
+            | Item::Type(ItemType { attrs, .. })
+            | Item::Union(ItemUnion { attrs, .. })
+            | Item::Use(ItemUse { attrs, .. }) => Some(attrs),
+            Item::Verbatim(_) => None,
+        }
+    }
+
     #[cfg(feature = "parsing")]
     pub(crate) fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
         match self {
@@ -646,6 +761,38 @@ ast_struct! {
     }
 }
 
+impl TraitItemFn {
+    /// Returns a copy of this function without its doc comments.
+    ///
+    /// This removes the `#[doc = "..."]` attributes that doc comments are
+    /// made of, which is useful when copying a trait method into an impl
+    /// where the documentation of the trait does not belong. Other `#[doc]`
+    /// attributes, such as `#[doc(hidden)]`, are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, TraitItemFn};
+    ///
+    /// let method: TraitItemFn = parse_quote! {
+    ///     /// Returns the length.
+    ///     #[must_use]
+    ///     fn len(&self) -> usize;
+    /// };
+    ///
+    /// let method = method.without_doc_attrs();
+    /// assert_eq!(method.attrs.len(), 1);
+    /// assert!(method.attrs[0].path().is_ident("must_use"));
+    /// ```
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+    pub fn without_doc_attrs(&self) -> Self {
+        let mut method = self.clone();
+        retain_non_doc_comments(&mut method.attrs);
+        method
+    }
+}
+
 ast_struct! {
     /// An associated type within the definition of a trait.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
@@ -746,6 +893,29 @@ ast_struct! {
     }
 }
 
+impl ImplItemFn {
+    /// Returns a copy of this function without its doc comments.
+    ///
+    /// This removes the `#[doc = "..."]` attributes that doc comments are
+    /// made of. Other `#[doc]` attributes, such as `#[doc(hidden)]`, are
+    /// kept. See [`TraitItemFn::without_doc_attrs`].
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+    pub fn without_doc_attrs(&self) -> Self {
+        let mut method = self.clone();
+        retain_non_doc_comments(&mut method.attrs);
+        method
+    }
+}
+
+#[cfg(feature = "clone-impls")]
+fn retain_non_doc_comments(attrs: &mut Vec<Attribute>) {
+    attrs.retain(|attr| match &attr.meta {
+        Meta::NameValue(meta) => !meta.path.is_ident("doc"),
+        Meta::Path(_) | Meta::List(_) => true,
+    });
+}
+
 ast_struct! {
     /// An associated type within an impl block.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
@@ -800,6 +970,102 @@ impl Signature {
             FnArg::Typed(_) => None,
         }
     }
+
+    /// Whether this is a `const fn`.
+    pub fn is_const(&self) -> bool {
+        self.constness.is_some()
+    }
+
+    /// Whether this is an `async fn`.
+    pub fn is_async(&self) -> bool {
+        self.asyncness.is_some()
+    }
+
+    /// Whether this is an `unsafe fn`.
+    pub fn is_unsafe(&self) -> bool {
+        self.unsafety.is_some()
+    }
+
+    /// Whether the arguments end in a C-variadic `...`.
+    pub fn is_variadic(&self) -> bool {
+        self.variadic.is_some()
+    }
+
+    /// The name of the ABI declared by an `extern` qualifier, or `None` for a
+    /// function without one.
+    ///
+    /// An `extern` without a name declares the `"C"` ABI, so `extern fn` and
+    /// `extern "C" fn` both give `"C"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Signature};
+    ///
+    /// let sig: Signature = parse_quote!(unsafe extern "system" fn f());
+    /// assert_eq!(sig.abi_name().as_deref(), Some("system"));
+    ///
+    /// let sig: Signature = parse_quote!(fn f());
+    /// assert_eq!(sig.abi_name(), None);
+    /// ```
+    pub fn abi_name(&self) -> Option<String> {
+        let abi = self.abi.as_ref()?;
+        Some(abi.name().unwrap_or_else(|| "C".to_owned()))
+    }
+
+    /// The types of the arguments other than the receiver, in order.
+    ///
+    /// A C-variadic `...` argument is not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Signature};
+    ///
+    /// let sig: Signature = parse_quote!(fn call(&self, id: u64, (x, y): (f32, f32)));
+    /// let arg_types = sig.arg_types();
+    /// let args_tuple = quote!((#(#arg_types,)*));
+    /// assert_eq!(args_tuple.to_string(), "(u64 , (f32 , f32) ,)");
+    /// ```
+    pub fn arg_types(&self) -> impl Iterator<Item = &Type> {
+        self.inputs.iter().filter_map(|arg| match arg {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+        })
+    }
+
+    /// Replaces the return type `T` of this signature with `wrap(T)`, for
+    /// example to turn `-> T` into `-> Result<T, Error>`.
+    ///
+    /// A signature without a declared return type is treated as returning
+    /// `()`, and gets an explicit `->` spanned like its parentheses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Signature};
+    ///
+    /// let mut sig: Signature = parse_quote!(fn f());
+    /// sig.wrap_return(|ty| parse_quote!(std::io::Result<#ty>));
+    ///
+    /// let expected: Signature = parse_quote!(fn f() -> std::io::Result<()>);
+    /// assert_eq!(sig, expected);
+    /// ```
+    pub fn wrap_return(&mut self, wrap: impl FnOnce(Type) -> Type) {
+        let (arrow, ty) = match mem::replace(&mut self.output, ReturnType::Default) {
+            ReturnType::Default => {
+                let span = self.paren_token.span.join();
+                let unit = Type::Tuple(TypeTuple {
+                    paren_token: token::Paren(span),
+                    elems: Punctuated::new(),
+                });
+                (Token![->](span), unit)
+            }
+            ReturnType::Type(arrow, ty) => (arrow, *ty),
+        };
+        self.output = ReturnType::Type(arrow, Box::new(wrap(ty)));
+    }
 }
 
 ast_enum_of_structs! {
@@ -2607,7 +2873,7 @@ pub(crate) mod parsing {
                 punct.set_span(semi.span);
                 let tokens = TokenStream::from_iter(vec![TokenTree::Punct(punct)]);
                 Block {
-                    brace_token: Brace { span: semi.span },
+                    brace_token: Brace(semi.span),
                     stmts: vec![Stmt::Item(Item::Verbatim(tokens))],
                 }
             } else {
@@ -245,6 +245,12 @@
 //!   types.
 //! - **`extra-traits`** — Debug, Eq, PartialEq, Hash impls for all syntax tree
 //!   types.
+//! - **`unparse`** — Best-effort formatting of a syntax tree as readable Rust
+//!   source code. Implies `full` and `printing`.
+//! - **`span-locations`** — Parsing a file together with a map from the spans
+//!   of its syntax tree to line, column and byte positions in the source.
+//!   Implies `full` and `parsing`, and enables the `span-locations` feature
+//!   of proc-macro2.
 //! - **`proc-macro`** *(enabled by default)* — Runtime dependency on the
 //!   dynamic library libproc_macro from rustc toolchain.
 
@@ -309,8 +315,20 @@ mod group;
 #[macro_use]
 pub mod token;
 
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+pub mod asm;
+#[cfg(feature = "full")]
+pub use crate::asm::{
+    AsmArg, AsmClobberAbi, AsmDirection, AsmOperand, AsmOperandKind, AsmOptions, AsmReg,
+    AsmRegOperand, InlineAsm,
+};
+
 #[cfg(any(feature = "full", feature = "derive"))]
-mod attr;
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub mod attr;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+pub use crate::attr::ReprOptions;
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::attr::{AttrStyle, Attribute, Meta, MetaList, MetaNameValue};
 
@@ -332,7 +350,11 @@ pub use crate::data::{Field, Fields, FieldsNamed, FieldsUnnamed, Variant};
 #[cfg(any(feature = "full", feature = "derive"))]
 mod derive;
 #[cfg(feature = "derive")]
-pub use crate::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveInput};
+pub use crate::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveInput, HelperAttrLocation};
+
+#[cfg(any(feature = "full", feature = "derive"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub mod doc;
 
 mod drops;
 
@@ -342,14 +364,15 @@ pub use crate::error::{Error, Result};
 #[cfg(any(feature = "full", feature = "derive"))]
 mod expr;
 #[cfg(feature = "full")]
-pub use crate::expr::{Arm, FieldValue, Label, RangeLimits};
+pub use crate::expr::{Arm, FieldValue, Label, PointerMutability, RangeLimits};
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::expr::{
-    Expr, ExprArray, ExprAssign, ExprAsync, ExprAwait, ExprBinary, ExprBlock, ExprBreak, ExprCall,
-    ExprCast, ExprClosure, ExprConst, ExprContinue, ExprField, ExprForLoop, ExprGroup, ExprIf,
-    ExprIndex, ExprInfer, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch, ExprMethodCall,
-    ExprParen, ExprPath, ExprRange, ExprReference, ExprRepeat, ExprReturn, ExprStruct, ExprTry,
-    ExprTryBlock, ExprTuple, ExprUnary, ExprUnsafe, ExprWhile, ExprYield, Index, Member,
+    ConstRef, Expr, ExprArray, ExprAssign, ExprAsync, ExprAwait, ExprBecome, ExprBinary, ExprBlock,
+    ExprBreak, ExprCall, ExprCast, ExprClosure, ExprConst, ExprContinue, ExprField, ExprForLoop,
+    ExprGroup, ExprIf, ExprIndex, ExprInfer, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch,
+    ExprMethodCall, ExprParen, ExprPath, ExprRange, ExprRawAddr, ExprReference, ExprRepeat,
+    ExprReturn, ExprStruct, ExprTry, ExprTryBlock, ExprTuple, ExprUnary, ExprUnsafe, ExprWhile,
+    ExprYield, Index, Member,
 };
 
 #[cfg(feature = "parsing")]
@@ -360,14 +383,16 @@ pub mod ext;
 mod file;
 #[cfg(feature = "full")]
 pub use crate::file::File;
+#[cfg(all(feature = "full", feature = "parsing"))]
+pub use crate::file::FileItems;
 
 #[cfg(any(feature = "full", feature = "derive"))]
 mod generics;
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::generics::{
     BoundLifetimes, ConstParam, GenericParam, Generics, LifetimeParam, PredicateLifetime,
-    PredicateType, TraitBound, TraitBoundModifier, TypeParam, TypeParamBound, WhereClause,
-    WherePredicate,
+    PredicateType, TraitBound, TraitBoundModifier, TypeParam, TypeParamBound, Variance,
+    WhereClause, WherePredicate,
 };
 #[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
 pub use crate::generics::{ImplGenerics, Turbofish, TypeGenerics};
@@ -454,11 +479,15 @@ pub use crate::path::{
     ParenthesizedGenericArguments, Path, PathArguments, PathSegment, QSelf,
 };
 
-#[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
+#[cfg(feature = "printing")]
 mod print;
+#[cfg(feature = "printing")]
+pub use crate::print::{to_token_stream_separated, ToTokensExt};
 
 pub mod punctuated;
 
+pub mod rename;
+
 #[cfg(any(feature = "full", feature = "derive"))]
 mod restriction;
 #[cfg(any(feature = "full", feature = "derive"))]
@@ -479,18 +508,33 @@ pub use crate::stmt::{Block, Local, LocalInit, Stmt, StmtMacro};
 
 mod thread;
 
-#[cfg(all(any(feature = "full", feature = "derive"), feature = "extra-traits"))]
+#[cfg(any(
+    all(any(feature = "full", feature = "derive"), feature = "extra-traits"),
+    all(feature = "fold", feature = "printing"),
+))]
 mod tt;
 
 #[cfg(any(feature = "full", feature = "derive"))]
-mod ty;
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub mod ty;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
+pub use crate::ty::TypeEqOptions;
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::ty::{
-    Abi, BareFnArg, BareVariadic, ReturnType, Type, TypeArray, TypeBareFn, TypeGroup,
+    Abi, BareFnArg, BareVariadic, RefSummary, ReturnType, Type, TypeArray, TypeBareFn, TypeGroup,
     TypeImplTrait, TypeInfer, TypeMacro, TypeNever, TypeParen, TypePath, TypePtr, TypeReference,
     TypeSlice, TypeTraitObject, TypeTuple,
 };
 
+#[cfg(feature = "unparse")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "unparse")))]
+pub mod unparse;
+
+#[cfg(feature = "span-locations")]
+mod locations;
+#[cfg(feature = "span-locations")]
+pub use crate::locations::{parse_str_with_locations, LocationMap};
+
 #[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
 mod verbatim;
 
@@ -889,6 +933,38 @@ pub fn parse2<T: parse::Parse>(tokens: proc_macro2::TokenStream) -> Result<T> {
     parse::Parser::parse2(T::parse, tokens)
 }
 
+/// Parse a syntax tree node from the beginning of a proc-macro2 token stream,
+/// returning it together with the tokens that follow it.
+///
+/// Unlike [`parse2`], this function does not require the input to be fully
+/// parsed. Parsing still fails if `T` reports an error, or if it leaves
+/// tokens unconsumed inside a group it descended into.
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::Type;
+///
+/// let tokens = quote!(Vec<u8> => handle(x));
+/// let (ty, rest) = syn::parse_partial::<Type>(tokens)?;
+/// assert_eq!(quote!(#ty).to_string(), "Vec < u8 >");
+/// assert_eq!(rest.to_string(), "=> handle (x)");
+/// # syn::Result::Ok(())
+/// ```
+#[cfg(feature = "parsing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+pub fn parse_partial<T: parse::Parse>(
+    tokens: proc_macro2::TokenStream,
+) -> Result<(T, proc_macro2::TokenStream)> {
+    let parser = |input: parse::ParseStream| {
+        let node = T::parse(input)?;
+        let rest: proc_macro2::TokenStream = input.parse()?;
+        Ok((node, rest))
+    };
+    parse::Parser::parse2(parser, tokens)
+}
+
 /// Parse a string of Rust code into the chosen syntax tree node.
 ///
 /// # Hygiene
@@ -952,7 +1028,52 @@ pub fn parse_str<T: parse::Parse>(s: &str) -> Result<T> {
 /// ```
 #[cfg(all(feature = "parsing", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "full"))))]
-pub fn parse_file(mut content: &str) -> Result<File> {
+pub fn parse_file(content: &str) -> Result<File> {
+    let (shebang, content) = split_shebang(content);
+    let mut file: File = parse_str(content)?;
+    file.shebang = shebang;
+    Ok(file)
+}
+
+/// Parse the content of a file of Rust code one item at a time.
+///
+/// Like [`parse_file`], this discards a leading byte order mark and preserves
+/// the shebang line, which along with the inner attributes of the file is
+/// available from the returned iterator. The items of the file are then
+/// parsed one at a time as the iterator is advanced, so a caller that
+/// processes and drops each item does not need to hold the syntax tree of the
+/// entire file in memory. The tokens of the file are still read up front.
+///
+/// The first error, whether in the tokens of the file, its inner attributes or
+/// one of its items, is yielded by the iterator and ends the iteration.
+///
+/// # Examples
+///
+/// ```
+/// use syn::Item;
+///
+/// let content = "#![allow(dead_code)] fn f() {} struct S;";
+/// let mut items = syn::parse_file_items(content);
+/// assert_eq!(items.attrs().len(), 1);
+///
+/// for item in &mut items {
+///     match item.unwrap() {
+///         Item::Fn(item) => assert_eq!(item.sig.ident, "f"),
+///         Item::Struct(item) => assert_eq!(item.ident, "S"),
+///         _ => unreachable!(),
+///     }
+/// }
+/// ```
+#[cfg(all(feature = "parsing", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "full"))))]
+pub fn parse_file_items(content: &str) -> FileItems {
+    let (shebang, content) = split_shebang(content);
+    let tokens = content.parse().map_err(Error::from);
+    FileItems::new(shebang, tokens)
+}
+
+#[cfg(all(feature = "parsing", feature = "full"))]
+fn split_shebang(mut content: &str) -> (Option<String>, &str) {
     // Strip the BOM if it is present
     const BOM: &str = "\u{feff}";
     if content.starts_with(BOM) {
@@ -973,7 +1094,5 @@ pub fn parse_file(mut content: &str) -> Result<File> {
         }
     }
 
-    let mut file: File = parse_str(content)?;
-    file.shebang = shebang;
-    Ok(file)
+    (shebang, content)
 }
@@ -1,3 +1,4 @@
+use crate::error::{Error, Result};
 use proc_macro2::{Ident, Span};
 use std::cmp::Ordering;
 use std::fmt::{self, Display};
@@ -37,25 +38,63 @@ impl Lifetime {
     /// # }
     /// ```
     pub fn new(symbol: &str, span: Span) -> Self {
+        match Lifetime::try_new(symbol, span) {
+            Ok(lifetime) => lifetime,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Non-panicking version of [`Lifetime::new`].
+    ///
+    /// Returns an error spanned to `span` if the lifetime does not conform to
+    /// the bulleted rules above.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use syn::Lifetime;
+    ///
+    /// assert!(Lifetime::try_new("'a", Span::call_site()).is_ok());
+    /// assert!(Lifetime::try_new("a", Span::call_site()).is_err());
+    /// assert!(Lifetime::try_new("'1", Span::call_site()).is_err());
+    /// ```
+    pub fn try_new(symbol: &str, span: Span) -> Result<Self> {
         if !symbol.starts_with('\'') {
-            panic!(
-                "lifetime name must start with apostrophe as in \"'a\", got {:?}",
-                symbol
-            );
+            return Err(Error::new(
+                span,
+                format!(
+                    "lifetime name must start with apostrophe as in \"'a\", got {:?}",
+                    symbol
+                ),
+            ));
         }
 
         if symbol == "'" {
-            panic!("lifetime name must not be empty");
+            return Err(Error::new(span, "lifetime name must not be empty"));
         }
 
         if !crate::ident::xid_ok(&symbol[1..]) {
-            panic!("{:?} is not a valid lifetime name", symbol);
+            return Err(Error::new(
+                span,
+                format!("{:?} is not a valid lifetime name", symbol),
+            ));
         }
 
-        Lifetime {
+        Ok(Lifetime {
             apostrophe: span,
             ident: Ident::new(&symbol[1..], span),
-        }
+        })
+    }
+
+    /// Returns `true` if this is the `'static` lifetime.
+    pub fn is_static(&self) -> bool {
+        self.ident == "static"
+    }
+
+    /// Returns `true` if this is the elided lifetime `'_`.
+    pub fn is_elided(&self) -> bool {
+        self.ident == "_"
     }
 
     pub fn span(&self) -> Span {
@@ -1122,6 +1122,24 @@ mod value {
                 Lit::Verbatim(lit) => lit.set_span(span),
             }
         }
+
+        /// Returns the value of this literal if it is a boolean literal
+        /// `true` or `false`.
+        pub fn bool_value(&self) -> Option<bool> {
+            match self {
+                Lit::Bool(lit) => Some(lit.value),
+                _ => None,
+            }
+        }
+
+        /// Returns the value of this literal if it is a character literal
+        /// like `'a'`.
+        pub fn char_value(&self) -> Option<char> {
+            match self {
+                Lit::Char(lit) => Some(lit.value()),
+                _ => None,
+            }
+        }
     }
 
     /// Get the byte at offset idx, or a default of `b'\0'` if we're looking
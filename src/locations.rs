@@ -0,0 +1,152 @@
+use crate::error::Result;
+use crate::File;
+use proc_macro2::{LineColumn, Span, TokenStream, TokenTree};
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// Parse the content of a file of Rust code like [`parse_file`], and return
+/// a [`LocationMap`] for translating the spans of the resulting syntax tree
+/// into positions in `content`.
+///
+/// Line and column information is only available from the fallback
+/// implementation of `proc_macro2` with its `span-locations` feature, which
+/// is what the `span-locations` feature of Syn enables. Inside of a
+/// procedural macro the spans come from the compiler instead and the map
+/// does not resolve them.
+///
+/// [`parse_file`]: crate::parse_file
+///
+/// # Example
+///
+/// ```
+/// use syn::spanned::Spanned;
+/// use syn::Item;
+///
+/// let content = "use std::fmt;\n\nfn f() -> fmt::Result {\n    Ok(())\n}\n";
+/// let (file, locations) = syn::parse_str_with_locations(content).unwrap();
+///
+/// let item = match &file.items[1] {
+///     Item::Fn(item) => item,
+///     _ => unreachable!(),
+/// };
+/// let start = locations.lookup(item.sig.ident.span()).unwrap();
+/// assert_eq!((start.line, start.column), (3, 3));
+///
+/// let range = locations.byte_range(item.block.span()).unwrap();
+/// assert_eq!(&content[range], "{\n    Ok(())\n}");
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(feature = "span-locations")))]
+pub fn parse_str_with_locations(content: &str) -> Result<(File, LocationMap)> {
+    let (shebang, rest) = crate::split_shebang(content);
+    let tokens: TokenStream = rest.parse()?;
+
+    let mut boundaries = Vec::new();
+    collect_boundaries(tokens.clone(), &mut boundaries);
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut file: File = crate::parse2(tokens)?;
+    file.shebang = shebang;
+
+    let base = content.len() - rest.len();
+    let offsets = byte_offsets(rest, base, boundaries);
+    Ok((file, LocationMap { offsets }))
+}
+
+/// The positions of the tokens of a file parsed by
+/// [`parse_str_with_locations`].
+///
+/// The map knows the line, column and byte offset of the start and end of
+/// every token of the file, including delimiters. A span resolves if it was
+/// produced by parsing that file, including the span of a syntax tree node
+/// covering several tokens as returned by [`Spanned`].
+///
+/// The map cannot tell apart spans that did not come from its file, such as
+/// `Span::call_site()` or spans from a different parse, if they happen to
+/// share a position with one of its tokens.
+///
+/// [`Spanned`]: crate::spanned::Spanned
+#[cfg_attr(doc_cfg, doc(cfg(feature = "span-locations")))]
+pub struct LocationMap {
+    // Byte offset within the content, keyed by line and column.
+    offsets: BTreeMap<(usize, usize), usize>,
+}
+
+impl LocationMap {
+    /// Returns the line and column where `span` starts, if it starts at a
+    /// token of the parsed file.
+    ///
+    /// Lines are 1-indexed and columns are 0-indexed, counted in characters.
+    pub fn lookup(&self, span: Span) -> Option<LineColumn> {
+        let start = span.start();
+        self.offset(start)?;
+        Some(start)
+    }
+
+    /// Returns the byte range of the parsed content covered by `span`, if it
+    /// starts and ends at tokens of the parsed file.
+    pub fn byte_range(&self, span: Span) -> Option<Range<usize>> {
+        let start = self.offset(span.start())?;
+        let end = self.offset(span.end())?;
+        Some(start..end)
+    }
+
+    fn offset(&self, position: LineColumn) -> Option<usize> {
+        self.offsets.get(&(position.line, position.column)).copied()
+    }
+}
+
+fn collect_boundaries(tokens: TokenStream, boundaries: &mut Vec<(usize, usize)>) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                push_boundaries(group.span_open(), boundaries);
+                push_boundaries(group.span_close(), boundaries);
+                collect_boundaries(group.stream(), boundaries);
+            }
+            TokenTree::Ident(ident) => push_boundaries(ident.span(), boundaries),
+            TokenTree::Punct(punct) => push_boundaries(punct.span(), boundaries),
+            TokenTree::Literal(literal) => push_boundaries(literal.span(), boundaries),
+        }
+    }
+}
+
+fn push_boundaries(span: Span, boundaries: &mut Vec<(usize, usize)>) {
+    for position in [span.start(), span.end()] {
+        // Spans from the compiler have no location on stable.
+        if position.line > 0 {
+            boundaries.push((position.line, position.column));
+        }
+    }
+}
+
+// Translates sorted line and column positions within `content` into byte
+// offsets, counted from `base` bytes before the start of `content`.
+fn byte_offsets(
+    content: &str,
+    base: usize,
+    boundaries: Vec<(usize, usize)>,
+) -> BTreeMap<(usize, usize), usize> {
+    let mut offsets = BTreeMap::new();
+    let mut boundaries = boundaries.into_iter().peekable();
+    let mut line = 1;
+    let mut column = 0;
+    for (i, ch) in content.char_indices().chain([(content.len(), '\n')]) {
+        while let Some(&position) = boundaries.peek() {
+            if position > (line, column) {
+                break;
+            }
+            if position == (line, column) {
+                offsets.insert(position, base + i);
+            }
+            boundaries.next();
+        }
+        if ch == '\n' {
+            line += 1;
+            column = 0;
+        } else {
+            column += 1;
+        }
+    }
+    offsets
+}
@@ -4,6 +4,7 @@ use crate::sealed::lookahead::Sealed;
 use crate::span::IntoSpans;
 use crate::token::Token;
 use proc_macro2::{Delimiter, Span};
+use std::borrow::Cow;
 use std::cell::RefCell;
 
 /// Support for checking the next token in a stream to decide how to parse.
@@ -62,7 +63,7 @@ use std::cell::RefCell;
 pub struct Lookahead1<'a> {
     scope: Span,
     cursor: Cursor<'a>,
-    comparisons: RefCell<Vec<&'static str>>,
+    comparisons: RefCell<Vec<Cow<'static, str>>>,
 }
 
 pub(crate) fn new(scope: Span, cursor: Cursor) -> Lookahead1 {
@@ -81,7 +82,10 @@ fn peek_impl(
     if peek(lookahead.cursor) {
         return true;
     }
-    lookahead.comparisons.borrow_mut().push(display());
+    lookahead
+        .comparisons
+        .borrow_mut()
+        .push(Cow::Borrowed(display()));
     false
 }
 
@@ -105,6 +109,48 @@ impl<'a> Lookahead1<'a> {
         peek_impl(self, T::Token::peek, T::Token::display)
     }
 
+    /// Adds `expected` to the alternatives listed in the error message of this
+    /// lookahead instance, without peeking anything.
+    ///
+    /// This lets a parser that checks the next token by other means, such as
+    /// a contextual keyword or a custom condition on the cursor, contribute a
+    /// description of what it accepts. The description is included in the
+    /// message as given, so it should be formatted like the built-in ones,
+    /// for example `` `union` `` with backticks or `identifier` without.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Result, Token};
+    /// use syn::parse::{Parse, ParseStream};
+    ///
+    /// // Either `struct` or the contextual keyword `union`.
+    /// struct StructOrUnion;
+    ///
+    /// impl Parse for StructOrUnion {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         let lookahead = input.lookahead1();
+    ///         if lookahead.peek(Token![struct]) {
+    ///             input.parse::<Token![struct]>()?;
+    ///         } else if input.peek_keyword("union") {
+    ///             input.parse::<syn::Ident>()?;
+    ///         } else {
+    ///             lookahead.hint("`union`");
+    ///             return Err(lookahead.error());
+    ///         }
+    ///         Ok(StructOrUnion)
+    ///     }
+    /// }
+    /// #
+    /// # let err = syn::parse_str::<StructOrUnion>("enum").err().unwrap();
+    /// # assert_eq!(err.to_string(), "expected `struct` or `union`");
+    /// ```
+    pub fn hint(&self, expected: &str) {
+        self.comparisons
+            .borrow_mut()
+            .push(Cow::Owned(expected.to_owned()));
+    }
+
     /// Triggers an error at the current position of the parse stream.
     ///
     /// The error message will identify all of the expected token types that
@@ -1,10 +1,9 @@
 use super::*;
 use crate::token::{Brace, Bracket, Paren};
-#[cfg(any(feature = "parsing", feature = "printing"))]
-use proc_macro2::Delimiter;
-use proc_macro2::TokenStream;
+use proc_macro2::extra::DelimSpan;
 #[cfg(feature = "parsing")]
-use proc_macro2::{Group, Span, TokenTree};
+use proc_macro2::TokenTree;
+use proc_macro2::{Delimiter, TokenStream};
 
 #[cfg(feature = "parsing")]
 use crate::parse::{Parse, ParseStream, Parser, Result};
@@ -30,20 +29,30 @@ ast_enum! {
     }
 }
 
-#[cfg(feature = "parsing")]
-fn delimiter_span_close(macro_delimiter: &MacroDelimiter) -> Span {
-    let delimiter = match macro_delimiter {
-        MacroDelimiter::Paren(_) => Delimiter::Parenthesis,
-        MacroDelimiter::Brace(_) => Delimiter::Brace,
-        MacroDelimiter::Bracket(_) => Delimiter::Bracket,
-    };
-    let mut group = Group::new(delimiter, TokenStream::new());
-    group.set_span(match macro_delimiter {
-        MacroDelimiter::Paren(token) => token.span,
-        MacroDelimiter::Brace(token) => token.span,
-        MacroDelimiter::Bracket(token) => token.span,
-    });
-    group.span_close()
+impl MacroDelimiter {
+    /// Returns the spans of the opening delimiter, the closing delimiter, and
+    /// the whole delimited group, such as the `(`, `)` and `(...)` of
+    /// `m!(...)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Error, Macro};
+    ///
+    /// let mac: Macro = parse_quote!(m![a, b]);
+    /// let error = Error::new(mac.delimiter.span().open(), "expected `(`");
+    /// ```
+    pub fn span(&self) -> DelimSpan {
+        self.delim_and_span().1
+    }
+
+    fn delim_and_span(&self) -> (Delimiter, DelimSpan) {
+        match self {
+            MacroDelimiter::Paren(paren) => (Delimiter::Parenthesis, paren.span),
+            MacroDelimiter::Brace(brace) => (Delimiter::Brace, brace.span),
+            MacroDelimiter::Bracket(bracket) => (Delimiter::Bracket, bracket.span),
+        }
+    }
 }
 
 impl Macro {
@@ -137,10 +146,19 @@ impl Macro {
 
     /// Parse the tokens within the macro invocation's delimiters using the
     /// given parser.
+    ///
+    /// If the parser runs out of tokens, the error is reported at the closing
+    /// delimiter, or at the whole delimiter pair like `{}` if the body of the
+    /// macro is empty.
     #[cfg(feature = "parsing")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     pub fn parse_body_with<F: Parser>(&self, parser: F) -> Result<F::Output> {
-        let scope = delimiter_span_close(&self.delimiter);
+        let span = self.delimiter.span();
+        let scope = if self.tokens.is_empty() {
+            span.join()
+        } else {
+            span.close()
+        };
         crate::parse::parse_scoped(parser, scope, self.tokens.clone())
     }
 }
@@ -149,7 +167,7 @@ impl Macro {
 pub(crate) fn parse_delimiter(input: ParseStream) -> Result<(MacroDelimiter, TokenStream)> {
     input.step(|cursor| {
         if let Some((TokenTree::Group(g), rest)) = cursor.token_tree() {
-            let span = g.span();
+            let span = g.delim_span();
             let delimiter = match g.delimiter() {
                 Delimiter::Parenthesis => MacroDelimiter::Paren(Paren(span)),
                 Delimiter::Brace => MacroDelimiter::Brace(Brace(span)),
@@ -196,12 +214,8 @@ mod printing {
 
     impl MacroDelimiter {
         pub(crate) fn surround(&self, tokens: &mut TokenStream, inner: TokenStream) {
-            let (delim, span) = match self {
-                MacroDelimiter::Paren(paren) => (Delimiter::Parenthesis, paren.span),
-                MacroDelimiter::Brace(brace) => (Delimiter::Brace, brace.span),
-                MacroDelimiter::Bracket(bracket) => (Delimiter::Bracket, bracket.span),
-            };
-            token::printing::delim(delim, span, tokens, inner);
+            let (delim, span) = self.delim_and_span();
+            token::printing::delim(delim, span.join(), tokens, inner);
         }
     }
 
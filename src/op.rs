@@ -76,6 +76,120 @@ ast_enum! {
     }
 }
 
+impl BinOp {
+    /// The precedence of the operator, where a higher value binds more
+    /// tightly.
+    ///
+    /// The values are those of rustc's `AssocOp::precedence`, which also
+    /// leaves room for the operators that are not a `BinOp`: `as` binds more
+    /// tightly than any binary operator with precedence 14, and ranges `..`
+    /// bind less tightly than `||` with precedence 4.
+    ///
+    /// | Precedence | Operators                    |
+    /// |------------|------------------------------|
+    /// | 13         | `*` `/` `%`                  |
+    /// | 12         | `+` `-`                      |
+    /// | 11         | `<<` `>>`                    |
+    /// | 10         | `&`                          |
+    /// | 9          | `^`                          |
+    /// | 8          | `\|`                         |
+    /// | 7          | `==` `!=` `<` `>` `<=` `>=`  |
+    /// | 6          | `&&`                         |
+    /// | 5          | `\|\|`                       |
+    /// | 2          | `=` `+=` `-=` `*=` and so on |
+    ///
+    /// Operators of equal precedence associate to the left, except that
+    /// assignments associate to the right and comparisons cannot be chained
+    /// at all. So an operand of `a OP b` needs parentheses if its own operator
+    /// has a lower precedence than `OP`, or the same precedence on the side
+    /// opposite to the associativity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, BinOp};
+    ///
+    /// let add: BinOp = parse_quote!(+);
+    /// let mul: BinOp = parse_quote!(*);
+    ///
+    /// // `(a + b) * c` needs its parentheses, `a * b + c` does not.
+    /// assert!(add.precedence() < mul.precedence());
+    /// ```
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => 13,
+            BinOp::Add(_) | BinOp::Sub(_) => 12,
+            BinOp::Shl(_) | BinOp::Shr(_) => 11,
+            BinOp::BitAnd(_) => 10,
+            BinOp::BitXor(_) => 9,
+            BinOp::BitOr(_) => 8,
+            BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => 7,
+            BinOp::And(_) => 6,
+            BinOp::Or(_) => 5,
+            BinOp::AddAssign(_)
+            | BinOp::SubAssign(_)
+            | BinOp::MulAssign(_)
+            | BinOp::DivAssign(_)
+            | BinOp::RemAssign(_)
+            | BinOp::BitXorAssign(_)
+            | BinOp::BitAndAssign(_)
+            | BinOp::BitOrAssign(_)
+            | BinOp::ShlAssign(_)
+            | BinOp::ShrAssign(_) => 2,
+        }
+    }
+
+    /// The operator as written in source code, like `"+="`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinOp::Add(_) => "+",
+            BinOp::Sub(_) => "-",
+            BinOp::Mul(_) => "*",
+            BinOp::Div(_) => "/",
+            BinOp::Rem(_) => "%",
+            BinOp::And(_) => "&&",
+            BinOp::Or(_) => "||",
+            BinOp::BitXor(_) => "^",
+            BinOp::BitAnd(_) => "&",
+            BinOp::BitOr(_) => "|",
+            BinOp::Shl(_) => "<<",
+            BinOp::Shr(_) => ">>",
+            BinOp::Eq(_) => "==",
+            BinOp::Lt(_) => "<",
+            BinOp::Le(_) => "<=",
+            BinOp::Ne(_) => "!=",
+            BinOp::Ge(_) => ">=",
+            BinOp::Gt(_) => ">",
+            BinOp::AddAssign(_) => "+=",
+            BinOp::SubAssign(_) => "-=",
+            BinOp::MulAssign(_) => "*=",
+            BinOp::DivAssign(_) => "/=",
+            BinOp::RemAssign(_) => "%=",
+            BinOp::BitXorAssign(_) => "^=",
+            BinOp::BitAndAssign(_) => "&=",
+            BinOp::BitOrAssign(_) => "|=",
+            BinOp::ShlAssign(_) => "<<=",
+            BinOp::ShrAssign(_) => ">>=",
+        }
+    }
+}
+
+impl UnOp {
+    /// The operator as written in source code, like `"!"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnOp::Deref(_) => "*",
+            UnOp::Not(_) => "!",
+            UnOp::Neg(_) => "-",
+        }
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
@@ -192,6 +192,10 @@ use crate::lookahead;
 use crate::proc_macro;
 use crate::punctuated::Punctuated;
 use crate::token::Token;
+#[cfg(any(feature = "full", feature = "derive"))]
+use crate::{token, MacroDelimiter};
+#[cfg(any(feature = "full", feature = "derive"))]
+use proc_macro2::extra::DelimSpan;
 use proc_macro2::{self, Delimiter, Group, Literal, Punct, Span, TokenStream, TokenTree};
 use std::cell::Cell;
 use std::fmt::{self, Debug, Display};
@@ -245,6 +249,15 @@ pub type ParseStream<'a> = &'a ParseBuffer<'a>;
 ///
 /// [`parse_macro_input!`]: crate::parse_macro_input!
 /// [syn-parse]: self#the-synparse-functions
+///
+/// ## Whitespace and comments
+///
+/// A `ParseStream` is a stream of tokens, not of source text. Whitespace and
+/// ordinary comments never appear in it and need not be skipped by parser
+/// functions. Doc comments are the one exception: the compiler turns each
+/// `/// ...` or `//! ...` into a `#[doc = "..."]` or `#![doc = "..."]`
+/// attribute before a macro sees it, so they are observed as the tokens of
+/// that attribute.
 pub struct ParseBuffer<'a> {
     scope: Span,
     // Instead of Cell<Cursor<'a>> so that ParseBuffer<'a> is covariant in 'a.
@@ -371,6 +384,33 @@ impl<'c, 'a> StepCursor<'c, 'a> {
     }
 }
 
+/// A saved position of a parse stream, to which the stream can later be
+/// rewound.
+///
+/// This type is returned by [`ParseStream::checkpoint`] and consumed by
+/// [`ParseStream::restore`].
+///
+/// [`ParseStream::checkpoint`]: ParseBuffer::checkpoint
+/// [`ParseStream::restore`]: ParseBuffer::restore
+pub struct Checkpoint<'a> {
+    cursor: Cursor<'a>,
+    // The innermost unexpected-token cell of the stream and its content at
+    // the time of the checkpoint, so that an unexpected token found in a
+    // group by an abandoned alternative is forgotten on restore.
+    unexpected: Rc<Cell<Unexpected>>,
+    unexpected_span: Option<Span>,
+}
+
+impl<'a> Clone for Checkpoint<'a> {
+    fn clone(&self) -> Self {
+        Checkpoint {
+            cursor: self.cursor,
+            unexpected: self.unexpected.clone(),
+            unexpected_span: self.unexpected_span,
+        }
+    }
+}
+
 pub(crate) fn advance_step_cursor<'c, 'a>(proof: StepCursor<'c, 'a>, to: Cursor<'c>) -> Cursor<'a> {
     // Refer to the comments within the StepCursor definition. We use the
     // fact that a StepCursor<'c, 'a> exists as proof that 'c outlives 'a.
@@ -648,12 +688,77 @@ impl<'a> ParseBuffer<'a> {
         peek3(self, T::Token::peek)
     }
 
+    /// Looks at the next token in the parse stream to determine whether it is
+    /// the identifier `keyword`, without consuming it.
+    ///
+    /// This is meant for contextual keywords of a custom syntax, which are
+    /// ordinary identifiers to Rust and so have no `Token!` type. Like
+    /// [`Ident::parse_any`], any identifier is considered, including Rust
+    /// keywords such as `fn` or `self`, so `peek_keyword("fn")` is equivalent
+    /// to `peek(Token![fn])`. A raw identifier is compared including its `r#`
+    /// prefix, so `r#default` does not count as the keyword `default`, which
+    /// lets the user of the syntax escape a contextual keyword. After a
+    /// successful peek, use `Ident::parse_any` to consume the keyword.
+    ///
+    /// [`Ident::parse_any`]: crate::ext::IdentExt::parse_any
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Ident, Result, Token, Type};
+    /// use syn::ext::IdentExt;
+    /// use syn::parse::{Parse, ParseStream};
+    ///
+    /// // Parses `default T` or `T`, where `default` is a contextual keyword.
+    /// struct MaybeDefault {
+    ///     default: Option<Ident>,
+    ///     ty: Type,
+    /// }
+    ///
+    /// impl Parse for MaybeDefault {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         let default = if input.peek_keyword("default") {
+    ///             Some(input.call(Ident::parse_any)?)
+    ///         } else {
+    ///             None
+    ///         };
+    ///         Ok(MaybeDefault {
+    ///             default,
+    ///             ty: input.parse()?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let parsed: MaybeDefault = syn::parse_str("default u8").unwrap();
+    /// assert!(parsed.default.is_some());
+    ///
+    /// let parsed: MaybeDefault = syn::parse_str("r#default").unwrap();
+    /// assert!(parsed.default.is_none());
+    /// ```
+    pub fn peek_keyword(&self, keyword: &str) -> bool {
+        match self.cursor().ident() {
+            Some((ident, _rest)) => ident == keyword,
+            None => false,
+        }
+    }
+
     /// Parses zero or more occurrences of `T` separated by punctuation of type
     /// `P`, with optional trailing punctuation.
     ///
     /// Parsing continues until the end of this parse stream. The entire content
     /// of this parse stream must consist of `T` and `P`.
     ///
+    /// This is the way to parse a delimited list like the `(a, b, c)` in
+    /// `foo(a, b, c)`. The content stream produced by [`parenthesized!`],
+    /// [`braced!`] or [`bracketed!`] holds only the tokens between the
+    /// delimiters, so its end is the matching closing delimiter and there is
+    /// no need to look for the `)` while parsing the list, as in the example
+    /// below. Tokens after the closing delimiter remain in the outer stream.
+    ///
+    /// [`parenthesized!`]: crate::parenthesized!
+    /// [`braced!`]: crate::braced!
+    /// [`bracketed!`]: crate::bracketed!
+    ///
     /// # Example
     ///
     /// ```
@@ -752,6 +857,67 @@ impl<'a> ParseBuffer<'a> {
         Punctuated::parse_terminated_with(self, parser)
     }
 
+    /// Parses a group delimited by any of parentheses, square brackets or
+    /// curly braces, and exposes its content to subsequent parsers.
+    ///
+    /// This accepts the same groups as the body of a macro invocation, which
+    /// may be written as `m!(...)`, `m![...]` or `m!{...}`. The returned
+    /// [`MacroDelimiter`] tells which one was used, and the [`DelimSpan`]
+    /// gives the spans of its opening and closing delimiters.
+    ///
+    /// [`MacroDelimiter`]: crate::MacroDelimiter
+    /// [`DelimSpan`]: proc_macro2::extra::DelimSpan
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Expr, MacroDelimiter, Result, Token};
+    /// use syn::parse::{Parse, ParseStream};
+    /// use syn::punctuated::Punctuated;
+    ///
+    /// // Parses the input of a `vec!`-like macro, whose elements may be
+    /// // enclosed in any delimiter: `(1, 2)`, `[1, 2]` or `{1, 2}`.
+    /// struct Elements {
+    ///     delimiter: MacroDelimiter,
+    ///     elems: Punctuated<Expr, Token![,]>,
+    /// }
+    ///
+    /// impl Parse for Elements {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         let (delimiter, _span, content) = input.parse_macro_delimiter()?;
+    ///         Ok(Elements {
+    ///             delimiter,
+    ///             elems: content.parse_terminated(Expr::parse, Token![,])?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let elements: Elements = syn::parse_str("{1, 2, 3}").unwrap();
+    /// assert!(matches!(elements.delimiter, MacroDelimiter::Brace(_)));
+    /// assert_eq!(elements.elems.len(), 3);
+    /// ```
+    #[cfg(any(feature = "full", feature = "derive"))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    pub fn parse_macro_delimiter(&self) -> Result<(MacroDelimiter, DelimSpan, ParseBuffer<'a>)> {
+        self.step(|cursor| {
+            if let Some((content, delimiter, span, rest)) = cursor.any_visible_group() {
+                let delimiter = match delimiter {
+                    Delimiter::Parenthesis => MacroDelimiter::Paren(token::Paren(span)),
+                    Delimiter::Brace => MacroDelimiter::Brace(token::Brace(span)),
+                    Delimiter::Bracket => MacroDelimiter::Bracket(token::Bracket(span)),
+                    Delimiter::None => unreachable!(),
+                };
+                let scope = crate::buffer::close_span_of_group(*cursor);
+                let nested = advance_step_cursor(cursor, content);
+                let unexpected = get_unexpected(self);
+                let content = new_parse_buffer(scope, nested, unexpected);
+                Ok(((delimiter, span, content), rest))
+            } else {
+                Err(cursor.error("expected delimiter"))
+            }
+        })
+    }
+
     /// Returns whether there are tokens remaining in this stream.
     ///
     /// This method returns true at the end of the content of a set of
@@ -793,6 +959,46 @@ impl<'a> ParseBuffer<'a> {
         self.cursor().eof()
     }
 
+    /// Returns the number of tokens remaining in this stream.
+    ///
+    /// Only tokens at the top level of the stream are counted: a delimited
+    /// group like `(a, b)` counts as a single token regardless of its
+    /// contents, as does a lifetime like `'a`.
+    ///
+    /// # Performance
+    ///
+    /// Unlike [`is_empty`][Self::is_empty], this is not a constant time
+    /// operation. It does not clone any tokens, but it walks every remaining
+    /// top-level token to count them, so it is O(n) in the length of the rest
+    /// of the stream. Calling it once per token in a parsing loop makes the
+    /// loop quadratic; to find out whether there is anything left to parse,
+    /// use `is_empty` or [`peek`][Self::peek] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::Result;
+    /// use syn::parse::{ParseStream, Parser};
+    ///
+    /// fn count(input: ParseStream) -> Result<usize> {
+    ///     let remaining = input.remaining();
+    ///     input.parse::<proc_macro2::TokenStream>()?;
+    ///     Ok(remaining)
+    /// }
+    ///
+    /// let n = count.parse_str("fn f<'a>(x: &'a u8) {}").unwrap();
+    /// assert_eq!(n, 7);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        let mut cursor = self.cursor();
+        let mut count = 0;
+        while let Some(rest) = cursor.skip() {
+            cursor = rest;
+            count += 1;
+        }
+        count
+    }
+
     /// Constructs a helper for peeking at the next token in this stream and
     /// building an error message if it is not one of a set of expected tokens.
     ///
@@ -975,6 +1181,79 @@ impl<'a> ParseBuffer<'a> {
         }
     }
 
+    /// Saves the current position of this parse stream, so that parsing can
+    /// later go back to it by [`restore`][ParseBuffer::restore].
+    ///
+    /// This is an alternative to [`fork`][ParseBuffer::fork] for trying
+    /// several ways of parsing the same input in turn: instead of parsing
+    /// each attempt in a fork and advancing the original stream to the fork
+    /// that succeeded, the attempts are made on the stream itself, which is
+    /// restored to the checkpoint after each one that fails. The same
+    /// performance caveats as for forking apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Expr, Result, Type};
+    /// use syn::parse::{Parse, ParseStream};
+    ///
+    /// // Either a type or, failing that, an expression.
+    /// enum TypeOrExpr {
+    ///     Type(Type),
+    ///     Expr(Expr),
+    /// }
+    ///
+    /// impl Parse for TypeOrExpr {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         let checkpoint = input.checkpoint();
+    ///         if let Ok(ty) = input.parse::<Type>() {
+    ///             if input.is_empty() {
+    ///                 return Ok(TypeOrExpr::Type(ty));
+    ///             }
+    ///         }
+    ///         input.restore(&checkpoint);
+    ///         input.parse().map(TypeOrExpr::Expr)
+    ///     }
+    /// }
+    /// #
+    /// # assert!(matches!(syn::parse_str("Vec<u8>"), Ok(TypeOrExpr::Type(_))));
+    /// # assert!(matches!(syn::parse_str("x * 2"), Ok(TypeOrExpr::Expr(_))));
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint<'a> {
+        let (unexpected, unexpected_span) = inner_unexpected(self);
+        Checkpoint {
+            cursor: self.cursor(),
+            unexpected,
+            unexpected_span,
+        }
+    }
+
+    /// Rewinds this parse stream to a position saved by
+    /// [`checkpoint`][ParseBuffer::checkpoint].
+    ///
+    /// Restoring to a checkpoint does not consume it, so the stream can be
+    /// rewound to the same checkpoint any number of times.
+    ///
+    /// # Panics
+    ///
+    /// The checkpoint must have been taken from this same parse stream, or
+    /// from a fork of it. Restoring to a checkpoint of any other stream is a
+    /// logic error and causes a panic.
+    pub fn restore(&self, checkpoint: &Checkpoint<'a>) {
+        if !crate::buffer::same_scope(self.cursor(), checkpoint.cursor) {
+            panic!("Checkpoint was not taken from the restored parse stream");
+        }
+
+        checkpoint.unexpected.set(match checkpoint.unexpected_span {
+            Some(span) => Unexpected::Some(span),
+            None => Unexpected::None,
+        });
+
+        // See comment on `cell` in the struct definition.
+        self.cell
+            .set(unsafe { mem::transmute::<Cursor, Cursor<'static>>(checkpoint.cursor) });
+    }
+
     /// Triggers an error at the current position of the parse stream.
     ///
     /// # Example
@@ -1082,9 +1361,33 @@ impl<'a> ParseBuffer<'a> {
         Ok(node)
     }
 
-    /// Returns the `Span` of the next token in the parse stream, or
-    /// `Span::call_site()` if this parse stream has completely exhausted its
-    /// input `TokenStream`.
+    /// Returns the `Span` of the next token in the parse stream, for use in
+    /// an error message about that token.
+    ///
+    /// If the next token is a delimited group, this is the span of its
+    /// opening delimiter. If this parse stream has completely exhausted its
+    /// input, the span of the end of input is returned instead: for the
+    /// contents of a delimited group, such as a stream obtained from
+    /// [`parenthesized!`], that is the span of the closing delimiter, and for
+    /// a top-level stream it is `Span::call_site()`.
+    ///
+    /// [`parenthesized!`]: crate::parenthesized!
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Error, Ident, Result};
+    /// use syn::parse::ParseStream;
+    ///
+    /// fn parse_keyword(input: ParseStream) -> Result<Ident> {
+    ///     let span = input.span();
+    ///     let ident: Ident = input.parse()?;
+    ///     if ident != "model" {
+    ///         return Err(Error::new(span, "expected `model`"));
+    ///     }
+    ///     Ok(ident)
+    /// }
+    /// ```
     pub fn span(&self) -> Span {
         let cursor = self.cursor();
         if cursor.eof() {
@@ -1238,6 +1541,20 @@ fn tokens_to_parse_buffer(tokens: &TokenBuffer) -> ParseBuffer {
     new_parse_buffer(scope, cursor, unexpected)
 }
 
+// Parses a prefix of the tokens at `cursor`, returning the position following
+// the parsed syntax tree. Unlike `parse2`, leftover tokens are not an error.
+#[cfg(feature = "full")]
+pub(crate) fn parse_prefix<'a, T>(
+    cursor: Cursor<'a>,
+    f: impl FnOnce(ParseStream) -> Result<T>,
+) -> Result<(T, Cursor<'a>)> {
+    let unexpected = Rc::new(Cell::new(Unexpected::None));
+    let state = new_parse_buffer(Span::call_site(), cursor, unexpected);
+    let node = f(&state)?;
+    state.check_unexpected()?;
+    Ok((node, state.cursor()))
+}
+
 impl<F, T> Parser for F
 where
     F: FnOnce(ParseStream) -> Result<T>,
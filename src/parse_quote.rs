@@ -89,7 +89,7 @@ macro_rules! parse_quote {
 /// // without introducing any call_site() spans.
 /// fn make_ret_pinned_future(sig: &mut Signature) {
 ///     let ret = match &sig.output {
-///         ReturnType::Default => quote_spanned!(sig.paren_token.span=> ()),
+///         ReturnType::Default => quote_spanned!(sig.paren_token.span.join()=> ()),
 ///         ReturnType::Type(_, ret) => quote!(#ret),
 ///     };
 ///     sig.output = parse_quote_spanned! {ret.span()=>
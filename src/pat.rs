@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "printing")]
+use crate::error::{Error, Result};
 use crate::punctuated::Punctuated;
 use proc_macro2::TokenStream;
 
@@ -90,6 +92,52 @@ ast_enum_of_structs! {
     }
 }
 
+impl Pat {
+    /// Returns the identifier bound by a simple binding pattern like `x`,
+    /// `mut x` or `ref x`, and otherwise an error spanned at the pattern.
+    ///
+    /// A binding with a subpattern like `x @ Some(_)` is not considered
+    /// simple. This is useful for macros that process function arguments and
+    /// only support plain argument names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, FnArg, ItemFn, Result};
+    ///
+    /// fn argument_names(f: &ItemFn) -> Result<Vec<String>> {
+    ///     let mut names = Vec::new();
+    ///     for arg in &f.sig.inputs {
+    ///         if let FnArg::Typed(arg) = arg {
+    ///             names.push(arg.pat.reject_complex()?.to_string());
+    ///         }
+    ///     }
+    ///     Ok(names)
+    /// }
+    ///
+    /// let f: ItemFn = parse_quote!(fn f(a: u8, mut b: u8) {});
+    /// assert_eq!(argument_names(&f).unwrap(), ["a", "b"]);
+    ///
+    /// let f: ItemFn = parse_quote!(fn f((a, b): (u8, u8)) {});
+    /// assert!(argument_names(&f).is_err());
+    /// ```
+    #[cfg(feature = "printing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    pub fn reject_complex(&self) -> Result<&Ident> {
+        match self {
+            Pat::Ident(PatIdent {
+                ident,
+                subpat: None,
+                ..
+            }) => Ok(ident),
+            _ => Err(Error::new_spanned(
+                self,
+                "unsupported pattern, only simple bindings like `x` or `mut x` are supported",
+            )),
+        }
+    }
+}
+
 ast_struct! {
     /// A pattern that binds a new variable: `ref mut binding @ SUBPATTERN`.
     ///
@@ -154,6 +202,35 @@ ast_struct! {
     }
 }
 
+impl PatSlice {
+    /// Returns the index within `elems` of the rest pattern `..`, if there is
+    /// one, including a rest pattern bound to a name as in `tail @ ..`.
+    ///
+    /// The elements before the index match the start of the slice and the
+    /// elements after it match the end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Pat};
+    ///
+    /// let pat: Pat = parse_quote!([first, .., last]);
+    /// if let Pat::Slice(pat) = pat {
+    ///     assert_eq!(pat.rest_position(), Some(1));
+    /// }
+    /// ```
+    pub fn rest_position(&self) -> Option<usize> {
+        self.elems.iter().position(|elem| match elem {
+            Pat::Rest(_) => true,
+            Pat::Ident(PatIdent {
+                subpat: Some((_at_token, subpat)),
+                ..
+            }) => matches!(**subpat, Pat::Rest(_)),
+            _ => false,
+        })
+    }
+}
+
 ast_struct! {
     /// A struct or struct variant pattern: `Variant { x, y, .. }`.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
@@ -81,6 +81,61 @@ impl Path {
             None
         }
     }
+
+    /// Returns the ident if this path consists of a single ident, as for
+    /// [`get_ident`][Path::get_ident], and otherwise an error spanning the
+    /// path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Path};
+    ///
+    /// let path: Path = parse_quote!(serde);
+    /// assert_eq!(path.require_ident().unwrap(), "serde");
+    ///
+    /// let path: Path = parse_quote!(serde::Serialize);
+    /// let err = path.require_ident().unwrap_err();
+    /// assert_eq!(err.to_string(), "expected identifier, found path");
+    /// ```
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn require_ident(&self) -> Result<&Ident> {
+        self.get_ident().ok_or_else(|| {
+            let first = self.segments.first();
+            let start = match (&self.leading_colon, first) {
+                (Some(colon), _) => colon.spans[0],
+                (None, Some(segment)) => segment.ident.span(),
+                (None, None) => proc_macro2::Span::call_site(),
+            };
+            let end = match self.segments.last() {
+                Some(segment) => match &segment.arguments {
+                    PathArguments::None => segment.ident.span(),
+                    PathArguments::AngleBracketed(arguments) => arguments.gt_token.span,
+                    PathArguments::Parenthesized(arguments) => match &arguments.output {
+                        ReturnType::Default => arguments.paren_token.span.close(),
+                        ReturnType::Type(arrow, ty) => return_type_end(arrow, ty),
+                    },
+                },
+                None => start,
+            };
+            error::new2(start, end, "expected identifier, found path")
+        })
+    }
+}
+
+// The span of the last token of a path's `-> Type` output. Finding it needs
+// the type's tokens, so without "printing" the span stops at the arrow.
+#[cfg(feature = "parsing")]
+fn return_type_end(arrow: &Token![->], ty: &Type) -> proc_macro2::Span {
+    #[cfg(feature = "printing")]
+    {
+        if let Some(token) = quote::ToTokens::to_token_stream(ty).into_iter().last() {
+            return token.span();
+        }
+    }
+    let _ = ty;
+    arrow.spans[1]
 }
 
 ast_struct! {
@@ -171,6 +226,66 @@ ast_enum! {
     }
 }
 
+impl GenericArgument {
+    /// Returns the type if this is a type argument like the `T` in `Vec<T>`.
+    ///
+    /// Associated type bindings like the `Item = T` in `Iterator<Item = T>`
+    /// are not type arguments; use [`as_assoc_type`] for those.
+    ///
+    /// [`as_assoc_type`]: GenericArgument::as_assoc_type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, PathArguments, Type, TypePath};
+    ///
+    /// let ty: TypePath = parse_quote!(Result<'a, T, E>);
+    /// if let PathArguments::AngleBracketed(args) = &ty.path.segments[0].arguments {
+    ///     let types: Vec<&Type> = args.args.iter().filter_map(|arg| arg.as_type()).collect();
+    ///     assert_eq!(types.len(), 2);
+    /// }
+    /// ```
+    pub fn as_type(&self) -> Option<&Type> {
+        match self {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// Returns the lifetime if this is a lifetime argument like the `'a` in
+    /// `Cow<'a, str>`.
+    pub fn as_lifetime(&self) -> Option<&Lifetime> {
+        match self {
+            GenericArgument::Lifetime(lifetime) => Some(lifetime),
+            _ => None,
+        }
+    }
+
+    /// Returns the expression if this is a const argument like the `{ N + 1 }`
+    /// in `Foo<{ N + 1 }>`.
+    ///
+    /// Const arguments that consist of a single identifier are parsed as type
+    /// arguments, as they are syntactically indistinguishable, and are
+    /// returned by [`as_type`] instead.
+    ///
+    /// [`as_type`]: GenericArgument::as_type
+    pub fn as_const(&self) -> Option<&Expr> {
+        match self {
+            GenericArgument::Const(expr) => Some(expr),
+            _ => None,
+        }
+    }
+
+    /// Returns the binding if this is an associated type binding like the
+    /// `Item = T` in `Iterator<Item = T>`.
+    pub fn as_assoc_type(&self) -> Option<&AssocType> {
+        match self {
+            GenericArgument::AssocType(assoc) => Some(assoc),
+            _ => None,
+        }
+    }
+}
+
 ast_struct! {
     /// Angle bracketed arguments of a path segment: the `<K, V>` in `HashMap<K,
     /// V>`.
@@ -783,10 +898,29 @@ pub(crate) mod printing {
     }
 
     pub(crate) fn print_path(tokens: &mut TokenStream, qself: &Option<QSelf>, path: &Path) {
+        print_qpath(tokens, qself, path, false);
+    }
+
+    // Like print_path, but writes every angle bracketed argument list in
+    // turbofish form `::<...>` so that the path is valid in expression
+    // position.
+    pub(crate) fn print_path_turbofish(
+        tokens: &mut TokenStream,
+        qself: &Option<QSelf>,
+        path: &Path,
+    ) {
+        print_qpath(tokens, qself, path, true);
+    }
+
+    fn print_qpath(tokens: &mut TokenStream, qself: &Option<QSelf>, path: &Path, turbofish: bool) {
         let qself = match qself {
             Some(qself) => qself,
             None => {
-                path.to_tokens(tokens);
+                path.leading_colon.to_tokens(tokens);
+                for segment in path.segments.pairs() {
+                    print_segment(tokens, segment.value(), turbofish);
+                    segment.punct().to_tokens(tokens);
+                }
                 return;
             }
         };
@@ -799,20 +933,32 @@ pub(crate) mod printing {
             TokensOrDefault(&qself.as_token).to_tokens(tokens);
             path.leading_colon.to_tokens(tokens);
             for (i, segment) in segments.by_ref().take(pos).enumerate() {
+                print_segment(tokens, segment.value(), turbofish);
                 if i + 1 == pos {
-                    segment.value().to_tokens(tokens);
                     qself.gt_token.to_tokens(tokens);
-                    segment.punct().to_tokens(tokens);
-                } else {
-                    segment.to_tokens(tokens);
                 }
+                segment.punct().to_tokens(tokens);
             }
         } else {
             qself.gt_token.to_tokens(tokens);
             path.leading_colon.to_tokens(tokens);
         }
         for segment in segments {
-            segment.to_tokens(tokens);
+            print_segment(tokens, segment.value(), turbofish);
+            segment.punct().to_tokens(tokens);
+        }
+    }
+
+    fn print_segment(tokens: &mut TokenStream, segment: &PathSegment, turbofish: bool) {
+        segment.ident.to_tokens(tokens);
+        match &segment.arguments {
+            PathArguments::AngleBracketed(arguments)
+                if turbofish && arguments.colon2_token.is_none() =>
+            {
+                Token![::](arguments.lt_token.span).to_tokens(tokens);
+                arguments.to_tokens(tokens);
+            }
+            arguments => arguments.to_tokens(tokens),
         }
     }
 }
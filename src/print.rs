@@ -1,8 +1,10 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 
+#[cfg(any(feature = "full", feature = "derive"))]
 pub(crate) struct TokensOrDefault<'a, T: 'a>(pub &'a Option<T>);
 
+#[cfg(any(feature = "full", feature = "derive"))]
 impl<'a, T> ToTokens for TokensOrDefault<'a, T>
 where
     T: ToTokens + Default,
@@ -14,3 +16,88 @@ where
         }
     }
 }
+
+/// Additional methods for emitting optional values and lists of syntax tree
+/// nodes as tokens.
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn. It
+/// is implemented for `Option<T>` and `[T]`, and so also for `Vec<T>`, where
+/// `T` implements `ToTokens`.
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, Attribute, ToTokensExt, Token};
+///
+/// let attrs: Vec<Attribute> = vec![parse_quote!(#[inline]), parse_quote!(#[must_use])];
+/// let comma: Option<Token![,]> = None;
+///
+/// let tokens = attrs.tokens_or_empty();
+/// assert_eq!(tokens.to_string(), "# [inline] # [must_use]");
+/// assert!(comma.tokens_or_empty().is_empty());
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub trait ToTokensExt: private::Sealed {
+    /// Returns the tokens of every value present, or an empty token stream
+    /// if there are none.
+    fn tokens_or_empty(&self) -> TokenStream;
+}
+
+impl<T: ToTokens> ToTokensExt for Option<T> {
+    fn tokens_or_empty(&self) -> TokenStream {
+        match self {
+            Some(t) => t.to_token_stream(),
+            None => TokenStream::new(),
+        }
+    }
+}
+
+impl<T: ToTokens> ToTokensExt for [T] {
+    fn tokens_or_empty(&self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        for t in self {
+            t.to_tokens(&mut tokens);
+        }
+        tokens
+    }
+}
+
+/// Emits the given values separated by the punctuation `P`, without a
+/// trailing separator.
+///
+/// This is the counterpart of printing a [`Punctuated`] for values that are
+/// not stored in one.
+///
+/// [`Punctuated`]: crate::punctuated::Punctuated
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, Token, Type};
+///
+/// let types: Vec<Type> = vec![parse_quote!(u8), parse_quote!(String)];
+/// let tokens = syn::to_token_stream_separated::<_, Token![,]>(&types);
+/// assert_eq!(tokens.to_string(), "u8 , String");
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub fn to_token_stream_separated<T, P>(items: &[T]) -> TokenStream
+where
+    T: ToTokens,
+    P: ToTokens + Default,
+{
+    let mut tokens = TokenStream::new();
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            P::default().to_tokens(&mut tokens);
+        }
+        item.to_tokens(&mut tokens);
+    }
+    tokens
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl<T> Sealed for Option<T> {}
+    impl<T> Sealed for [T] {}
+}
@@ -36,6 +36,7 @@ use crate::drops::{NoDrop, TrivialDrop};
 use crate::parse::{Parse, ParseStream, Result};
 #[cfg(feature = "parsing")]
 use crate::token::Token;
+use proc_macro2::Span;
 
 /// A punctuated sequence of syntax tree nodes of type `T` separated by
 /// punctuation of type `P`.
@@ -57,6 +58,98 @@ impl<T, P> Punctuated<T, P> {
         }
     }
 
+    /// Creates a punctuated sequence from its nodes and the punctuation
+    /// between them, keeping each punctuation token exactly as given.
+    ///
+    /// The `i`th punctuation token follows the `i`th node. There may be one
+    /// fewer punctuation token than nodes, or the same number to produce a
+    /// sequence with trailing punctuation. To build a sequence from pairs of
+    /// nodes and punctuation instead, collect an iterator of [`Pair`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of punctuation tokens is neither the number of
+    /// nodes nor one less.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use syn::punctuated::Punctuated;
+    /// use syn::{Ident, Token};
+    ///
+    /// let span = Span::call_site();
+    /// let idents = vec![Ident::new("a", span), Ident::new("b", span)];
+    /// let commas = vec![<Token![,]>::default()];
+    ///
+    /// let list = Punctuated::from_values_and_puncts(idents, commas);
+    /// assert_eq!(list.len(), 2);
+    /// assert!(!list.trailing_punct());
+    /// ```
+    pub fn from_values_and_puncts(values: Vec<T>, puncts: Vec<P>) -> Self {
+        let (values_len, puncts_len) = (values.len(), puncts.len());
+        if puncts_len != values_len && puncts_len + 1 != values_len {
+            panic!(
+                "Punctuated::from_values_and_puncts expected {} or {} punctuation tokens for {} values, got {}",
+                values_len.saturating_sub(1),
+                values_len,
+                values_len,
+                puncts_len,
+            );
+        }
+        let mut values = values.into_iter();
+        let inner = puncts
+            .into_iter()
+            .map(|punct| (values.next().unwrap(), punct))
+            .collect();
+        Punctuated {
+            inner,
+            last: values.next().map(Box::new),
+        }
+    }
+
+    /// Sets the span of every punctuation token to `span`, for example to
+    /// make separators synthesized with the default `call_site` span point
+    /// at the input they were generated for. The spans of the syntax tree
+    /// nodes are left unchanged.
+    ///
+    /// Any of Syn's punctuation and keyword tokens can be converted from a
+    /// `Span` in this way, as can those defined by [`custom_keyword!`] and
+    /// [`custom_punctuation!`].
+    ///
+    /// [`custom_keyword!`]: crate::custom_keyword
+    /// [`custom_punctuation!`]: crate::custom_punctuation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::parse::Parser;
+    /// use syn::punctuated::Punctuated;
+    /// use syn::spanned::Spanned;
+    /// use syn::{Expr, Token};
+    ///
+    /// let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+    /// let mut args = parser.parse_str("a, b, c").unwrap();
+    /// let first = args[0].span().source_text();
+    ///
+    /// // Point every comma at the last argument.
+    /// let span = args[2].span();
+    /// args.respan(span);
+    ///
+    /// for punct in args.puncts() {
+    ///     assert_eq!(punct.span.source_text(), span.source_text());
+    /// }
+    /// assert_eq!(args[0].span().source_text(), first);
+    /// ```
+    pub fn respan(&mut self, span: Span)
+    where
+        P: From<Span>,
+    {
+        for (_value, punct) in &mut self.inner {
+            *punct = P::from(span);
+        }
+    }
+
     /// Determines whether this punctuated sequence is empty, meaning it
     /// contains no syntax tree nodes or punctuation.
     pub fn is_empty(&self) -> bool {
@@ -112,6 +205,18 @@ impl<T, P> Punctuated<T, P> {
         }
     }
 
+    /// Returns an iterator over the borrowed punctuation of type `&P`,
+    /// without the syntax tree nodes between them.
+    ///
+    /// Trailing punctuation is yielded like any other, so a sequence of `n`
+    /// nodes yields `n` punctuation tokens if it has trailing punctuation and
+    /// `n - 1` otherwise.
+    pub fn puncts(&self) -> Puncts<'_, T, P> {
+        Puncts {
+            inner: self.inner.iter(),
+        }
+    }
+
     /// Returns an iterator over the contents of this sequence as borrowed
     /// punctuated pairs.
     pub fn pairs(&self) -> Pairs<T, P> {
@@ -433,6 +538,15 @@ where
     }
 }
 
+/// Builds a punctuated sequence from pairs of nodes and their punctuation,
+/// keeping each punctuation token exactly as given.
+///
+/// Only the last pair may be a `Pair::End`. A sequence collected only from
+/// `Pair::Punctuated` has trailing punctuation.
+///
+/// # Panics
+///
+/// Panics if a `Pair::End` is followed by another pair.
 impl<T, P> FromIterator<Pair<T, P>> for Punctuated<T, P> {
     fn from_iter<I: IntoIterator<Item = Pair<T, P>>>(i: I) -> Self {
         let mut ret = Punctuated::new();
@@ -901,6 +1015,48 @@ where
 {
 }
 
+/// An iterator over borrowed punctuation of type `&P`.
+///
+/// Refer to the [module documentation] for details about punctuated sequences.
+///
+/// [module documentation]: self
+pub struct Puncts<'a, T: 'a, P: 'a> {
+    inner: slice::Iter<'a, (T, P)>,
+}
+
+impl<'a, T, P> Iterator for Puncts<'a, T, P> {
+    type Item = &'a P;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_t, p)| p)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<'a, T, P> DoubleEndedIterator for Puncts<'a, T, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_t, p)| p)
+    }
+}
+
+impl<'a, T, P> ExactSizeIterator for Puncts<'a, T, P> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+// No Clone bound on T or P.
+impl<'a, T, P> Clone for Puncts<'a, T, P> {
+    fn clone(&self) -> Self {
+        Puncts {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 /// A single syntax tree node of type `T` followed by its trailing punctuation
 /// of type `P` if any.
 ///
@@ -0,0 +1,155 @@
+//! Renaming of struct fields and enum variants by a serde `rename_all` rule.
+//!
+//! The rules are those of serde: a field name is expected to be written in
+//! snake case and a variant name in Pascal case, and each is converted by
+//! inserting or replacing separators and changing the case of letters. A
+//! name that is written in some other case is converted character by
+//! character all the same, for example the variant `HTTPServer` becomes
+//! `hTTPServer` in camel case and `h_t_t_p_server` in snake case.
+//!
+//! The supported rules are `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`,
+//! `"camelCase"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`
+//! and `"SCREAMING-KEBAB-CASE"`.
+
+use crate::error::{Error, Result};
+use proc_macro2::Ident;
+
+/// Renames a struct field, whose name is expected to be in snake case,
+/// according to a `rename_all` rule, as serde does.
+///
+/// A raw marker `r#` on the identifier is ignored. An error spanned to the
+/// identifier is returned if `rule` is not one of the supported rules.
+///
+/// # Example
+///
+/// ```
+/// use proc_macro2::Span;
+/// use syn::Ident;
+///
+/// let field = Ident::new("content_type", Span::call_site());
+/// assert_eq!(syn::rename::apply_to_field(&field, "camelCase").unwrap(), "contentType");
+/// assert_eq!(syn::rename::apply_to_field(&field, "UPPERCASE").unwrap(), "CONTENT_TYPE");
+/// assert_eq!(syn::rename::apply_to_field(&field, "lowercase").unwrap(), "content_type");
+///
+/// let field = Ident::new_raw("type", Span::call_site());
+/// assert_eq!(syn::rename::apply_to_field(&field, "PascalCase").unwrap(), "Type");
+/// ```
+pub fn apply_to_field(ident: &Ident, rule: &str) -> Result<String> {
+    let field = unraw(ident);
+    Ok(match parse_rule(ident, rule)? {
+        Rule::Lower | Rule::Snake => field,
+        Rule::Upper | Rule::ScreamingSnake => field.to_ascii_uppercase(),
+        Rule::Pascal => field_to_pascal_case(&field),
+        Rule::Camel => lowercase_first(&field_to_pascal_case(&field)),
+        Rule::Kebab => field.replace('_', "-"),
+        Rule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+    })
+}
+
+/// Renames an enum variant, whose name is expected to be in Pascal case,
+/// according to a `rename_all` rule, as serde does.
+///
+/// A raw marker `r#` on the identifier is ignored. An error spanned to the
+/// identifier is returned if `rule` is not one of the supported rules.
+///
+/// # Example
+///
+/// ```
+/// use proc_macro2::Span;
+/// use syn::Ident;
+///
+/// let variant = Ident::new("StatusCode", Span::call_site());
+/// assert_eq!(syn::rename::apply_to_variant(&variant, "camelCase").unwrap(), "statusCode");
+/// assert_eq!(syn::rename::apply_to_variant(&variant, "kebab-case").unwrap(), "status-code");
+/// assert_eq!(syn::rename::apply_to_variant(&variant, "lowercase").unwrap(), "statuscode");
+/// ```
+pub fn apply_to_variant(ident: &Ident, rule: &str) -> Result<String> {
+    let variant = unraw(ident);
+    Ok(match parse_rule(ident, rule)? {
+        Rule::Pascal => variant,
+        Rule::Lower => variant.to_ascii_lowercase(),
+        Rule::Upper => variant.to_ascii_uppercase(),
+        Rule::Camel => lowercase_first(&variant),
+        Rule::Snake => variant_to_snake_case(&variant),
+        Rule::ScreamingSnake => variant_to_snake_case(&variant).to_ascii_uppercase(),
+        Rule::Kebab => variant_to_snake_case(&variant).replace('_', "-"),
+        Rule::ScreamingKebab => variant_to_snake_case(&variant)
+            .to_ascii_uppercase()
+            .replace('_', "-"),
+    })
+}
+
+enum Rule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+fn parse_rule(ident: &Ident, rule: &str) -> Result<Rule> {
+    match rule {
+        "lowercase" => Ok(Rule::Lower),
+        "UPPERCASE" => Ok(Rule::Upper),
+        "PascalCase" => Ok(Rule::Pascal),
+        "camelCase" => Ok(Rule::Camel),
+        "snake_case" => Ok(Rule::Snake),
+        "SCREAMING_SNAKE_CASE" => Ok(Rule::ScreamingSnake),
+        "kebab-case" => Ok(Rule::Kebab),
+        "SCREAMING-KEBAB-CASE" => Ok(Rule::ScreamingKebab),
+        _ => Err(Error::new(
+            ident.span(),
+            format!("unsupported rename_all rule: {:?}", rule),
+        )),
+    }
+}
+
+fn unraw(ident: &Ident) -> String {
+    let name = ident.to_string();
+    match name.strip_prefix("r#") {
+        Some(name) => name.to_owned(),
+        None => name,
+    }
+}
+
+// Uppercases the first letter and every letter after an underscore, and
+// drops the underscores.
+fn field_to_pascal_case(field: &str) -> String {
+    let mut pascal = String::new();
+    let mut capitalize = true;
+    for ch in field.chars() {
+        if ch == '_' {
+            capitalize = true;
+        } else if capitalize {
+            pascal.push(ch.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            pascal.push(ch);
+        }
+    }
+    pascal
+}
+
+// Puts an underscore before every uppercase letter but the first, and
+// lowercases all letters.
+fn variant_to_snake_case(variant: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in variant.char_indices() {
+        if i > 0 && ch.is_uppercase() {
+            snake.push('_');
+        }
+        snake.push(ch.to_ascii_lowercase());
+    }
+    snake
+}
+
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
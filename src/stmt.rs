@@ -1,4 +1,5 @@
 use super::*;
+use crate::punctuated::Punctuated;
 
 ast_struct! {
     /// A braced block containing Rust statements.
@@ -10,6 +11,46 @@ ast_struct! {
     }
 }
 
+impl Block {
+    /// Replaces the trailing expression `e` of this block with `f(e)`, for
+    /// example to turn `{ ...; x }` into `{ ...; Ok(x) }`.
+    ///
+    /// A block without a trailing expression is treated as evaluating to
+    /// `()`, and gets a new trailing expression `f(())` spanned like its
+    /// braces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Block};
+    ///
+    /// let mut block: Block = parse_quote!({ run(); });
+    /// block.wrap_tail(|expr| parse_quote!(Ok(#expr)));
+    ///
+    /// let expected: Block = parse_quote!({ run(); Ok(()) });
+    /// assert_eq!(block, expected);
+    /// ```
+    pub fn wrap_tail(&mut self, f: impl FnOnce(Expr) -> Expr) {
+        let tail = match self.stmts.pop() {
+            Some(Stmt::Expr(expr, None)) => expr,
+            Some(Stmt::Macro(StmtMacro {
+                attrs,
+                mac,
+                semi_token: None,
+            })) => Expr::Macro(ExprMacro { attrs, mac }),
+            stmt => {
+                self.stmts.extend(stmt);
+                Expr::Tuple(ExprTuple {
+                    attrs: Vec::new(),
+                    paren_token: token::Paren(self.brace_token.span),
+                    elems: Punctuated::new(),
+                })
+            }
+        };
+        self.stmts.push(Stmt::Expr(f(tail), None));
+    }
+}
+
 ast_enum! {
     /// A statement, usually ending in a semicolon.
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
@@ -323,6 +364,7 @@ pub(crate) mod parsing {
                 Expr::Array(_)
                 | Expr::Async(_)
                 | Expr::Await(_)
+                | Expr::Become(_)
                 | Expr::Block(_)
                 | Expr::Break(_)
                 | Expr::Call(_)
@@ -344,6 +386,7 @@ pub(crate) mod parsing {
                 | Expr::Paren(_)
                 | Expr::Path(_)
                 | Expr::Range(_)
+                | Expr::RawAddr(_)
                 | Expr::Reference(_)
                 | Expr::Repeat(_)
                 | Expr::Return(_)
@@ -102,6 +102,7 @@ use crate::lookahead;
 #[cfg(feature = "parsing")]
 use crate::parse::{Parse, ParseStream};
 use crate::span::IntoSpans;
+use proc_macro2::extra::DelimSpan;
 use proc_macro2::Span;
 #[cfg(feature = "printing")]
 use proc_macro2::TokenStream;
@@ -267,6 +268,12 @@ macro_rules! define_keywords {
                 }
             }
 
+            impl From<Span> for $name {
+                fn from(span: Span) -> Self {
+                    $name { span }
+                }
+            }
+
             #[cfg(feature = "clone-impls")]
             #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
             impl Copy for $name {}
@@ -393,6 +400,14 @@ macro_rules! define_punctuation_structs {
                 }
             }
 
+            impl From<Span> for $name {
+                fn from(span: Span) -> Self {
+                    $name {
+                        spans: [span; $len],
+                    }
+                }
+            }
+
             #[cfg(feature = "clone-impls")]
             #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
             impl Copy for $name {}
@@ -483,12 +498,14 @@ macro_rules! define_delimiters {
         $(
             #[$doc]
             pub struct $name {
-                pub span: Span,
+                /// The span of the whole group together with the spans of
+                /// its opening and closing delimiter.
+                pub span: DelimSpan,
             }
 
             #[doc(hidden)]
             #[allow(non_snake_case)]
-            pub fn $name<S: IntoSpans<Span>>(span: S) -> $name {
+            pub fn $name<S: IntoSpans<DelimSpan>>(span: S) -> $name {
                 $name {
                     span: span.into_spans(),
                 }
@@ -496,9 +513,7 @@ macro_rules! define_delimiters {
 
             impl std::default::Default for $name {
                 fn default() -> Self {
-                    $name {
-                        span: Span::call_site(),
-                    }
+                    $name(Span::call_site())
                 }
             }
 
@@ -541,6 +556,26 @@ macro_rules! define_delimiters {
             }
 
             impl $name {
+                /// Returns the span of the opening delimiter.
+                ///
+                /// This is the same as `self.span.open()`.
+                pub fn open(&self) -> Span {
+                    self.span.open()
+                }
+
+                /// Returns the span of the closing delimiter.
+                ///
+                /// This is the same as `self.span.close()`.
+                pub fn close(&self) -> Span {
+                    self.span.close()
+                }
+
+                /// Prints the tokens produced by `f` inside of this pair of
+                /// delimiters.
+                ///
+                /// The delimiters of the printed group are spanned by
+                /// [`span.join()`][DelimSpan::join], since a group can only be
+                /// given a single span when it is constructed.
                 #[cfg(feature = "printing")]
                 pub fn surround<F>(&self, tokens: &mut TokenStream, f: F)
                 where
@@ -548,7 +583,7 @@ macro_rules! define_delimiters {
                 {
                     let mut inner = TokenStream::new();
                     f(&mut inner);
-                    printing::delim(Delimiter::$delim, self.span, tokens, inner);
+                    printing::delim(Delimiter::$delim, self.span.join(), tokens, inner);
                 }
             }
 
@@ -688,6 +723,7 @@ define_keywords! {
     "override"    pub struct Override
     "priv"        pub struct Priv
     "pub"         pub struct Pub
+    "raw"         pub struct Raw
     "ref"         pub struct Ref
     "return"      pub struct Return
     "Self"        pub struct SelfType
@@ -762,9 +798,82 @@ define_delimiters! {
     Brace         pub struct Brace        /// `{`&hellip;`}`
     Bracket       pub struct Bracket      /// `[`&hellip;`]`
     Parenthesis   pub struct Paren        /// `(`&hellip;`)`
-    None          pub struct Group        /// None-delimited group
 }
 
+/// None-delimited group
+pub struct Group {
+    pub span: Span,
+}
+
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub fn Group<S: IntoSpans<Span>>(span: S) -> Group {
+    Group {
+        span: span.into_spans(),
+    }
+}
+
+impl std::default::Default for Group {
+    fn default() -> Self {
+        Group {
+            span: Span::call_site(),
+        }
+    }
+}
+
+#[cfg(feature = "clone-impls")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Copy for Group {}
+
+#[cfg(feature = "clone-impls")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for Group {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Debug for Group {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Group")
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl cmp::Eq for Group {}
+
+#[cfg(feature = "extra-traits")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for Group {
+    fn eq(&self, _other: &Group) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for Group {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl Group {
+    #[cfg(feature = "printing")]
+    pub fn surround<F>(&self, tokens: &mut TokenStream, f: F)
+    where
+        F: FnOnce(&mut TokenStream),
+    {
+        let mut inner = TokenStream::new();
+        f(&mut inner);
+        printing::delim(Delimiter::None, self.span, tokens, inner);
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl private::Sealed for Group {}
+
 /// A type-macro that expands to the name of the Rust type representation of a
 /// given token.
 ///
@@ -808,6 +917,7 @@ macro_rules! Token {
     [override]    => { $crate::token::Override };
     [priv]        => { $crate::token::Priv };
     [pub]         => { $crate::token::Pub };
+    [raw]         => { $crate::token::Raw };
     [ref]         => { $crate::token::Ref };
     [return]      => { $crate::token::Return };
     [Self]        => { $crate::token::SelfType };
@@ -1,6 +1,15 @@
+//! Types and helpers for rewriting them.
+
 use super::*;
-use crate::punctuated::Punctuated;
+use crate::punctuated::{self, Punctuated};
 use proc_macro2::TokenStream;
+#[cfg(feature = "printing")]
+use proc_macro2::{Delimiter, Spacing, TokenTree};
+#[cfg(feature = "printing")]
+use quote::ToTokens;
+#[cfg(feature = "clone-impls")]
+use std::borrow::Cow;
+use std::iter;
 
 ast_enum_of_structs! {
     /// The possible types that a Rust value could have.
@@ -80,6 +89,494 @@ ast_enum_of_structs! {
     }
 }
 
+impl Type {
+    /// If this type is one of the standard library smart pointers `Box<T>`,
+    /// `Rc<T>`, `Arc<T>` or `Cow<'a, T>`, returns the name of the wrapper
+    /// together with the inner type `T`.
+    ///
+    /// Only the last segment of the path is inspected, so `Box<T>`,
+    /// `std::boxed::Box<T>` and `alloc::rc::Rc<T>` are all recognized. Paths
+    /// with a qualified self type like `<X as Trait>::Box<T>` are not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let ty: Type = parse_quote!(std::borrow::Cow<'static, str>);
+    /// let (wrapper, inner) = ty.smart_ptr_inner().unwrap();
+    /// assert_eq!(wrapper, "Cow");
+    /// assert!(matches!(inner, Type::Path(ty) if ty.path.is_ident("str")));
+    /// ```
+    pub fn smart_ptr_inner(&self) -> Option<(&'static str, &Type)> {
+        let path = match self {
+            Type::Path(TypePath { qself: None, path }) => path,
+            _ => return None,
+        };
+        let segment = path.segments.last()?;
+        let wrapper = if segment.ident == "Box" {
+            "Box"
+        } else if segment.ident == "Rc" {
+            "Rc"
+        } else if segment.ident == "Arc" {
+            "Arc"
+        } else if segment.ident == "Cow" {
+            "Cow"
+        } else {
+            return None;
+        };
+        let args = match &segment.arguments {
+            PathArguments::AngleBracketed(bracketed) => &bracketed.args,
+            PathArguments::None | PathArguments::Parenthesized(_) => return None,
+        };
+        args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(inner) => Some((wrapper, inner)),
+            _ => None,
+        })
+    }
+
+    /// If this type is a single identifier like `u32` or `String`, returns
+    /// that identifier.
+    ///
+    /// This is the case for a type path with one segment, no generic
+    /// arguments, no leading `::` and no qualified self type, as for
+    /// [`Path::get_ident`]. An invisible group around the type, as from a
+    /// `$ty:ty` fragment of a `macro_rules!` macro, is looked through, but
+    /// parentheses are not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let ty: Type = parse_quote!(String);
+    /// assert_eq!(ty.as_ident().unwrap(), "String");
+    ///
+    /// let ty: Type = parse_quote!(Vec<u8>);
+    /// assert!(ty.as_ident().is_none());
+    /// ```
+    pub fn as_ident(&self) -> Option<&Ident> {
+        match self {
+            Type::Group(ty) => ty.elem.as_ident(),
+            Type::Path(TypePath { qself: None, path }) => path.get_ident(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this type is the inference placeholder `_`, possibly
+    /// within parentheses or an invisible group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let ty: Type = parse_quote!((_));
+    /// assert!(ty.is_infer());
+    ///
+    /// let ty: Type = parse_quote!(Vec<_>);
+    /// assert!(!ty.is_infer());
+    /// ```
+    pub fn is_infer(&self) -> bool {
+        match self {
+            Type::Infer(_) => true,
+            Type::Group(ty) => ty.elem.is_infer(),
+            Type::Paren(ty) => ty.elem.is_infer(),
+            _ => false,
+        }
+    }
+
+    /// Counts the layers of references around this type and whether they are
+    /// mutable, for example to decide whether a value of this type is
+    /// borrowed or owned.
+    ///
+    /// Parentheses and invisible groups are looked through. Raw pointers are
+    /// not references and end the search, as does any other type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let ty: Type = parse_quote!(&mut &'a str);
+    /// let summary = ty.reference_summary();
+    /// assert_eq!(summary.depth, 2);
+    /// assert!(summary.outer_mut);
+    /// assert!(summary.any_mut);
+    /// ```
+    pub fn reference_summary(&self) -> RefSummary {
+        let mut summary = RefSummary {
+            depth: 0,
+            outer_mut: false,
+            any_mut: false,
+        };
+        let mut ty = self;
+        loop {
+            ty = match ty {
+                Type::Group(ty) => &ty.elem,
+                Type::Paren(ty) => &ty.elem,
+                Type::Reference(reference) => {
+                    let mutable = reference.mutability.is_some();
+                    if summary.depth == 0 {
+                        summary.outer_mut = mutable;
+                    }
+                    summary.any_mut |= mutable;
+                    summary.depth += 1;
+                    &reference.elem
+                }
+                _ => return summary,
+            };
+        }
+    }
+
+    /// Determines whether this type must be parenthesized to be used as the
+    /// referent of a reference or raw pointer, as in `&(dyn Trait + Send)`.
+    ///
+    /// This is the case for a type that ends in a list of bounds joined by
+    /// `+`, which would otherwise be ambiguous: a trait object or `impl Trait`
+    /// with more than one bound, including lifetime bounds, and a function
+    /// pointer or `Fn(...) -> ...` bound returning such a type. Other types, like
+    /// `dyn Trait` with a single bound or `fn()`, can be used as is. Invisible
+    /// groups are looked through.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let referent: Type = parse_quote!(dyn Iterator<Item = u8> + Send);
+    /// let reference = if referent.needs_parens_in_ref_position() {
+    ///     quote!(&(#referent))
+    /// } else {
+    ///     quote!(&#referent)
+    /// };
+    /// assert_eq!(
+    ///     reference.to_string(),
+    ///     quote!(&(dyn Iterator<Item = u8> + Send)).to_string(),
+    /// );
+    /// ```
+    pub fn needs_parens_in_ref_position(&self) -> bool {
+        match self {
+            Type::BareFn(ty) => match &ty.output {
+                ReturnType::Type(_, ty) => ty.needs_parens_in_ref_position(),
+                ReturnType::Default => false,
+            },
+            Type::Group(ty) => ty.elem.needs_parens_in_ref_position(),
+            Type::ImplTrait(TypeImplTrait { bounds, .. })
+            | Type::TraitObject(TypeTraitObject { bounds, .. }) => {
+                if bounds.len() > 1 {
+                    return true;
+                }
+                match bounds.last() {
+                    Some(TypeParamBound::Trait(bound)) => match bound.path.segments.last() {
+                        Some(PathSegment {
+                            arguments: PathArguments::Parenthesized(arguments),
+                            ..
+                        }) => match &arguments.output {
+                            ReturnType::Type(_, ty) => ty.needs_parens_in_ref_position(),
+                            ReturnType::Default => false,
+                        },
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Determines whether `path` is used as a type anywhere within this type,
+    /// for example to detect a field of a recursive type definition.
+    ///
+    /// A type path matches if it consists of the same segment names as `path`,
+    /// including a leading `::`. Generic arguments are not compared, so the
+    /// path `List` is found in `Option<Box<List<T>>>`. Trait bounds and the
+    /// trait of a qualified path like `<T as List>::Item` are not themselves
+    /// considered types, but their generic arguments are searched.
+    /// Expressions, such as the length of an array, and macros are not
+    /// searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let ty: Type = parse_quote!(Option<Box<Node<T>>>);
+    /// assert!(ty.references_path(&parse_quote!(Node)));
+    /// assert!(!ty.references_path(&parse_quote!(Tree)));
+    /// ```
+    pub fn references_path(&self, path: &Path) -> bool {
+        references::ty(self, &references::Target::Path(path))
+    }
+
+    /// Determines whether this type mentions the type or const parameter
+    /// named `ident`, for example to infer which parameters of a derive input
+    /// need a trait bound because a field uses them.
+    ///
+    /// A type path matches if its first segment is `ident`, so both `T` and
+    /// the associated type `T::Item` mention `T`. References, slices, arrays,
+    /// tuples, generic arguments, trait bounds, qualified self types and
+    /// function pointer types are searched. Expressions, such as the length
+    /// of an array, and macros are not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Ident, Type};
+    ///
+    /// let ty: Type = parse_quote!(&'a [Option<T::Item>]);
+    /// let t: Ident = parse_quote!(T);
+    /// let u: Ident = parse_quote!(U);
+    /// assert!(ty.references_ident(&t));
+    /// assert!(!ty.references_ident(&u));
+    /// ```
+    pub fn references_ident(&self, ident: &Ident) -> bool {
+        references::ty(self, &references::Target::Ident(ident))
+    }
+
+    /// Determines whether this type mentions the lifetime `lifetime`, for
+    /// example to decide whether a field borrows from a lifetime parameter.
+    ///
+    /// The search covers the same positions as
+    /// [`references_ident`][Type::references_ident]. A lifetime introduced by
+    /// a `for<...>` binder, as in `for<'a> fn(&'a str)`, shadows any lifetime
+    /// of the same name outside of it, so is not a mention.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Lifetime, Type};
+    ///
+    /// let a: Lifetime = parse_quote!('a);
+    ///
+    /// let ty: Type = parse_quote!(Box<dyn Fn(&str) -> Cow<'a, str> + 'static>);
+    /// assert!(ty.references_lifetime(&a));
+    ///
+    /// let ty: Type = parse_quote!(for<'a> fn(&'a str));
+    /// assert!(!ty.references_lifetime(&a));
+    /// ```
+    pub fn references_lifetime(&self, lifetime: &Lifetime) -> bool {
+        references::ty(self, &references::Target::Lifetime(lifetime))
+    }
+
+    /// Compares two types structurally, with the significance of lifetimes,
+    /// const arguments and standard library paths controlled by `opts`.
+    ///
+    /// Parentheses and invisible groups around a type are never significant,
+    /// nor are the names of bare function arguments or the presence of the
+    /// `dyn` keyword. Generic arguments are compared in order. Expressions
+    /// and macros within the types are compared by their tokens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type, TypeEqOptions};
+    ///
+    /// let a: Type = parse_quote!(std::borrow::Cow<'a, str>);
+    /// let b: Type = parse_quote!(Cow<'static, str>);
+    /// assert!(!a.eq_with(&b, TypeEqOptions::default()));
+    ///
+    /// let opts = TypeEqOptions::default()
+    ///     .lifetimes(false)
+    ///     .normalize_std_paths(true);
+    /// assert!(a.eq_with(&b, opts));
+    /// ```
+    #[cfg(feature = "printing")]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    )]
+    pub fn eq_with(&self, other: &Type, opts: TypeEqOptions) -> bool {
+        equality::types(self, other, &opts)
+    }
+
+    /// Renders this type as a canonical string, suitable as the key of a map
+    /// from types to data derived from them.
+    ///
+    /// The key depends only on the tokens of the type, not on their spans,
+    /// on the whitespace the type was written with, or on invisible groups
+    /// introduced by macro expansion. Two types that print the same tokens
+    /// have the same key. No other normalization is done, so `Vec<T>` and
+    /// `std::vec::Vec<T>` have different keys; use [`Type::eq_with`] for a
+    /// more lenient comparison.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let a: Type = syn::parse_str("HashMap<&'a str,Vec<u8>>").unwrap();
+    /// let b: Type = parse_quote!(HashMap<&'a str, Vec<u8>>);
+    /// assert_eq!(a.cache_key(), b.cache_key());
+    /// assert_eq!(a.cache_key(), "HashMap < & 'a str , Vec < u8 > >");
+    /// ```
+    #[cfg(feature = "printing")]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    )]
+    pub fn cache_key(&self) -> String {
+        let mut key = String::new();
+        push_cache_key(self.to_token_stream(), &mut key, &mut false);
+        key
+    }
+
+    /// Prints this type in the turbofish form used in expression position,
+    /// for example `Vec::<u8>` for the type `Vec<u8>`.
+    ///
+    /// Path types are printed as by [`TypePath::to_turbofish`]. Any other
+    /// type is printed unchanged.
+    #[cfg(feature = "printing")]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    )]
+    pub fn to_turbofish(&self) -> TokenStream {
+        match self {
+            Type::Path(ty) => ty.to_turbofish(),
+            _ => self.to_token_stream(),
+        }
+    }
+}
+
+/// Options for comparing types with [`Type::eq_with`].
+///
+/// The default options make every lifetime and const argument significant and
+/// do not normalize paths.
+///
+/// This struct is non-exhaustive so that more ways of comparing types can be
+/// added without a breaking change. Start from [`TypeEqOptions::default()`]
+/// and change options with the setter methods.
+#[cfg(feature = "printing")]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+)]
+#[non_exhaustive]
+pub struct TypeEqOptions {
+    /// Whether lifetimes must match, including lifetime arguments like the
+    /// `'a` in `Cow<'a, str>`, the lifetimes of references, lifetime bounds,
+    /// and `for<'a>` binders.
+    pub lifetimes: bool,
+
+    /// Whether const generic arguments like the `{ N + 1 }` in
+    /// `Foo<{ N + 1 }>`, associated const equalities, and array lengths must
+    /// match.
+    ///
+    /// Note that a const argument written as a plain path, such as the `N` in
+    /// `Foo<N>`, is indistinguishable from a type argument and is always
+    /// compared.
+    pub const_args: bool,
+
+    /// Whether a path beginning with `std`, `core` or `alloc` compares equal
+    /// to the same path reduced to its last segment, so that
+    /// `::std::vec::Vec<T>` is equal to `Vec<T>`. The leading `::` of other
+    /// paths is still significant.
+    pub normalize_std_paths: bool,
+}
+
+#[cfg(feature = "printing")]
+impl Default for TypeEqOptions {
+    fn default() -> Self {
+        TypeEqOptions {
+            lifetimes: true,
+            const_args: true,
+            normalize_std_paths: false,
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+impl TypeEqOptions {
+    /// Sets whether lifetimes must match, as for the
+    /// [`lifetimes`][TypeEqOptions#structfield.lifetimes] field.
+    pub fn lifetimes(mut self, lifetimes: bool) -> Self {
+        self.lifetimes = lifetimes;
+        self
+    }
+
+    /// Sets whether const generic arguments must match, as for the
+    /// [`const_args`][TypeEqOptions#structfield.const_args] field.
+    pub fn const_args(mut self, const_args: bool) -> Self {
+        self.const_args = const_args;
+        self
+    }
+
+    /// Sets whether standard library paths are normalized, as for the
+    /// [`normalize_std_paths`][TypeEqOptions#structfield.normalize_std_paths]
+    /// field.
+    pub fn normalize_std_paths(mut self, normalize_std_paths: bool) -> Self {
+        self.normalize_std_paths = normalize_std_paths;
+        self
+    }
+}
+
+#[cfg(feature = "printing")]
+impl Copy for TypeEqOptions {}
+
+#[cfg(feature = "printing")]
+impl Clone for TypeEqOptions {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// The layers of references around a type, as returned by
+/// [`Type::reference_summary`].
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub struct RefSummary {
+    /// The number of references, like 2 for `&&T`.
+    pub depth: usize,
+
+    /// Whether the outermost reference is `&mut`. False if there are no
+    /// references.
+    pub outer_mut: bool,
+
+    /// Whether any of the references is `&mut`.
+    pub any_mut: bool,
+}
+
+impl Copy for RefSummary {}
+
+impl Clone for RefSummary {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for RefSummary {}
+
+#[cfg(feature = "extra-traits")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for RefSummary {
+    fn eq(&self, other: &Self) -> bool {
+        self.depth == other.depth
+            && self.outer_mut == other.outer_mut
+            && self.any_mut == other.any_mut
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+mod debug_impls {
+    use super::*;
+    use std::fmt::{self, Debug};
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+    impl Debug for RefSummary {
+        fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter
+                .debug_struct("RefSummary")
+                .field("depth", &self.depth)
+                .field("outer_mut", &self.outer_mut)
+                .field("any_mut", &self.any_mut)
+                .finish()
+        }
+    }
+}
+
 ast_struct! {
     /// A fixed size array type: `[T; n]`.
     #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
@@ -106,6 +603,53 @@ ast_struct! {
     }
 }
 
+impl TypeBareFn {
+    /// Sets the ABI of this function pointer type to `extern "abi"`, or
+    /// removes the ABI if `abi` is `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, TypeBareFn};
+    ///
+    /// let ty: TypeBareFn = parse_quote!(fn(u8) -> u8);
+    /// let ty = ty.with_unsafe(true).with_abi(Some("C"));
+    ///
+    /// let expected: TypeBareFn = parse_quote!(unsafe extern "C" fn(u8) -> u8);
+    /// assert_eq!(ty, expected);
+    /// ```
+    pub fn with_abi(mut self, abi: Option<&str>) -> TypeBareFn {
+        self.abi = abi.map(|abi| {
+            let span = self.fn_token.span;
+            let (extern_token, span) = match self.abi {
+                Some(Abi {
+                    extern_token,
+                    name: Some(name),
+                }) => (extern_token, name.span()),
+                Some(Abi { extern_token, .. }) => (extern_token, span),
+                None => (Token![extern](span), span),
+            };
+            Abi {
+                extern_token,
+                name: Some(LitStr::new(abi, span)),
+            }
+        });
+        self
+    }
+
+    /// Marks this function pointer type as `unsafe` if `yes` is true, or
+    /// as safe otherwise.
+    pub fn with_unsafe(mut self, yes: bool) -> TypeBareFn {
+        self.unsafety = if yes {
+            let span = self.fn_token.span;
+            Some(self.unsafety.unwrap_or(Token![unsafe](span)))
+        } else {
+            None
+        };
+        self
+    }
+}
+
 ast_struct! {
     /// A type contained within invisible delimiters.
     #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
@@ -168,6 +712,81 @@ ast_struct! {
     }
 }
 
+impl TypePath {
+    /// Splits a qualified path into its self type, trait and projection.
+    ///
+    /// For `<Vec<T> as IntoIterator>::Item` this returns the self type
+    /// `Vec<T>`, the segments `IntoIterator` of the trait, and the remaining
+    /// segments `Item`. For a qualified path without a trait, such as
+    /// `<Vec<T>>::new`, the trait is `None` and every segment of the path is
+    /// part of the projection.
+    ///
+    /// The segments are yielded from `self.path.segments`, whose leading `::`
+    /// (if any) belongs to the trait path. Returns `None` if this path has no
+    /// `qself`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, TypePath};
+    ///
+    /// let ty: TypePath = parse_quote!(<Vec<T> as IntoIterator>::Item);
+    /// let (_self_ty, trait_, mut projection) = ty.qself_split().unwrap();
+    ///
+    /// let trait_: Vec<_> = trait_.unwrap().map(|segment| &segment.ident).collect();
+    /// assert_eq!(trait_, ["IntoIterator"]);
+    /// assert!(projection.next().unwrap().ident == "Item");
+    /// assert!(projection.next().is_none());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn qself_split(
+        &self,
+    ) -> Option<(
+        &Type,
+        Option<iter::Take<punctuated::Iter<'_, PathSegment>>>,
+        iter::Skip<punctuated::Iter<'_, PathSegment>>,
+    )> {
+        let qself = self.qself.as_ref()?;
+        let segments = self.path.segments.iter();
+        let trait_ = if qself.position == 0 {
+            None
+        } else {
+            Some(segments.clone().take(qself.position))
+        };
+        let projection = segments.skip(qself.position);
+        Some((&qself.ty, trait_, projection))
+    }
+
+    /// Prints this path in the turbofish form used in expression position,
+    /// for example `Vec::<u8>` for the type `Vec<u8>`.
+    ///
+    /// Every angle bracketed argument list in the path gets a leading `::`
+    /// unless it already has one. A path without angle bracketed arguments
+    /// is printed unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, TypePath};
+    ///
+    /// let ty: TypePath = parse_quote!(std::vec::Vec<u8>);
+    /// let ctor = ty.to_turbofish();
+    /// let expr = quote!(#ctor::new());
+    /// assert_eq!(expr.to_string(), "std :: vec :: Vec :: < u8 > :: new ()");
+    /// ```
+    #[cfg(feature = "printing")]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "printing")))
+    )]
+    pub fn to_turbofish(&self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        path::printing::print_path_turbofish(&mut tokens, &self.qself, &self.path);
+        tokens
+    }
+}
+
 ast_struct! {
     /// A raw pointer type: `*const T` or `*mut T`.
     #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
@@ -209,6 +828,66 @@ ast_struct! {
     }
 }
 
+impl TypeTraitObject {
+    /// Separates the bounds of the trait object into the principal trait,
+    /// the auto traits and the lifetimes, as in `dyn Trait + Send + 'static`.
+    ///
+    /// Auto traits are recognized by name, as a path ending in `Send`, `Sync`,
+    /// `Unpin`, `UnwindSafe` or `RefUnwindSafe` without generic arguments. The
+    /// principal trait is the first trait bound that is not one of these. Any
+    /// further trait bound, such as a second non-auto trait which the compiler
+    /// would reject or a `?Sized` bound, is returned along with the auto
+    /// traits. Verbatim bounds are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, TypeTraitObject};
+    ///
+    /// let ty: TypeTraitObject = parse_quote!(dyn Send + Fn() -> u8 + Sync + 'static);
+    /// let (principal, auto_traits, lifetimes) = ty.split_bounds();
+    ///
+    /// let principal = principal.unwrap();
+    /// assert!(principal.path.segments[0].ident == "Fn");
+    /// assert_eq!(auto_traits.len(), 2);
+    /// assert_eq!(lifetimes[0].ident, "static");
+    /// ```
+    pub fn split_bounds(&self) -> (Option<&TraitBound>, Vec<&TraitBound>, Vec<&Lifetime>) {
+        let mut principal = None;
+        let mut auto_traits = Vec::new();
+        let mut lifetimes = Vec::new();
+        for bound in &self.bounds {
+            match bound {
+                TypeParamBound::Trait(bound) => {
+                    if principal.is_none() && is_principal_candidate(bound) {
+                        principal = Some(bound);
+                    } else {
+                        auto_traits.push(bound);
+                    }
+                }
+                TypeParamBound::Lifetime(lifetime) => lifetimes.push(lifetime),
+                TypeParamBound::Verbatim(_) => {}
+            }
+        }
+        (principal, auto_traits, lifetimes)
+    }
+}
+
+fn is_principal_candidate(bound: &TraitBound) -> bool {
+    if let TraitBoundModifier::Maybe(_) = bound.modifier {
+        return false;
+    }
+    match bound.path.segments.last() {
+        Some(segment) => {
+            !segment.arguments.is_none()
+                || !["Send", "Sync", "Unpin", "UnwindSafe", "RefUnwindSafe"]
+                    .iter()
+                    .any(|name| segment.ident == name)
+        }
+        None => false,
+    }
+}
+
 ast_struct! {
     /// A tuple type: `(A, B, C, String)`.
     #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
@@ -227,6 +906,33 @@ ast_struct! {
     }
 }
 
+impl Abi {
+    /// The value of the ABI string, such as `"C"` or `"system"`, or `None`
+    /// for an `extern` without one.
+    ///
+    /// An `extern` without a string declares the `"C"` ABI, but this method
+    /// does not fill it in, so that callers can tell the two spellings apart.
+    /// Use [`Signature::abi_name`] for the ABI a function actually has.
+    ///
+    /// The value is returned as a `String` because escapes in the string
+    /// literal are resolved, as by [`LitStr::value`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Abi};
+    ///
+    /// let abi: Abi = parse_quote!(extern "system");
+    /// assert_eq!(abi.name().as_deref(), Some("system"));
+    ///
+    /// let abi: Abi = parse_quote!(extern);
+    /// assert_eq!(abi.name(), None);
+    /// ```
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(LitStr::value)
+    }
+}
+
 ast_struct! {
     /// An argument in a function type: the `usize` in `fn(usize) -> bool`.
     #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
@@ -261,6 +967,530 @@ ast_enum! {
     }
 }
 
+impl ReturnType {
+    /// Returns the type this return type stands for, treating an unspecified
+    /// return type as the unit type `()`.
+    ///
+    /// This is the effective return type of a function or function pointer.
+    /// For closures an unspecified return type is inferred instead, so the
+    /// unit type is not meaningful there.
+    ///
+    /// The synthesized `()` has parentheses spanned at `Span::call_site()`,
+    /// as there are no tokens in the source that it could point to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ItemFn, Type};
+    ///
+    /// let item: ItemFn = parse_quote! {
+    ///     fn f() {}
+    /// };
+    /// let unit: Type = parse_quote!(());
+    /// assert_eq!(*item.sig.output.as_type(), unit);
+    /// ```
+    #[cfg(feature = "clone-impls")]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(all(any(feature = "full", feature = "derive"), feature = "clone-impls")))
+    )]
+    pub fn as_type(&self) -> Cow<'_, Type> {
+        match self {
+            ReturnType::Default => Cow::Owned(Type::Tuple(TypeTuple {
+                paren_token: token::Paren::default(),
+                elems: Punctuated::new(),
+            })),
+            ReturnType::Type(_, ty) => Cow::Borrowed(ty),
+        }
+    }
+}
+
+/// Replaces every inference placeholder `_` within a type with a copy of
+/// `concrete`.
+///
+/// All nested types are visited, including generic arguments, the elements
+/// of tuples and arrays, and the types within expressions such as an array
+/// length. Placeholders inside macro invocations are not replaced. The
+/// replacement itself is not searched for further placeholders.
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{parse_quote, Type};
+///
+/// let mut ty: Type = parse_quote!(HashMap<_, Vec<_>>);
+/// syn::ty::replace_infer(&mut ty, &parse_quote!(String));
+/// assert_eq!(
+///     quote!(#ty).to_string(),
+///     quote!(HashMap<String, Vec<String> >).to_string(),
+/// );
+/// ```
+#[cfg(all(feature = "visit-mut", feature = "clone-impls"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(
+        any(feature = "full", feature = "derive"),
+        feature = "visit-mut",
+        feature = "clone-impls"
+    )))
+)]
+pub fn replace_infer(node: &mut Type, concrete: &Type) {
+    use crate::visit_mut::{self, VisitMut};
+
+    struct ReplaceInfer<'a>(&'a Type);
+
+    impl VisitMut for ReplaceInfer<'_> {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            if let Type::Infer(_) = ty {
+                *ty = self.0.clone();
+            } else {
+                visit_mut::visit_type_mut(self, ty);
+            }
+        }
+    }
+
+    ReplaceInfer(concrete).visit_type_mut(node);
+}
+
+pub(crate) mod references {
+    use super::*;
+
+    pub(crate) enum Target<'a> {
+        Path(&'a Path),
+        Ident(&'a Ident),
+        Lifetime(&'a Lifetime),
+        // A const parameter, which matches like an identifier and is also
+        // searched for in array lengths and const generic arguments.
+        Const(&'a Ident),
+    }
+
+    pub(crate) fn ty(ty: &Type, target: &Target) -> bool {
+        match ty {
+            Type::Array(ty) => self::ty(&ty.elem, target) || expr(&ty.len, target),
+            Type::BareFn(ty) => {
+                !binds(&ty.lifetimes, target)
+                    && (ty.inputs.iter().any(|arg| self::ty(&arg.ty, target))
+                        || return_type(&ty.output, target))
+            }
+            Type::Group(ty) => self::ty(&ty.elem, target),
+            Type::ImplTrait(ty) => bounds(&ty.bounds, target),
+            Type::Paren(ty) => self::ty(&ty.elem, target),
+            Type::Path(ty) => {
+                if let Some(qself) = &ty.qself {
+                    if self::ty(&qself.ty, target) {
+                        return true;
+                    }
+                } else if same_path(&ty.path, target) {
+                    return true;
+                }
+                path_arguments(&ty.path, target)
+            }
+            Type::Ptr(ty) => self::ty(&ty.elem, target),
+            Type::Reference(ty) => {
+                ty.lifetime
+                    .as_ref()
+                    .map_or(false, |lifetime| same_lifetime(lifetime, target))
+                    || self::ty(&ty.elem, target)
+            }
+            Type::Slice(ty) => self::ty(&ty.elem, target),
+            Type::TraitObject(ty) => bounds(&ty.bounds, target),
+            Type::Tuple(ty) => ty.elems.iter().any(|elem| self::ty(elem, target)),
+            Type::Infer(_) | Type::Macro(_) | Type::Never(_) | Type::Verbatim(_) => false,
+        }
+    }
+
+    // A path given as the target matches by all of its segment names, while
+    // an identifier matches a path starting with it, such as `T::Item`.
+    fn same_path(path: &Path, target: &Target) -> bool {
+        match target {
+            Target::Path(target) => {
+                path.leading_colon.is_some() == target.leading_colon.is_some()
+                    && path.segments.len() == target.segments.len()
+                    && path
+                        .segments
+                        .iter()
+                        .zip(&target.segments)
+                        .all(|(a, b)| a.ident == b.ident)
+            }
+            Target::Ident(target) | Target::Const(target) => {
+                path.leading_colon.is_none()
+                    && path
+                        .segments
+                        .first()
+                        .map_or(false, |segment| segment.ident == **target)
+            }
+            Target::Lifetime(_) => false,
+        }
+    }
+
+    fn same_lifetime(lifetime: &Lifetime, target: &Target) -> bool {
+        match target {
+            Target::Lifetime(target) => lifetime == *target,
+            Target::Path(_) | Target::Ident(_) | Target::Const(_) => false,
+        }
+    }
+
+    // Whether a `for<...>` binder introduces a lifetime that shadows the
+    // target.
+    fn binds(lifetimes: &Option<BoundLifetimes>, target: &Target) -> bool {
+        lifetimes.as_ref().map_or(false, |lifetimes| {
+            lifetimes.lifetimes.iter().any(|param| match param {
+                GenericParam::Lifetime(param) => same_lifetime(&param.lifetime, target),
+                GenericParam::Type(_) | GenericParam::Const(_) => false,
+            })
+        })
+    }
+
+    fn return_type(output: &ReturnType, target: &Target) -> bool {
+        match output {
+            ReturnType::Default => false,
+            ReturnType::Type(_, ty) => self::ty(ty, target),
+        }
+    }
+
+    fn path_arguments(path: &Path, target: &Target) -> bool {
+        path.segments
+            .iter()
+            .any(|segment| match &segment.arguments {
+                PathArguments::None => false,
+                PathArguments::AngleBracketed(arguments) => generic_arguments(arguments, target),
+                PathArguments::Parenthesized(arguments) => {
+                    arguments.inputs.iter().any(|ty| self::ty(ty, target))
+                        || return_type(&arguments.output, target)
+                }
+            })
+    }
+
+    fn generic_arguments(arguments: &AngleBracketedGenericArguments, target: &Target) -> bool {
+        arguments.args.iter().any(|arg| match arg {
+            GenericArgument::Lifetime(lifetime) => same_lifetime(lifetime, target),
+            GenericArgument::Type(ty) => self::ty(ty, target),
+            GenericArgument::AssocType(assoc) => {
+                assoc
+                    .generics
+                    .as_ref()
+                    .map_or(false, |generics| generic_arguments(generics, target))
+                    || self::ty(&assoc.ty, target)
+            }
+            GenericArgument::Constraint(constraint) => {
+                constraint
+                    .generics
+                    .as_ref()
+                    .map_or(false, |generics| generic_arguments(generics, target))
+                    || bounds(&constraint.bounds, target)
+            }
+            GenericArgument::Const(arg) => expr(arg, target),
+            GenericArgument::AssocConst(assoc) => {
+                assoc
+                    .generics
+                    .as_ref()
+                    .map_or(false, |generics| generic_arguments(generics, target))
+                    || expr(&assoc.value, target)
+            }
+        })
+    }
+
+    // Only paths, literals, operators, casts, parentheses and blocks made of
+    // a single expression are searched, which covers the usual array lengths
+    // and const generic arguments like `N` and `{ N + 1 }`.
+    fn expr(expr: &Expr, target: &Target) -> bool {
+        if let Target::Const(_) = target {
+            match expr {
+                Expr::Path(expr) => expr.qself.is_none() && same_path(&expr.path, target),
+                Expr::Binary(expr) => {
+                    self::expr(&expr.left, target) || self::expr(&expr.right, target)
+                }
+                Expr::Unary(expr) => self::expr(&expr.expr, target),
+                Expr::Cast(expr) => self::expr(&expr.expr, target),
+                Expr::Paren(expr) => self::expr(&expr.expr, target),
+                #[cfg(feature = "full")]
+                Expr::Block(expr) => match expr.block.stmts.as_slice() {
+                    [Stmt::Expr(expr, None)] => self::expr(expr, target),
+                    _ => false,
+                },
+                _ => false,
+            }
+        } else {
+            false
+        }
+    }
+
+    fn bounds(bounds: &Punctuated<TypeParamBound, Token![+]>, target: &Target) -> bool {
+        bounds.iter().any(|bound| match bound {
+            TypeParamBound::Trait(bound) => {
+                !binds(&bound.lifetimes, target) && path_arguments(&bound.path, target)
+            }
+            TypeParamBound::Lifetime(lifetime) => same_lifetime(lifetime, target),
+            TypeParamBound::Verbatim(_) => false,
+        })
+    }
+}
+
+// Tokens are separated by a single space, except after a joint punctuation
+// character, after an opening delimiter and before a closing delimiter.
+#[cfg(feature = "printing")]
+fn push_cache_key(tokens: TokenStream, key: &mut String, space: &mut bool) {
+    for token in tokens {
+        let delimiters = match &token {
+            TokenTree::Group(group) => match group.delimiter() {
+                Delimiter::Parenthesis => Some(('(', ')')),
+                Delimiter::Brace => Some(('{', '}')),
+                Delimiter::Bracket => Some(('[', ']')),
+                Delimiter::None => {
+                    push_cache_key(group.stream(), key, space);
+                    continue;
+                }
+            },
+            _ => None,
+        };
+        if *space {
+            key.push(' ');
+        }
+        *space = true;
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = delimiters.unwrap();
+                key.push(open);
+                push_cache_key(group.stream(), key, &mut false);
+                key.push(close);
+            }
+            TokenTree::Punct(punct) => {
+                key.push(punct.as_char());
+                *space = punct.spacing() == Spacing::Alone;
+            }
+            TokenTree::Ident(ident) => key.push_str(&ident.to_string()),
+            TokenTree::Literal(literal) => key.push_str(&literal.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+mod equality {
+    use super::*;
+    use quote::ToTokens;
+
+    pub(super) fn types(a: &Type, b: &Type, opts: &TypeEqOptions) -> bool {
+        match (ungroup(a), ungroup(b)) {
+            (Type::Array(a), Type::Array(b)) => {
+                types(&a.elem, &b.elem, opts) && (!opts.const_args || tokens(&a.len, &b.len))
+            }
+            (Type::BareFn(a), Type::BareFn(b)) => {
+                (!opts.lifetimes || tokens(&a.lifetimes, &b.lifetimes))
+                    && a.unsafety.is_some() == b.unsafety.is_some()
+                    && abi(&a.abi) == abi(&b.abi)
+                    && a.inputs.len() == b.inputs.len()
+                    && a.inputs
+                        .iter()
+                        .zip(&b.inputs)
+                        .all(|(a, b)| types(&a.ty, &b.ty, opts))
+                    && a.variadic.is_some() == b.variadic.is_some()
+                    && return_types(&a.output, &b.output, opts)
+            }
+            (Type::ImplTrait(a), Type::ImplTrait(b)) => bounds(&a.bounds, &b.bounds, opts),
+            (Type::Infer(_), Type::Infer(_)) => true,
+            (Type::Macro(a), Type::Macro(b)) => tokens(&a.mac, &b.mac),
+            (Type::Never(_), Type::Never(_)) => true,
+            (Type::Path(a), Type::Path(b)) => {
+                match (&a.qself, &b.qself) {
+                    (None, None) => {}
+                    (Some(a), Some(b)) => {
+                        if a.position != b.position || !types(&a.ty, &b.ty, opts) {
+                            return false;
+                        }
+                    }
+                    _ => return false,
+                }
+                paths(&a.path, &b.path, a.qself.is_none(), opts)
+            }
+            (Type::Ptr(a), Type::Ptr(b)) => {
+                a.mutability.is_some() == b.mutability.is_some() && types(&a.elem, &b.elem, opts)
+            }
+            (Type::Reference(a), Type::Reference(b)) => {
+                (!opts.lifetimes || a.lifetime == b.lifetime)
+                    && a.mutability.is_some() == b.mutability.is_some()
+                    && types(&a.elem, &b.elem, opts)
+            }
+            (Type::Slice(a), Type::Slice(b)) => types(&a.elem, &b.elem, opts),
+            (Type::TraitObject(a), Type::TraitObject(b)) => bounds(&a.bounds, &b.bounds, opts),
+            (Type::Tuple(a), Type::Tuple(b)) => {
+                a.elems.len() == b.elems.len()
+                    && a.elems.iter().zip(&b.elems).all(|(a, b)| types(a, b, opts))
+            }
+            (Type::Verbatim(a), Type::Verbatim(b)) => a.to_string() == b.to_string(),
+            _ => false,
+        }
+    }
+
+    fn ungroup(mut ty: &Type) -> &Type {
+        loop {
+            match ty {
+                Type::Group(group) => ty = &group.elem,
+                Type::Paren(paren) => ty = &paren.elem,
+                _ => return ty,
+            }
+        }
+    }
+
+    fn tokens<T: ToTokens>(a: &T, b: &T) -> bool {
+        a.to_token_stream().to_string() == b.to_token_stream().to_string()
+    }
+
+    fn abi(abi: &Option<Abi>) -> String {
+        match abi {
+            None => "Rust".to_owned(),
+            Some(Abi { name: None, .. }) => "C".to_owned(),
+            Some(Abi {
+                name: Some(name), ..
+            }) => name.value(),
+        }
+    }
+
+    fn return_types(a: &ReturnType, b: &ReturnType, opts: &TypeEqOptions) -> bool {
+        match (a, b) {
+            (ReturnType::Default, ReturnType::Default) => true,
+            (ReturnType::Type(_, a), ReturnType::Type(_, b)) => types(a, b, opts),
+            _ => false,
+        }
+    }
+
+    fn paths(a: &Path, b: &Path, allow_normalize: bool, opts: &TypeEqOptions) -> bool {
+        let normalize = allow_normalize && opts.normalize_std_paths;
+        let (a_leading_colon, a) = segments(a, normalize);
+        let (b_leading_colon, b) = segments(b, normalize);
+        a_leading_colon == b_leading_colon
+            && a.len() == b.len()
+            && a.iter().zip(b).all(|(a, b)| {
+                a.ident == b.ident && path_arguments(&a.arguments, &b.arguments, opts)
+            })
+    }
+
+    // Returns whether the path has a leading `::` along with its segments. A
+    // normalized path into std, core or alloc is reduced to its last segment
+    // and loses its leading `::`, so `::std::vec::Vec` is the same as `Vec`.
+    fn segments(path: &Path, normalize: bool) -> (bool, Vec<&PathSegment>) {
+        let mut segments: Vec<&PathSegment> = path.segments.iter().collect();
+        if normalize && segments.len() > 1 {
+            let first = &segments[0].ident;
+            if first == "std" || first == "core" || first == "alloc" {
+                segments.drain(..segments.len() - 1);
+                return (false, segments);
+            }
+        }
+        (path.leading_colon.is_some(), segments)
+    }
+
+    fn path_arguments(a: &PathArguments, b: &PathArguments, opts: &TypeEqOptions) -> bool {
+        match (a, b) {
+            (PathArguments::Parenthesized(a), PathArguments::Parenthesized(b)) => {
+                a.inputs.len() == b.inputs.len()
+                    && a.inputs
+                        .iter()
+                        .zip(&b.inputs)
+                        .all(|(a, b)| types(a, b, opts))
+                    && return_types(&a.output, &b.output, opts)
+            }
+            (PathArguments::Parenthesized(_), _) | (_, PathArguments::Parenthesized(_)) => false,
+            _ => angle_bracketed(angle_bracketed_of(a), angle_bracketed_of(b), opts),
+        }
+    }
+
+    fn angle_bracketed_of(arguments: &PathArguments) -> Option<&AngleBracketedGenericArguments> {
+        match arguments {
+            PathArguments::AngleBracketed(bracketed) => Some(bracketed),
+            PathArguments::None | PathArguments::Parenthesized(_) => None,
+        }
+    }
+
+    // Missing angle brackets are the same as empty ones, like for `Vec` and
+    // `Vec<>`.
+    fn angle_bracketed(
+        a: Option<&AngleBracketedGenericArguments>,
+        b: Option<&AngleBracketedGenericArguments>,
+        opts: &TypeEqOptions,
+    ) -> bool {
+        let a = generic_arguments(a, opts);
+        let b = generic_arguments(b, opts);
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| generic_argument(a, b, opts))
+    }
+
+    fn generic_arguments<'a>(
+        arguments: Option<&'a AngleBracketedGenericArguments>,
+        opts: &TypeEqOptions,
+    ) -> Vec<&'a GenericArgument> {
+        arguments.map_or_else(Vec::new, |bracketed| {
+            bracketed
+                .args
+                .iter()
+                .filter(|arg| match arg {
+                    GenericArgument::Lifetime(_) => opts.lifetimes,
+                    GenericArgument::Const(_) | GenericArgument::AssocConst(_) => opts.const_args,
+                    _ => true,
+                })
+                .collect()
+        })
+    }
+
+    fn generic_argument(a: &GenericArgument, b: &GenericArgument, opts: &TypeEqOptions) -> bool {
+        match (a, b) {
+            (GenericArgument::Lifetime(a), GenericArgument::Lifetime(b)) => a == b,
+            (GenericArgument::Type(a), GenericArgument::Type(b)) => types(a, b, opts),
+            (GenericArgument::Const(a), GenericArgument::Const(b)) => tokens(a, b),
+            (GenericArgument::AssocType(a), GenericArgument::AssocType(b)) => {
+                a.ident == b.ident
+                    && angle_bracketed(a.generics.as_ref(), b.generics.as_ref(), opts)
+                    && types(&a.ty, &b.ty, opts)
+            }
+            (GenericArgument::AssocConst(a), GenericArgument::AssocConst(b)) => {
+                a.ident == b.ident
+                    && angle_bracketed(a.generics.as_ref(), b.generics.as_ref(), opts)
+                    && tokens(&a.value, &b.value)
+            }
+            (GenericArgument::Constraint(a), GenericArgument::Constraint(b)) => {
+                a.ident == b.ident
+                    && angle_bracketed(a.generics.as_ref(), b.generics.as_ref(), opts)
+                    && bounds(&a.bounds, &b.bounds, opts)
+            }
+            _ => false,
+        }
+    }
+
+    fn bounds(
+        a: &Punctuated<TypeParamBound, Token![+]>,
+        b: &Punctuated<TypeParamBound, Token![+]>,
+        opts: &TypeEqOptions,
+    ) -> bool {
+        let significant = |bound: &&TypeParamBound| match bound {
+            TypeParamBound::Lifetime(_) => opts.lifetimes,
+            _ => true,
+        };
+        let a: Vec<&TypeParamBound> = a.iter().filter(significant).collect();
+        let b: Vec<&TypeParamBound> = b.iter().filter(significant).collect();
+        a.len() == b.len()
+            && a.iter().zip(b).all(|(a, b)| match (a, b) {
+                (TypeParamBound::Trait(a), TypeParamBound::Trait(b)) => {
+                    let same_modifier = matches!(
+                        (&a.modifier, &b.modifier),
+                        (TraitBoundModifier::None, TraitBoundModifier::None)
+                            | (TraitBoundModifier::Maybe(_), TraitBoundModifier::Maybe(_))
+                            | (
+                                TraitBoundModifier::MaybeConst(..),
+                                TraitBoundModifier::MaybeConst(..)
+                            )
+                    );
+                    same_modifier
+                        && (!opts.lifetimes || tokens(&a.lifetimes, &b.lifetimes))
+                        && paths(&a.path, &b.path, true, opts)
+                }
+                (TypeParamBound::Lifetime(a), TypeParamBound::Lifetime(b)) => a == b,
+                (TypeParamBound::Verbatim(a), TypeParamBound::Verbatim(b)) => {
+                    a.to_string() == b.to_string()
+                }
+                _ => false,
+            })
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
@@ -0,0 +1,520 @@
+//! Best-effort formatting of a syntax tree as readable Rust source code.
+
+use crate::{File, Item, Lit};
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// Formats a syntax tree as Rust source code meant to be read by a person,
+/// such as code generated by a build script.
+///
+/// This is a best-effort formatter working on the tokens of the file. Items
+/// are separated by blank lines, the contents of blocks and other braces are
+/// indented on lines of their own, and statements, fields and match arms are
+/// put on separate lines. Otherwise spacing follows a few simple rules, and
+/// lines are never wrapped, so the output does not generally match rustfmt
+/// and its exact layout may change between releases. Documentation
+/// attributes are printed as `///` comments, while other comments are not
+/// part of the syntax tree and so are lost. Use a dedicated formatter such
+/// as `prettyplease` where the layout matters.
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, File};
+///
+/// let file: File = parse_quote! {
+///     use std::fmt;
+///     pub struct S { a: u8 }
+///     impl fmt::Debug for S {
+///         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str("S") }
+///     }
+/// };
+///
+/// let expected = "\
+/// use std::fmt;
+///
+/// pub struct S {
+///     a: u8
+/// }
+///
+/// impl fmt::Debug for S {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         f.write_str(\"S\")
+///     }
+/// }
+/// ";
+/// assert_eq!(syn::unparse::file(&file), expected);
+/// ```
+pub fn file(file: &File) -> String {
+    let mut printer = Printer::new(false);
+    if let Some(shebang) = &file.shebang {
+        printer.out.push_str(shebang);
+        printer.out.push('\n');
+    }
+    for attr in &file.attrs {
+        printer.stream(attr.to_token_stream(), true);
+        printer.newline();
+    }
+    for (i, item) in file.items.iter().enumerate() {
+        let consecutive_use =
+            i > 0 && matches!((&file.items[i - 1], item), (Item::Use(_), Item::Use(_)));
+        if (i > 0 || !file.attrs.is_empty()) && !consecutive_use {
+            printer.out.push('\n');
+        }
+        printer.last = Last::Open;
+        printer.stream(item.to_token_stream(), true);
+        printer.newline();
+    }
+    printer.out
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "dyn", "else", "enum", "extern",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "type", "union", "unsafe", "use", "where", "while",
+    "yield",
+];
+
+// Keywords after which a brace group is a block or the body of an item,
+// rather than the fields of a struct expression or pattern.
+const BLOCK_KEYWORDS: &[&str] = &[
+    "else", "enum", "fn", "for", "if", "impl", "loop", "match", "mod", "trait", "struct", "union",
+    "unsafe", "while",
+];
+
+const MAX_INLINE: usize = 60;
+
+struct Printer {
+    out: String,
+    indent: usize,
+    line_start: bool,
+    last: Last,
+    // The previous word was `fn`, `struct` and the like, so this word is the
+    // name of an item which may be followed by generic parameters.
+    item_name: bool,
+    // The previous token was the `!` of a macro invocation.
+    macro_bang: bool,
+    // Within the body of a macro, whose tokens are not necessarily Rust
+    // syntax, so the spacing of punctuation is kept as it is.
+    macro_body: bool,
+}
+
+#[derive(Copy, Clone)]
+enum Last {
+    // Start of the input or just after an opening delimiter.
+    Open,
+    Word {
+        keyword: bool,
+        // A following `<` starts generic arguments rather than a comparison.
+        generic: bool,
+        // A following parenthesized or bracketed group is attached to it,
+        // as in a function call or indexing expression.
+        call: bool,
+    },
+    Literal {
+        // An integer, which a following `.` would turn into a float literal.
+        integer: bool,
+    },
+    Punct {
+        ch: char,
+        // The punctuation continues into the next character of an operator.
+        joint: bool,
+        // No space is written after it, as after `.` or a unary operator.
+        tight: bool,
+        // It ends an operand, like `?` or the closing `>` of generics.
+        value: bool,
+    },
+    Close,
+}
+
+impl Last {
+    fn is_tight(self) -> bool {
+        match self {
+            Last::Open => true,
+            Last::Punct { tight, .. } => tight,
+            Last::Word { .. } | Last::Literal { .. } | Last::Close => false,
+        }
+    }
+
+    fn is_value(self) -> bool {
+        match self {
+            Last::Open => false,
+            Last::Word { keyword, .. } => !keyword,
+            Last::Punct { value, .. } => value,
+            Last::Literal { .. } | Last::Close => true,
+        }
+    }
+
+    fn is_path_sep(self) -> bool {
+        match self {
+            Last::Punct { ch, tight, .. } => ch == ':' && tight,
+            _ => false,
+        }
+    }
+}
+
+// State of the token stream within one pair of delimiters.
+#[derive(Default)]
+struct Scope {
+    angle: usize,
+    closure_params: bool,
+    where_clause: bool,
+    expect_block: bool,
+    attr: bool,
+}
+
+// Renders an attribute `#[doc = "..."]` or `#![doc = "..."]` at the start of
+// `tokens`, which follow a `#`, as a `///` or `//!` comment. Returns the
+// comment and the number of tokens it replaces.
+fn doc_comment(tokens: &[TokenTree]) -> Option<(String, usize)> {
+    let (inner, group) = match tokens {
+        [TokenTree::Punct(bang), TokenTree::Group(group), ..] if bang.as_char() == '!' => {
+            (true, group)
+        }
+        [TokenTree::Group(group), ..] => (false, group),
+        _ => return None,
+    };
+    if group.delimiter() != Delimiter::Bracket {
+        return None;
+    }
+    let attr: Vec<TokenTree> = group.stream().into_iter().collect();
+    let value = match attr.as_slice() {
+        [TokenTree::Ident(doc), TokenTree::Punct(eq), TokenTree::Literal(lit)]
+            if doc == "doc" && eq.as_char() == '=' =>
+        {
+            match Lit::new(lit.clone()) {
+                Lit::Str(lit) => lit.value(),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    if value.starts_with('/') || value.contains(|ch| ch == '\n' || ch == '\r') {
+        return None;
+    }
+    let comment = if inner { "//!" } else { "///" };
+    Some((format!("{}{}", comment, value), inner as usize + 1))
+}
+
+// Whether two punctuation characters written next to each other would be
+// read back as a single multi-character operator.
+fn glues(first: char, second: char) -> bool {
+    match (first, second) {
+        ('+' | '-' | '*' | '/' | '%' | '^' | '&' | '|' | '=' | '!' | '<' | '>', '=') => true,
+        ('&', '&') | ('|', '|') | ('<', '<') | ('>', '>') | (':', ':') | ('.', '.') => true,
+        ('-' | '=', '>') | ('<', '-') => true,
+        _ => false,
+    }
+}
+
+impl Printer {
+    fn new(macro_body: bool) -> Self {
+        Printer {
+            out: String::new(),
+            indent: 0,
+            line_start: true,
+            last: Last::Open,
+            item_name: false,
+            macro_bang: false,
+            macro_body,
+        }
+    }
+
+    fn stream(&mut self, tokens: TokenStream, block: bool) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut scope = Scope::default();
+        let mut i = 0;
+        while i < tokens.len() {
+            let token = &tokens[i];
+            let next = tokens.get(i + 1);
+            i += 1;
+            match token {
+                TokenTree::Ident(ident) => {
+                    let word = ident.to_string();
+                    if word == "where" {
+                        scope.where_clause = true;
+                    }
+                    if BLOCK_KEYWORDS.contains(&&*word) {
+                        scope.expect_block = true;
+                    }
+                    self.word(&word, &scope);
+                    scope.attr = false;
+                }
+                TokenTree::Literal(literal) => {
+                    let space = !self.last.is_tight();
+                    // The index of a tuple field, as in `x.0.1`, may be
+                    // followed by `.` since the parser splits such a float.
+                    let index = matches!(self.last, Last::Punct { ch: '.', .. });
+                    let literal = literal.to_string();
+                    self.write(space, &literal);
+                    self.last = Last::Literal {
+                        integer: !index
+                            && literal.starts_with(|ch: char| ch.is_ascii_digit())
+                            && !literal.contains('.'),
+                    };
+                    self.item_name = false;
+                    self.macro_bang = false;
+                    scope.attr = false;
+                }
+                TokenTree::Punct(punct) => {
+                    let ch = punct.as_char();
+                    if ch == '#' && self.line_start {
+                        if let Some((comment, len)) = doc_comment(&tokens[i..]) {
+                            self.write(false, &comment);
+                            self.newline();
+                            i += len;
+                            continue;
+                        }
+                        scope.attr = true;
+                    } else if ch != '!' {
+                        scope.attr = false;
+                    }
+                    let fat_arrow = match self.last {
+                        Last::Punct { ch: '=', joint, .. } => joint && ch == '>',
+                        _ => false,
+                    };
+                    self.punct(punct, next, &mut scope);
+                    if ch == ';' {
+                        scope.where_clause = false;
+                    }
+                    if ch == ',' || ch == ';' || fat_arrow {
+                        scope.expect_block = false;
+                    }
+                    let separates = match ch {
+                        ';' => true,
+                        ',' => scope.angle == 0 && !scope.closure_params && !scope.where_clause,
+                        _ => false,
+                    };
+                    if block && separates && punct.spacing() == Spacing::Alone {
+                        self.newline();
+                    }
+                }
+                TokenTree::Group(group) => {
+                    let expect_block = scope.expect_block;
+                    if group.delimiter() == Delimiter::Brace {
+                        scope.where_clause = false;
+                        scope.expect_block = false;
+                    }
+                    self.group(group, next, block, expect_block);
+                    if scope.attr && group.delimiter() == Delimiter::Bracket {
+                        self.newline();
+                    }
+                    scope.attr = false;
+                }
+            }
+        }
+    }
+
+    fn word(&mut self, word: &str, scope: &Scope) {
+        let keyword = KEYWORDS.contains(&word);
+        let generic = scope.angle > 0
+            || self.item_name
+            || word.starts_with(|ch: char| ch.is_ascii_uppercase())
+            || word == "impl"
+            || word == "for";
+        let call = !keyword || word == "fn" || word == "pub";
+        // The name of a macro defined by `macro_rules! name { ... }` does not
+        // separate the `!` from the macro body.
+        let macro_name = self.macro_bang && matches!(self.last, Last::Punct { .. });
+        let space = !self.last.is_tight();
+        self.write(space, word);
+        self.last = Last::Word {
+            keyword,
+            generic,
+            call,
+        };
+        self.item_name = matches!(word, "fn" | "struct" | "enum" | "union" | "trait" | "type");
+        self.macro_bang = macro_name;
+    }
+
+    fn punct(&mut self, punct: &Punct, next: Option<&TokenTree>, scope: &mut Scope) {
+        let ch = punct.as_char();
+        // A lifetime's apostrophe is joint with the following identifier, and
+        // an operator may be joint with the apostrophe of a lifetime, but
+        // neither continues into a multi-character operator.
+        let joint = punct.spacing() == Spacing::Joint
+            && match next {
+                Some(TokenTree::Punct(next)) => ch != '\'' && next.as_char() != '\'',
+                _ => false,
+            };
+        let (prev, continues) = match self.last {
+            Last::Punct { ch, joint, .. } => (Some(ch), joint),
+            _ => (None, false),
+        };
+        let generic_context = scope.angle > 0
+            || self.last.is_path_sep()
+            || match self.last {
+                Last::Word { generic, .. } => generic,
+                _ => false,
+            };
+        let after_value = self.last.is_value();
+
+        let mut space = !continues && !self.last.is_tight();
+        let mut tight = punct.spacing() == Spacing::Joint;
+        let mut value = false;
+        self.macro_bang = false;
+        match ch {
+            ',' | ';' => space = false,
+            '?' => {
+                space = false;
+                value = true;
+            }
+            '.' => {
+                space = space && !after_value;
+                tight = true;
+            }
+            ':' if joint => space = space && !after_value,
+            ':' if continues && prev == Some(':') => tight = true,
+            ':' => space = false,
+            '<' if !continues && !joint && generic_context => {
+                scope.angle += 1;
+                space = false;
+                tight = true;
+            }
+            '>' if scope.angle > 0 && !(continues && matches!(prev, Some('-' | '='))) => {
+                scope.angle -= 1;
+                space = false;
+                value = true;
+            }
+            '!' if after_value && !joint => {
+                // The `!` of a macro invocation.
+                space = false;
+                tight = matches!(next, Some(TokenTree::Group(_)));
+                self.macro_bang = true;
+            }
+            '&' | '*' | '-' | '!' if !continues && !after_value => tight = true,
+            '|' if !continues && !joint && !after_value => {
+                scope.closure_params = true;
+                tight = true;
+            }
+            '|' if scope.closure_params && !joint => {
+                scope.closure_params = false;
+                space = false;
+            }
+            '#' | '$' | '\'' => tight = true,
+            _ => {}
+        }
+        if let Some(prev) = prev {
+            let nested_generics = prev == '>' && ch == '>' && value;
+            if !continues && (glues(prev, ch) && !nested_generics || self.macro_body && ch != '\'')
+            {
+                space = true;
+            }
+        } else if let Last::Literal { integer } = self.last {
+            // Written as `0.` it would be read back as a float literal, as
+            // would the field access `0.0`. A range like `0..n` is fine.
+            if ch == '.' && (self.macro_body || integer && punct.spacing() == Spacing::Alone) {
+                space = true;
+            }
+        }
+
+        let mut buf = [0; 4];
+        self.write(space, ch.encode_utf8(&mut buf));
+        self.last = Last::Punct {
+            ch,
+            joint,
+            tight,
+            value,
+        };
+        self.item_name = false;
+    }
+
+    fn group(&mut self, group: &Group, next: Option<&TokenTree>, block: bool, expect_block: bool) {
+        let delimiter = group.delimiter();
+        let (open, close) = match delimiter {
+            Delimiter::Parenthesis => ("(", ")"),
+            Delimiter::Bracket => ("[", "]"),
+            Delimiter::Brace => ("{", "}"),
+            Delimiter::None => {
+                self.stream(group.stream(), block);
+                return;
+            }
+        };
+        let stream = group.stream();
+        let path_sep = self.last.is_path_sep();
+        let outer_macro_body = self.macro_body;
+        self.macro_body |= self.macro_bang;
+        self.macro_bang = false;
+        // Short struct expressions and patterns like `S { a, b }` stay on
+        // one line.
+        let inline = match self.last {
+            Last::Word { keyword: false, .. }
+                if delimiter == Delimiter::Brace && !expect_block && !stream.is_empty() =>
+            {
+                self.inline(stream.clone())
+            }
+            _ => None,
+        };
+        let space = match self.last {
+            Last::Open => false,
+            _ if delimiter == Delimiter::Brace => !path_sep,
+            Last::Word { call, .. } => !call,
+            Last::Punct { tight, value, .. } => !tight && !value,
+            Last::Literal { .. } => true,
+            Last::Close => false,
+        };
+        self.write(space, open);
+        self.last = Last::Open;
+        self.item_name = false;
+
+        let is_block = delimiter == Delimiter::Brace && !path_sep && inline.is_none();
+        if let Some(inline) = inline {
+            self.write(true, &inline);
+            self.write(true, close);
+        } else if is_block && !stream.is_empty() {
+            self.indent += 1;
+            self.newline();
+            self.stream(stream, true);
+            self.indent -= 1;
+            self.newline();
+            self.write(false, close);
+        } else {
+            self.stream(stream, false);
+            self.write(false, close);
+        }
+        self.last = Last::Close;
+        self.macro_body = outer_macro_body;
+
+        if is_block && block {
+            let continues = match next {
+                None => true,
+                Some(TokenTree::Punct(punct)) => matches!(punct.as_char(), ',' | ';' | '.' | '?'),
+                Some(TokenTree::Ident(ident)) => ident == "else",
+                Some(TokenTree::Group(_) | TokenTree::Literal(_)) => false,
+            };
+            if !continues {
+                self.newline();
+            }
+        }
+    }
+
+    fn inline(&self, stream: TokenStream) -> Option<String> {
+        let mut printer = Printer::new(self.macro_body);
+        printer.line_start = false;
+        printer.stream(stream, false);
+        if printer.out.len() <= MAX_INLINE && !printer.out.contains('\n') {
+            Some(printer.out)
+        } else {
+            None
+        }
+    }
+
+    fn write(&mut self, space: bool, s: &str) {
+        if self.line_start {
+            for _ in 0..self.indent {
+                self.out.push_str("    ");
+            }
+            self.line_start = false;
+        } else if space {
+            self.out.push(' ');
+        }
+        self.out.push_str(s);
+    }
+
+    fn newline(&mut self) {
+        if !self.line_start {
+            self.out.push('\n');
+            self.line_start = true;
+        }
+    }
+}
@@ -65,6 +65,170 @@ impl Debug for Lite<syn::Arm> {
         formatter.finish()
     }
 }
+impl Debug for Lite<syn::AsmArg> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            syn::AsmArg::Template(_val) => {
+                formatter.write_str("AsmArg::Template")?;
+                formatter.write_str("(")?;
+                Debug::fmt(Lite(_val), formatter)?;
+                formatter.write_str(")")?;
+                Ok(())
+            }
+            syn::AsmArg::Operand(_val) => {
+                formatter.write_str("AsmArg::Operand")?;
+                formatter.write_str("(")?;
+                Debug::fmt(Lite(_val), formatter)?;
+                formatter.write_str(")")?;
+                Ok(())
+            }
+            syn::AsmArg::ClobberAbi(_val) => {
+                formatter.write_str("AsmArg::ClobberAbi")?;
+                formatter.write_str("(")?;
+                Debug::fmt(Lite(_val), formatter)?;
+                formatter.write_str(")")?;
+                Ok(())
+            }
+            syn::AsmArg::Options(_val) => {
+                formatter.write_str("AsmArg::Options")?;
+                formatter.write_str("(")?;
+                Debug::fmt(Lite(_val), formatter)?;
+                formatter.write_str(")")?;
+                Ok(())
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Debug for Lite<syn::AsmClobberAbi> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("AsmClobberAbi");
+        formatter.field("clobber_abi_token", Lite(&self.value.clobber_abi_token));
+        if !self.value.abis.is_empty() {
+            formatter.field("abis", Lite(&self.value.abis));
+        }
+        formatter.finish()
+    }
+}
+impl Debug for Lite<syn::AsmDirection> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            syn::AsmDirection::In => formatter.write_str("AsmDirection::In"),
+            syn::AsmDirection::Out => formatter.write_str("AsmDirection::Out"),
+            syn::AsmDirection::LateOut => formatter.write_str("AsmDirection::LateOut"),
+            syn::AsmDirection::InOut => formatter.write_str("AsmDirection::InOut"),
+            syn::AsmDirection::InLateOut => {
+                formatter.write_str("AsmDirection::InLateOut")
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Debug for Lite<syn::AsmOperand> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("AsmOperand");
+        if let Some(val) = &self.value.name {
+            #[derive(RefCast)]
+            #[repr(transparent)]
+            struct Print((proc_macro2::Ident, syn::token::Eq));
+            impl Debug for Print {
+                fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("Some(")?;
+                    Debug::fmt(Lite(&self.0.0), formatter)?;
+                    formatter.write_str(")")?;
+                    Ok(())
+                }
+            }
+            formatter.field("name", Print::ref_cast(val));
+        }
+        formatter.field("kind", Lite(&self.value.kind));
+        formatter.finish()
+    }
+}
+impl Debug for Lite<syn::AsmOperandKind> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            syn::AsmOperandKind::Reg(_val) => {
+                formatter.write_str("AsmOperandKind::Reg")?;
+                formatter.write_str("(")?;
+                Debug::fmt(Lite(_val), formatter)?;
+                formatter.write_str(")")?;
+                Ok(())
+            }
+            syn::AsmOperandKind::Const(_v0, _v1) => {
+                let mut formatter = formatter.debug_tuple("AsmOperandKind::Const");
+                formatter.field(Lite(_v1));
+                formatter.finish()
+            }
+            syn::AsmOperandKind::Sym(_v0, _v1) => {
+                let mut formatter = formatter.debug_tuple("AsmOperandKind::Sym");
+                formatter.field(Lite(_v0));
+                formatter.field(Lite(_v1));
+                formatter.finish()
+            }
+            syn::AsmOperandKind::Label(_v0, _v1) => {
+                let mut formatter = formatter.debug_tuple("AsmOperandKind::Label");
+                formatter.field(Lite(_v0));
+                formatter.field(Lite(_v1));
+                formatter.finish()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl Debug for Lite<syn::AsmOptions> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("AsmOptions");
+        formatter.field("options_token", Lite(&self.value.options_token));
+        if !self.value.options.is_empty() {
+            formatter.field("options", Lite(&self.value.options));
+        }
+        formatter.finish()
+    }
+}
+impl Debug for Lite<syn::AsmReg> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            syn::AsmReg::Class(_val) => {
+                formatter.write_str("AsmReg::Class")?;
+                formatter.write_str("(")?;
+                Debug::fmt(Lite(_val), formatter)?;
+                formatter.write_str(")")?;
+                Ok(())
+            }
+            syn::AsmReg::Explicit(_val) => {
+                formatter.write_str("AsmReg::Explicit")?;
+                formatter.write_str("(")?;
+                Debug::fmt(Lite(_val), formatter)?;
+                formatter.write_str(")")?;
+                Ok(())
+            }
+        }
+    }
+}
+impl Debug for Lite<syn::AsmRegOperand> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("AsmRegOperand");
+        formatter.field("direction", Lite(&self.value.direction));
+        formatter.field("reg", Lite(&self.value.reg));
+        formatter.field("expr", Lite(&self.value.expr));
+        if let Some(val) = &self.value.out_expr {
+            #[derive(RefCast)]
+            #[repr(transparent)]
+            struct Print((syn::token::FatArrow, syn::Expr));
+            impl Debug for Print {
+                fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("Some(")?;
+                    Debug::fmt(Lite(&self.0.1), formatter)?;
+                    formatter.write_str(")")?;
+                    Ok(())
+                }
+            }
+            formatter.field("out_expr", Print::ref_cast(val));
+        }
+        formatter.finish()
+    }
+}
 impl Debug for Lite<syn::AssocConst> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         let mut formatter = formatter.debug_struct("AssocConst");
@@ -473,6 +637,14 @@ impl Debug for Lite<syn::Expr> {
                 formatter.field("base", Lite(&_val.base));
                 formatter.finish()
             }
+            syn::Expr::Become(_val) => {
+                let mut formatter = formatter.debug_struct("Expr::Become");
+                if !_val.attrs.is_empty() {
+                    formatter.field("attrs", Lite(&_val.attrs));
+                }
+                formatter.field("expr", Lite(&_val.expr));
+                formatter.finish()
+            }
             syn::Expr::Binary(_val) => {
                 let mut formatter = formatter.debug_struct("Expr::Binary");
                 if !_val.attrs.is_empty() {
@@ -857,6 +1029,15 @@ impl Debug for Lite<syn::Expr> {
                 }
                 formatter.finish()
             }
+            syn::Expr::RawAddr(_val) => {
+                let mut formatter = formatter.debug_struct("Expr::RawAddr");
+                if !_val.attrs.is_empty() {
+                    formatter.field("attrs", Lite(&_val.attrs));
+                }
+                formatter.field("mutability", Lite(&_val.mutability));
+                formatter.field("expr", Lite(&_val.expr));
+                formatter.finish()
+            }
             syn::Expr::Reference(_val) => {
                 let mut formatter = formatter.debug_struct("Expr::Reference");
                 if !_val.attrs.is_empty() {
@@ -1084,6 +1265,16 @@ impl Debug for Lite<syn::ExprAwait> {
         formatter.finish()
     }
 }
+impl Debug for Lite<syn::ExprBecome> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("ExprBecome");
+        if !self.value.attrs.is_empty() {
+            formatter.field("attrs", Lite(&self.value.attrs));
+        }
+        formatter.field("expr", Lite(&self.value.expr));
+        formatter.finish()
+    }
+}
 impl Debug for Lite<syn::ExprBinary> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         let mut formatter = formatter.debug_struct("ExprBinary");
@@ -1514,6 +1705,17 @@ impl Debug for Lite<syn::ExprRange> {
         formatter.finish()
     }
 }
+impl Debug for Lite<syn::ExprRawAddr> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("ExprRawAddr");
+        if !self.value.attrs.is_empty() {
+            formatter.field("attrs", Lite(&self.value.attrs));
+        }
+        formatter.field("mutability", Lite(&self.value.mutability));
+        formatter.field("expr", Lite(&self.value.expr));
+        formatter.finish()
+    }
+}
 impl Debug for Lite<syn::ExprReference> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         let mut formatter = formatter.debug_struct("ExprReference");
@@ -1737,6 +1939,20 @@ impl Debug for Lite<syn::Field> {
             formatter.field("colon_token", &Present);
         }
         formatter.field("ty", Lite(&self.value.ty));
+        if let Some(val) = &self.value.default {
+            #[derive(RefCast)]
+            #[repr(transparent)]
+            struct Print((syn::token::Eq, syn::Expr));
+            impl Debug for Print {
+                fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("Some(")?;
+                    Debug::fmt(Lite(&self.0.1), formatter)?;
+                    formatter.write_str(")")?;
+                    Ok(())
+                }
+            }
+            formatter.field("default", Print::ref_cast(val));
+        }
         formatter.finish()
     }
 }
@@ -2219,6 +2435,15 @@ impl Debug for Lite<syn::Index> {
         formatter.finish()
     }
 }
+impl Debug for Lite<syn::InlineAsm> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut formatter = formatter.debug_struct("InlineAsm");
+        if !self.value.args.is_empty() {
+            formatter.field("args", Lite(&self.value.args));
+        }
+        formatter.finish()
+    }
+}
 impl Debug for Lite<syn::Item> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match &self.value {
@@ -3526,6 +3751,20 @@ impl Debug for Lite<syn::PathSegment> {
         formatter.finish()
     }
 }
+impl Debug for Lite<syn::PointerMutability> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            syn::PointerMutability::Const(_val) => {
+                formatter.write_str("PointerMutability::Const")?;
+                Ok(())
+            }
+            syn::PointerMutability::Mut(_val) => {
+                formatter.write_str("PointerMutability::Mut")?;
+                Ok(())
+            }
+        }
+    }
+}
 impl Debug for Lite<syn::PredicateLifetime> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         let mut formatter = formatter.debug_struct("PredicateLifetime");
@@ -3822,6 +4061,12 @@ impl Debug for Lite<syn::TraitBoundModifier> {
                 formatter.write_str("TraitBoundModifier::Maybe")?;
                 Ok(())
             }
+            syn::TraitBoundModifier::MaybeConst(_v0, _v1) => {
+                let mut formatter = formatter
+                    .debug_tuple("TraitBoundModifier::MaybeConst");
+                formatter.finish()
+            }
+            _ => unreachable!(),
         }
     }
 }
@@ -0,0 +1,147 @@
+use quote::quote;
+use syn::visit::{self, Visit};
+use syn::{AsmArg, AsmDirection, AsmOperandKind, AsmReg, Expr, ExprPath};
+
+#[test]
+fn test_round_trip() {
+    let tokens = quote! {
+        "mov {0}, {1}",
+        "add {0}, {number}",
+        out(reg) x,
+        in(reg) y,
+        number = const 5,
+        inout("eax") a => b,
+        inlateout(reg) c,
+        lateout("edx") _,
+        sym foo::bar,
+        clobber_abi("C", "system"),
+        options(nostack, pure, readonly),
+    };
+    let asm = syn::asm::parse(tokens.clone()).unwrap();
+    assert_eq!(quote!(#asm).to_string(), tokens.to_string());
+
+    let templates: Vec<String> = asm.templates().map(|lit| lit.value()).collect();
+    assert_eq!(templates, ["mov {0}, {1}", "add {0}, {number}"]);
+    let abis: Vec<String> = asm.clobber_abis().map(|lit| lit.value()).collect();
+    assert_eq!(abis, ["C", "system"]);
+    let options: Vec<String> = asm.options().map(|ident| ident.to_string()).collect();
+    assert_eq!(options, ["nostack", "pure", "readonly"]);
+    assert!(matches!(asm.args.last().unwrap(), AsmArg::Options(_)));
+
+    let operands: Vec<_> = asm.operands().collect();
+    assert_eq!(operands.len(), 7);
+    assert!(operands[0].name.is_none());
+    assert_eq!(operands[2].name.as_ref().unwrap().0, "number");
+    assert!(matches!(operands[2].kind, AsmOperandKind::Const(..)));
+    assert!(matches!(operands[6].kind, AsmOperandKind::Sym(..)));
+
+    let directions: Vec<AsmDirection> = operands
+        .iter()
+        .filter_map(|operand| match &operand.kind {
+            AsmOperandKind::Reg(operand) => Some(operand.direction),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        directions,
+        [
+            AsmDirection::Out,
+            AsmDirection::In,
+            AsmDirection::InOut,
+            AsmDirection::InLateOut,
+            AsmDirection::LateOut,
+        ],
+    );
+    assert!(AsmDirection::InOut.is_input() && AsmDirection::InOut.is_output());
+    assert!(!AsmDirection::LateOut.is_input());
+
+    match &operands[3].kind {
+        AsmOperandKind::Reg(operand) => {
+            assert!(matches!(&operand.reg, AsmReg::Explicit(reg) if reg.value() == "eax"));
+            assert!(operand.out_expr.is_some());
+        }
+        _ => panic!("expected register operand"),
+    }
+    match &operands[5].kind {
+        AsmOperandKind::Reg(operand) => assert!(matches!(operand.expr, Expr::Infer(_))),
+        _ => panic!("expected register operand"),
+    }
+}
+
+#[test]
+fn test_visit() {
+    struct Paths(Vec<String>);
+
+    impl<'ast> Visit<'ast> for Paths {
+        fn visit_expr_path(&mut self, node: &'ast ExprPath) {
+            self.0.push(quote!(#node).to_string());
+            visit::visit_expr_path(self, node);
+        }
+    }
+
+    let asm = syn::asm::parse(quote!("xchg {0}, {1}", inout(reg) a => b, in(reg) c)).unwrap();
+    let mut paths = Paths(Vec::new());
+    paths.visit_inline_asm(&asm);
+    assert_eq!(paths.0, ["a", "b", "c"]);
+    assert_eq!(asm.clone(), asm);
+}
+
+#[test]
+fn test_label() {
+    let tokens = quote! {
+        "jmp {}", "jmp {done}",
+        label { return 1; },
+        done = label {},
+        in(reg) x,
+    };
+    let asm = syn::asm::parse(tokens.clone()).unwrap();
+    assert_eq!(quote!(#asm).to_string(), tokens.to_string());
+
+    let operands: Vec<_> = asm.operands().collect();
+    assert_eq!(operands.len(), 3);
+    match &operands[0].kind {
+        AsmOperandKind::Label(label_token, block) => {
+            assert_eq!(label_token, "label");
+            assert_eq!(block.stmts.len(), 1);
+        }
+        _ => panic!("expected label operand"),
+    }
+    assert_eq!(operands[1].name.as_ref().unwrap().0, "done");
+    assert!(matches!(operands[1].kind, AsmOperandKind::Label(..)));
+
+    // Without a block, `label` is not an operand.
+    let err = syn::asm::parse(quote!("nop", label x)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected operand, clobber_abi, options, or additional template string",
+    );
+}
+
+#[test]
+fn test_trailing_comma() {
+    let asm = syn::asm::parse(quote!("nop",)).unwrap();
+    assert_eq!(asm.templates().count(), 1);
+    assert!(asm.args.trailing_punct());
+}
+
+#[test]
+fn test_errors() {
+    let err = syn::asm::parse(quote!()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected end of input, expected string literal"
+    );
+
+    let err = syn::asm::parse(quote!("nop", push(reg) x)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected operand, clobber_abi, options, or additional template string",
+    );
+
+    // Templates must come before all other arguments.
+    let err = syn::asm::parse(quote!("nop", in(reg) x, "nop")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected operand, clobber_abi, options, or additional template string",
+    );
+}
@@ -3,8 +3,9 @@
 #[macro_use]
 mod macros;
 
+use quote::ToTokens;
 use syn::parse::Parser;
-use syn::{Attribute, Meta};
+use syn::{parse_quote, AttrStyle, Attribute, Meta};
 
 #[test]
 fn test_meta_item_word() {
@@ -234,6 +235,148 @@ fn test_unsafe() {
     "###);
 }
 
+#[test]
+fn test_marker() {
+    let mut attrs = Attribute::parse_outer
+        .parse_str("#[inline] #[serde(rename = \"x\")]")
+        .unwrap();
+    assert!(syn::attr::has_marker(&attrs, "inline"));
+    assert!(syn::attr::has_marker(&attrs, "serde"));
+    assert!(!syn::attr::has_marker(&attrs, "my_macro::expanded"));
+
+    syn::attr::insert_marker(&mut attrs, parse_quote!(my_macro::expanded));
+    assert_eq!(attrs.len(), 3);
+    assert!(syn::attr::has_marker(&attrs, "my_macro::expanded"));
+    assert!(!syn::attr::has_marker(&attrs, "my_macro"));
+    assert!(!syn::attr::has_marker(&attrs, "expanded"));
+    assert!(!syn::attr::has_marker(&attrs, "::my_macro::expanded"));
+    assert!(!syn::attr::has_marker(&attrs, "my_macro::expanded::more"));
+
+    let marker = &attrs[2];
+    assert_eq!(
+        quote::quote!(#marker).to_string(),
+        "# [my_macro :: expanded]"
+    );
+}
+
+#[test]
+fn test_dedup() {
+    let mut attrs = Attribute::parse_outer
+        .parse_str(
+            "#[allow(dead_code)] #[doc = \"\"] #[inline] #[allow(dead_code)] #[doc = \"\"] \
+             #[allow(unused)] #[allow( dead_code )]",
+        )
+        .unwrap();
+    attrs.extend(
+        Attribute::parse_inner
+            .parse_str("#![allow(dead_code)]")
+            .unwrap(),
+    );
+
+    syn::attr::dedup(&mut attrs);
+
+    let rendered: Vec<String> = attrs
+        .iter()
+        .map(|attr| quote::quote!(#attr).to_string())
+        .collect();
+    assert_eq!(
+        rendered,
+        [
+            "# [allow (dead_code)]",
+            "# [doc = \"\"]",
+            "# [inline]",
+            "# [doc = \"\"]",
+            "# [allow (unused)]",
+            "# ! [allow (dead_code)]",
+        ],
+    );
+}
+
+#[test]
+fn test_cfg_attr_split() {
+    let attr: Attribute = parse_quote!(#[cfg_attr(all(unix, feature = "x"), serde(skip), inline,)]);
+    let (predicate, attrs) = attr.cfg_attr_split().unwrap();
+    let expected: Meta = parse_quote!(all(unix, feature = "x"));
+    assert_eq!(predicate, expected);
+    let expected: [Meta; 2] = [parse_quote!(serde(skip)), parse_quote!(inline)];
+    assert_eq!(attrs, expected);
+
+    let attr: Attribute = parse_quote!(#[cfg_attr(test,)]);
+    let (predicate, attrs) = attr.cfg_attr_split().unwrap();
+    assert!(predicate.path().is_ident("test"));
+    assert!(attrs.is_empty());
+
+    let attr: Attribute = parse_quote!(#[cfg_attr(test)]);
+    assert!(attr.cfg_attr_split().is_none());
+
+    let attr: Attribute = parse_quote!(#[cfg(test)]);
+    assert!(attr.cfg_attr_split().is_none());
+}
+
+#[test]
+fn test_set_meta() {
+    let mut attr: Attribute = parse_quote!(#![allow(dead_code)]);
+    attr.set_meta(parse_quote!(deny(warnings)));
+    assert!(matches!(attr.style, AttrStyle::Inner(_)));
+    assert!(attr.path().is_ident("deny"));
+    assert_eq!(attr.to_token_stream().to_string(), "# ! [deny (warnings)]");
+}
+
+#[test]
+fn test_parse_repr() {
+    let attrs = Attribute::parse_outer
+        .parse_str("#[derive(Debug)] #[repr(u8, C)] #[repr(packed, Rust)]")
+        .unwrap();
+    let repr = syn::attr::parse_repr(&attrs).unwrap();
+    assert!(repr.c);
+    assert!(!repr.transparent);
+    assert_eq!(repr.int.unwrap(), "u8");
+    assert_eq!(repr.packed, Some(1));
+    assert_eq!(repr.align, None);
+
+    let repr = syn::attr::parse_repr(&[]).unwrap();
+    assert!(!repr.c && repr.int.is_none() && repr.packed.is_none());
+
+    for (input, error) in [
+        ("#[repr(simd)]", "unrecognized repr"),
+        (
+            "#[repr(u8)] #[repr(i32)]",
+            "conflicting representation hints",
+        ),
+        ("#[repr(align(N))]", "expected integer literal"),
+    ] {
+        let attrs = Attribute::parse_outer.parse_str(input).unwrap();
+        let err = syn::attr::parse_repr(&attrs).unwrap_err();
+        assert_eq!(err.to_string(), error, "{}", input);
+    }
+}
+
+#[test]
+fn test_item_cfg() {
+    fn cfg(input: &str) -> Option<String> {
+        let attrs = Attribute::parse_outer.parse_str(input).unwrap();
+        syn::attr::item_cfg(&attrs).map(|meta| meta.to_token_stream().to_string())
+    }
+
+    assert_eq!(cfg("#[inline] #[allow(dead_code)]"), None);
+    assert_eq!(cfg("#[cfg(test)]").as_deref(), Some("test"));
+    assert_eq!(
+        cfg("#[cfg(not(feature = \"std\"))] #[inline]").as_deref(),
+        Some("not (feature = \"std\")"),
+    );
+    assert_eq!(
+        cfg("#[cfg(unix)] #[doc(hidden)] #[cfg(any(test, doc))]").as_deref(),
+        Some("all (unix , any (test , doc))"),
+    );
+
+    // Malformed cfg attributes and cfg_attr are ignored.
+    assert_eq!(
+        cfg("#[cfg] #[cfg = \"x\"] #[cfg(test)]").as_deref(),
+        Some("test")
+    );
+    assert_eq!(cfg("#[cfg_attr(test, cfg(unix))]"), None);
+}
+
 fn test(input: &str) -> Meta {
     let attrs = Attribute::parse_outer.parse_str(input).unwrap();
 
@@ -242,3 +385,27 @@ fn test(input: &str) -> Meta {
 
     attr.meta
 }
+
+#[test]
+fn test_doc_extract() {
+    let attrs: Vec<Attribute> = vec![
+        parse_quote!(#[doc = " First line."]),
+        parse_quote!(#[doc = ""]),
+        parse_quote!(#[doc(hidden)]),
+        parse_quote!(#[inline]),
+        parse_quote!(#[doc = "  Indented."]),
+        parse_quote!(#[doc = include_str!("README.md")]),
+    ];
+    assert_eq!(syn::doc::extract(&attrs), "First line.\n\n Indented.");
+
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[doc = "\n * Block\n * comment.\n "])];
+    assert_eq!(syn::doc::extract(&attrs), "Block\ncomment.");
+
+    let attrs: Vec<Attribute> = vec![
+        parse_quote!(#![doc = " Inner."]),
+        parse_quote!(#[doc = "\n    Unstarred\n    block.\n"]),
+    ];
+    assert_eq!(syn::doc::extract(&attrs), "Inner.\n   Unstarred\n   block.");
+
+    assert_eq!(syn::doc::extract(&[]), "");
+}
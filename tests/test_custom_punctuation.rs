@@ -0,0 +1,97 @@
+use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::parse::{ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::Ident;
+
+syn::custom_punctuation!(LArrow3, <<<);
+syn::custom_punctuation!(LArrow4, <<<<);
+syn::custom_punctuation!(FatPipe, |=>);
+
+fn spacings(tokens: TokenStream) -> Vec<(char, Spacing)> {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Punct(punct) => (punct.as_char(), punct.spacing()),
+            _ => panic!("expected punct"),
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_and_print() {
+    let arrow: LArrow3 = syn::parse_str("<<<").unwrap();
+    assert_eq!(arrow.spans.len(), 3);
+    assert_eq!(
+        spacings(arrow.to_token_stream()),
+        [
+            ('<', Spacing::Joint),
+            ('<', Spacing::Joint),
+            ('<', Spacing::Alone)
+        ],
+    );
+    assert_eq!(arrow.to_token_stream().to_string(), "<<<");
+
+    let arrow: LArrow4 = syn::parse_str("<<<<").unwrap();
+    assert_eq!(arrow.spans.len(), 4);
+    assert_eq!(arrow.to_token_stream().to_string(), "<<<<");
+    let reparsed: LArrow4 = syn::parse2(arrow.to_token_stream()).unwrap();
+    assert_eq!(reparsed.spans.len(), 4);
+
+    let pipe: FatPipe = syn::parse_str("|=>").unwrap();
+    assert_eq!(pipe.to_token_stream().to_string(), "|=>");
+}
+
+#[test]
+fn test_spacing_is_significant() {
+    for input in ["< <<", "<< <", "< < <"] {
+        syn::parse_str::<LArrow3>(input).unwrap_err();
+    }
+    for input in ["<<< <", "< <<<", "<< <<"] {
+        syn::parse_str::<LArrow4>(input).unwrap_err();
+    }
+
+    let peek = |input: ParseStream| {
+        let peeked = (input.peek(LArrow3), input.peek(LArrow4));
+        input.parse::<TokenStream>()?;
+        Ok(peeked)
+    };
+    assert_eq!(peek.parse_str("<<< x").unwrap(), (true, false));
+    assert_eq!(peek.parse_str("<<<< x").unwrap(), (true, true));
+    assert_eq!(peek.parse_str("< << x").unwrap(), (false, false));
+    assert_eq!(peek.parse_str("<<< < x").unwrap(), (true, false));
+}
+
+#[test]
+fn test_error() {
+    let err = syn::parse_str::<LArrow3>("< <<").unwrap_err();
+    assert_eq!(err.to_string(), "expected `<<<`");
+}
+
+#[test]
+fn test_respan() {
+    syn::custom_keyword!(then);
+
+    let content = "a <<< b <<< c";
+    let parser = Punctuated::<Ident, LArrow3>::parse_separated_nonempty;
+    let mut idents = parser.parse_str(content).unwrap();
+    let text = |span: Span| &content[span.byte_range()];
+
+    let span = idents[1].span();
+    idents.respan(span);
+    for punct in idents.puncts() {
+        assert_eq!(punct.spans.map(text), ["b"; 3]);
+    }
+    let idents_text: Vec<&str> = idents.iter().map(|ident| text(ident.span())).collect();
+    assert_eq!(idents_text, ["a", "b", "c"]);
+
+    let content = "a then b";
+    let parser = Punctuated::<Ident, then>::parse_separated_nonempty;
+    let mut idents = parser.parse_str(content).unwrap();
+    let text = |span: Span| &content[span.byte_range()];
+
+    idents.respan(idents[0].span());
+    let punct = idents.puncts().next().unwrap();
+    assert_eq!(text(punct.span), "a");
+    assert_eq!(text(idents[1].span()), "b");
+}
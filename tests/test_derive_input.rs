@@ -9,7 +9,7 @@
 mod macros;
 
 use quote::quote;
-use syn::{Data, DeriveInput};
+use syn::{Data, DataEnum, DeriveInput, Fields, HelperAttrLocation, Visibility};
 
 #[test]
 fn test_unit() {
@@ -728,6 +728,40 @@ fn test_fields_on_tuple_struct() {
     "###);
 }
 
+#[test]
+fn test_fields_iter_named_and_indexed() {
+    let named: DeriveInput = syn::parse_quote! {
+        struct S {
+            foo: i32,
+            bar: String,
+        }
+    };
+    let tuple: DeriveInput = syn::parse_quote! {
+        struct S(i32, String);
+    };
+
+    let (named, tuple) = match (named.data, tuple.data) {
+        (Data::Struct(named), Data::Struct(tuple)) => (named.fields, tuple.fields),
+        _ => panic!("expected a struct"),
+    };
+
+    let idents: Vec<_> = named
+        .iter_named()
+        .unwrap()
+        .map(|(ident, _field)| ident.to_string())
+        .collect();
+    assert_eq!(idents, ["foo", "bar"]);
+    assert!(named.iter_indexed().is_none());
+
+    let indices: Vec<_> = tuple
+        .iter_indexed()
+        .unwrap()
+        .map(|(index, _field)| index.index)
+        .collect();
+    assert_eq!(indices, [0, 1]);
+    assert!(tuple.iter_named().is_none());
+}
+
 #[test]
 fn test_ambiguous_crate() {
     let input = quote! {
@@ -765,3 +799,264 @@ fn test_ambiguous_crate() {
     }
     "###);
 }
+
+#[test]
+fn test_discriminant_value() {
+    let input: DeriveInput = syn::parse_quote! {
+        enum E {
+            A,
+            B = 2,
+            C = -0x10,
+            D = 1 << 3,
+            E = 300u8,
+            F = -170141183460469231731687303715884105728,
+            G = 170141183460469231731687303715884105728,
+        }
+    };
+
+    let data = match input.data {
+        Data::Enum(data) => data,
+        _ => panic!("expected an enum"),
+    };
+
+    let values: Vec<_> = data
+        .variants
+        .iter()
+        .map(|variant| variant.discriminant_value().map(|value| value.ok()))
+        .collect();
+    assert_eq!(
+        values,
+        [
+            None,
+            Some(Some(2)),
+            Some(Some(-16)),
+            Some(None),
+            Some(Some(300)),
+            Some(Some(i128::MIN)),
+            Some(None),
+        ]
+    );
+}
+
+#[test]
+fn test_discriminant_value_error_span() {
+    let input: DeriveInput = syn::parse_str("enum E { D = 1 << 3 }").unwrap();
+    let data = match input.data {
+        Data::Enum(data) => data,
+        _ => panic!("expected an enum"),
+    };
+
+    let err = data.variants[0].discriminant_value().unwrap().unwrap_err();
+    assert_eq!(err.to_string(), "expected integer literal as discriminant");
+    let span = err.span();
+    assert_eq!((span.start().column, span.end().column), (13, 19));
+}
+
+#[test]
+fn test_fieldless_enum() {
+    fn enum_data(input: DeriveInput) -> DataEnum {
+        match input.data {
+            Data::Enum(data) => data,
+            _ => panic!("expected an enum"),
+        }
+    }
+
+    let data = enum_data(syn::parse_quote! {
+        enum E {
+            A,
+            B = 2,
+        }
+    });
+    assert!(data.is_fieldless());
+    assert!(data.has_discriminants());
+
+    let data = enum_data(syn::parse_quote! {
+        enum E {
+            A,
+            B(u8),
+        }
+    });
+    assert!(!data.is_fieldless());
+    assert!(!data.has_discriminants());
+
+    let data = enum_data(syn::parse_quote! {
+        enum E {
+            A {},
+        }
+    });
+    assert!(!data.is_fieldless());
+
+    let data = enum_data(syn::parse_quote! {
+        enum E {}
+    });
+    assert!(data.is_fieldless());
+    assert!(!data.has_discriminants());
+}
+
+#[test]
+fn test_fields_map() {
+    let input: DeriveInput = syn::parse_quote! {
+        struct S(u8, pub String,);
+    };
+    let fields = match input.data {
+        Data::Struct(data) => data.fields,
+        _ => panic!("expected a struct"),
+    };
+
+    let wrapped = fields.clone().map(|mut field| {
+        let ty = field.ty;
+        field.ty = syn::parse_quote!(Option<#ty>);
+        field
+    });
+    assert!(matches!(wrapped, Fields::Unnamed(_)));
+    assert_eq!(
+        quote!(#wrapped).to_string(),
+        quote!((Option<u8>, pub Option<String>,)).to_string(),
+    );
+
+    let err = fields
+        .try_map(|field| match &field.vis {
+            Visibility::Inherited => Ok(field),
+            vis => Err(syn::Error::new_spanned(vis, "fields must be private")),
+        })
+        .unwrap_err();
+    assert_eq!(err.to_string(), "fields must be private");
+
+    let unit = Fields::Unit.try_map(|_| unreachable!()).unwrap();
+    assert!(matches!(unit, Fields::Unit));
+}
+
+#[test]
+fn test_collect_helper_attrs() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[derive(MyTrait)]
+        #[my_trait(crate = "x")]
+        struct S {
+            #[my_trait(skip)]
+            #[serde(skip)]
+            a: u8,
+            b: u8,
+            #[my_trait(default)]
+            c: u8,
+        }
+    };
+
+    let attrs = input.collect_helper_attrs("my_trait");
+    let locations: Vec<String> = attrs
+        .iter()
+        .map(|(location, _attr)| match location {
+            HelperAttrLocation::Container => "container".to_owned(),
+            HelperAttrLocation::Variant(variant) => variant.ident.to_string(),
+            HelperAttrLocation::Field {
+                variant: None,
+                index,
+                field,
+            } => format!("{} {}", index, field.ident.as_ref().unwrap()),
+            HelperAttrLocation::Field {
+                variant: Some(_), ..
+            } => unreachable!(),
+        })
+        .collect();
+    assert_eq!(locations, ["container", "0 a", "2 c"]);
+
+    let tokens: Vec<String> = attrs
+        .iter()
+        .map(|(_location, attr)| quote!(#attr).to_string())
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            "# [my_trait (crate = \"x\")]",
+            "# [my_trait (skip)]",
+            "# [my_trait (default)]",
+        ],
+    );
+
+    let input: DeriveInput = syn::parse_quote! {
+        enum E {
+            #[my_trait(skip)]
+            A,
+            B(u8, #[my_trait(default)] u8),
+        }
+    };
+
+    let attrs = input.collect_helper_attrs("my_trait");
+    let locations: Vec<String> = attrs
+        .iter()
+        .map(|(location, _attr)| match location {
+            HelperAttrLocation::Container => "container".to_owned(),
+            HelperAttrLocation::Variant(variant) => variant.ident.to_string(),
+            HelperAttrLocation::Field {
+                variant: Some(variant),
+                index,
+                ..
+            } => format!("{}.{}", variant.ident, index),
+            HelperAttrLocation::Field { variant: None, .. } => unreachable!(),
+        })
+        .collect();
+    assert_eq!(locations, ["A", "B.1"]);
+}
+
+#[test]
+fn test_field_default_values() {
+    let input = quote! {
+        struct S {
+            x: i32 = 0,
+            y: u8,
+        }
+    };
+
+    snapshot!(input as DeriveInput, @r###"
+    DeriveInput {
+        vis: Visibility::Inherited,
+        ident: "S",
+        generics: Generics,
+        data: Data::Struct {
+            fields: Fields::Named {
+                named: [
+                    Field {
+                        vis: Visibility::Inherited,
+                        ident: Some("x"),
+                        colon_token: Some,
+                        ty: Type::Path {
+                            path: Path {
+                                segments: [
+                                    PathSegment {
+                                        ident: "i32",
+                                    },
+                                ],
+                            },
+                        },
+                        default: Some(Expr::Lit {
+                            lit: 0,
+                        }),
+                    },
+                    Field {
+                        vis: Visibility::Inherited,
+                        ident: Some("y"),
+                        colon_token: Some,
+                        ty: Type::Path {
+                            path: Path {
+                                segments: [
+                                    PathSegment {
+                                        ident: "u8",
+                                    },
+                                ],
+                            },
+                        },
+                    },
+                ],
+            },
+        },
+    }
+    "###);
+
+    let input = quote! {
+        struct S {
+            pub x: Vec<u8> = Vec::new(),
+            y: bool = !false,
+        }
+    };
+    let derive_input: DeriveInput = syn::parse2(input.clone()).unwrap();
+    assert_eq!(quote!(#derive_input).to_string(), input.to_string());
+}
@@ -0,0 +1,19 @@
+use proc_macro2::TokenTree;
+use syn::{Error, ItemStruct};
+
+#[test]
+fn test_new_spanned2() {
+    let content = "#[serde(skip)]\n#[serde(rename = \"b\")]\nstruct S;\n";
+    let item: ItemStruct = syn::parse_str(content).unwrap();
+    let error = Error::new_spanned2(&item.attrs[0], &item.attrs[1], "conflict");
+
+    let tokens: Vec<TokenTree> = error.to_compile_error().into_iter().collect();
+    let start = tokens[0].span().start();
+    let end = tokens[2].span().end();
+    assert_eq!((start.line, start.column), (1, 0));
+    assert_eq!((end.line, end.column), (2, 22));
+
+    let span = error.span();
+    assert_eq!(span.start().line, 1);
+    assert_eq!(span.end().line, 2);
+}
@@ -3,9 +3,11 @@
 #[macro_use]
 mod macros;
 
-use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use proc_macro2::{
+    Delimiter, Group, Ident, LineColumn, Punct, Spacing, Span, TokenStream, TokenTree,
+};
 use quote::quote;
-use syn::{Expr, ExprRange};
+use syn::{BinOp, ConstRef, Expr, ExprBinary, ExprClosure, ExprRange, Lit, Pat, UnOp};
 
 #[test]
 fn test_expr_parse() {
@@ -30,6 +32,43 @@ fn test_expr_parse() {
     "###);
 }
 
+#[test]
+fn test_range_accessors() {
+    let range: ExprRange = syn::parse_str("1..5").unwrap();
+    assert!(matches!(range.start(), Some(Expr::Lit(_))));
+    assert!(matches!(range.end(), Some(Expr::Lit(_))));
+    assert!(!range.limits.is_inclusive());
+
+    let range: ExprRange = syn::parse_str("..=5").unwrap();
+    assert!(range.start().is_none());
+    assert!(matches!(range.end(), Some(Expr::Lit(_))));
+    assert!(range.limits.is_inclusive());
+
+    let range: ExprRange = syn::parse_str("1..").unwrap();
+    assert!(range.start().is_some());
+    assert!(range.end().is_none());
+
+    let range: ExprRange = syn::parse_str("..").unwrap();
+    assert!(range.start().is_none());
+    assert!(range.end().is_none());
+    assert!(!range.limits.is_inclusive());
+}
+
+#[test]
+fn test_closure_modifiers() {
+    let closure: ExprClosure = syn::parse_str("|x| x + 1").unwrap();
+    assert!(!closure.is_async());
+    assert!(!closure.is_move());
+    assert!(!closure.is_static());
+    assert!(!closure.has_return_type());
+
+    let closure: ExprClosure = syn::parse_str("static async move |x| -> u8 { x }").unwrap();
+    assert!(closure.is_async());
+    assert!(closure.is_move());
+    assert!(closure.is_static());
+    assert!(closure.has_return_type());
+}
+
 #[test]
 fn test_await() {
     // Must not parse as Expr::Field.
@@ -310,3 +349,343 @@ fn test_ranges() {
     syn::parse_str::<Expr>("lo...").unwrap_err();
     syn::parse_str::<Expr>("lo...hi").unwrap_err();
 }
+
+#[test]
+fn test_as_const_ref() {
+    let expr: Expr = syn::parse_quote!(5);
+    match expr.as_const_ref() {
+        Some(ConstRef::Lit(Lit::Int(int))) => assert_eq!(int.base10_digits(), "5"),
+        _ => panic!("expected an integer literal"),
+    }
+
+    let expr: Expr = syn::parse_quote!(Self::MAX);
+    match expr.as_const_ref() {
+        Some(ConstRef::Path(path)) => assert_eq!(path.segments.len(), 2),
+        _ => panic!("expected a path"),
+    }
+
+    let expr: Expr = syn::parse_quote!(<T as Trait>::MAX);
+    assert!(expr.as_const_ref().is_none());
+
+    let expr: Expr = syn::parse_quote!(1 + 1);
+    assert!(expr.as_const_ref().is_none());
+}
+
+#[test]
+fn test_field_builders() {
+    let expr = Expr::tuple_index(syn::parse_quote!(self), 1, Span::call_site());
+    assert_eq!(quote!(#expr).to_string(), "self . 1");
+
+    let expr = Expr::field(expr, Ident::new("len", Span::call_site()));
+    assert_eq!(quote!(#expr).to_string(), "self . 1 . len");
+
+    let expr = Expr::tuple_index(syn::parse_quote!(a + b), 0, Span::call_site());
+    assert_eq!(quote!(#expr).to_string(), "(a + b) . 0");
+
+    let expr = Expr::field(syn::parse_quote!(*ptr), Ident::new("x", Span::call_site()));
+    assert_eq!(quote!(#expr).to_string(), "(* ptr) . x");
+
+    // Printed as `1.0`, the field access would reparse as a float literal.
+    let expr = Expr::tuple_index(syn::parse_quote!(1), 0, Span::call_site());
+    assert_eq!(quote!(#expr).to_string(), "(1) . 0");
+    let file: syn::File = syn::parse_quote!(fn f() { #expr; });
+    let reparsed: syn::File = syn::parse_str(&syn::unparse::file(&file)).unwrap();
+    assert_eq!(reparsed, file);
+}
+
+#[test]
+fn test_macro_parse_body_empty() {
+    fn location(span: Span) -> (LineColumn, LineColumn) {
+        (span.start(), span.end())
+    }
+
+    let mac: syn::Macro = syn::parse_str("m! {}").unwrap();
+    let err = mac.parse_body::<Expr>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected end of input, expected expression"
+    );
+    let delimiter = mac.delimiter.span();
+    assert_eq!(location(err.span()), location(delimiter.join()));
+
+    let mac: syn::Macro = syn::parse_str("m!(1 +)").unwrap();
+    let err = mac.parse_body::<Expr>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected end of input, expected expression"
+    );
+    let delimiter = mac.delimiter.span();
+    assert_eq!(location(err.span()), location(delimiter.close()));
+}
+
+#[test]
+fn test_arm_guard() {
+    let arm: syn::Arm = syn::parse_quote!(Some(n) if n > 0 => n,);
+    let guard = arm.guard_expr().unwrap();
+    assert_eq!(quote!(#guard).to_string(), "n > 0");
+
+    let arm = arm.with_guard(syn::parse_quote!(n > 1 && n < 10));
+    assert_eq!(
+        quote!(#arm).to_string(),
+        "Some (n) if n > 1 && n < 10 => n ,",
+    );
+
+    let arm: syn::Arm = syn::parse_quote!(_ => 0);
+    assert!(arm.guard_expr().is_none());
+}
+
+#[test]
+fn test_cast() {
+    let expr = Expr::cast(syn::parse_quote!(x), syn::parse_quote!(u64));
+    assert_eq!(quote!(#expr).to_string(), "x as u64");
+
+    let expr = Expr::cast(expr, syn::parse_quote!(f32));
+    assert_eq!(quote!(#expr).to_string(), "x as u64 as f32");
+
+    let expr = Expr::cast(syn::parse_quote!(1), syn::parse_quote!(u8));
+    assert_eq!(quote!(#expr).to_string(), "1 as u8");
+
+    let expr = Expr::cast(syn::parse_quote!(-x), syn::parse_quote!(u8));
+    assert_eq!(quote!(#expr).to_string(), "- x as u8");
+
+    let expr = Expr::cast(syn::parse_quote!(a * b), syn::parse_quote!(usize));
+    assert_eq!(quote!(#expr).to_string(), "(a * b) as usize");
+
+    let cast = match &expr {
+        Expr::Cast(cast) => cast,
+        _ => unreachable!(),
+    };
+    let operand: Expr = syn::parse_quote!((a * b));
+    assert_eq!(*cast.operand(), operand);
+    let ty: syn::Type = syn::parse_quote!(usize);
+    assert_eq!(*cast.target_type(), ty);
+}
+
+#[test]
+fn test_const_block() {
+    let expr: Expr = syn::parse_str("const { 1 }").unwrap();
+    assert_eq!(quote!(#expr).to_string(), "const { 1 }");
+    snapshot!(expr, @r###"
+    Expr::Const {
+        block: Block {
+            stmts: [
+                Stmt::Expr(
+                    Expr::Lit {
+                        lit: 1,
+                    },
+                    None,
+                ),
+            ],
+        },
+    }
+    "###);
+
+    let expr: Expr = syn::parse_str("const { 1 }.max(2)").unwrap();
+    assert!(matches!(expr, Expr::MethodCall(_)));
+}
+
+#[test]
+fn test_become() {
+    let tokens = quote!(become f(x));
+    snapshot!(tokens as Expr, @r###"
+    Expr::Become {
+        expr: Expr::Call {
+            func: Expr::Path {
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "f",
+                        },
+                    ],
+                },
+            },
+            args: [
+                Expr::Path {
+                    path: Path {
+                        segments: [
+                            PathSegment {
+                                ident: "x",
+                            },
+                        ],
+                    },
+                },
+            ],
+        },
+    }
+    "###);
+
+    let expr: Expr = syn::parse_str("become self.f(x + 1)").unwrap();
+    assert_eq!(quote!(#expr).to_string(), "become self . f (x + 1)");
+
+    syn::parse_str::<Expr>("become").unwrap_err();
+}
+
+#[test]
+fn test_unparen() {
+    let expr: Expr = syn::parse_str("((x))").unwrap();
+    assert!(matches!(expr.unparen(), Expr::Path(_)));
+
+    for tuple in ["(x,)", "((x, y))", "(())"] {
+        let expr: Expr = syn::parse_str(tuple).unwrap();
+        assert!(matches!(expr.unparen(), Expr::Tuple(_)), "{}", tuple);
+    }
+
+    // (#x) where x is the expression `a * b`, substituted in a None-delimited
+    // group.
+    let group = Group::new(Delimiter::None, quote!(a * b));
+    let tokens = quote!((#group));
+    let expr: Expr = syn::parse2(tokens).unwrap();
+    assert!(matches!(expr.unparen(), Expr::Binary(_)));
+
+    let expr: Expr = syn::parse_str("(a).b").unwrap();
+    assert!(matches!(expr.unparen(), Expr::Field(_)));
+}
+
+#[test]
+fn test_try_desugar() {
+    let expr: Expr = syn::parse_str("#[allow(unused)] (a? + 1)?").unwrap();
+    let expr = match expr {
+        Expr::Try(expr) => expr,
+        value => panic!("expected ExprTry, got {:?}", value),
+    };
+    let desugared = expr.desugar();
+    assert_eq!(
+        quote!(#desugared).to_string(),
+        quote! {
+            #[allow(unused)]
+            match (a? + 1) {
+                ::core::result::Result::Ok(val) => val,
+                ::core::result::Result::Err(err) => return ::core::result::Result::Err(::core::convert::From::from(err)),
+            }
+        }
+        .to_string(),
+    );
+}
+
+#[test]
+fn test_binop_precedence() {
+    let ops = [
+        "*", "/", "%", "+", "-", "<<", ">>", "&", "^", "|", "==", "<", "&&", "||",
+    ];
+    for first in ops {
+        for second in ops {
+            let source = format!("a {} b {} c", first, second);
+            let expr: Expr = syn::parse_str(&source).unwrap();
+            let (left, op) = match expr {
+                Expr::Binary(ExprBinary { left, op, .. }) => (left, op),
+                _ => unreachable!(),
+            };
+            let first_op: BinOp = syn::parse_str(first).unwrap();
+            let second_op: BinOp = syn::parse_str(second).unwrap();
+            let top = if first_op.precedence() >= second_op.precedence() {
+                assert!(matches!(*left, Expr::Binary(_)), "{}", source);
+                second
+            } else {
+                first
+            };
+            assert_eq!(op.as_str(), top, "{}", source);
+        }
+    }
+
+    let op: UnOp = syn::parse_str("!").unwrap();
+    assert_eq!(op.as_str(), "!");
+}
+
+#[test]
+fn test_expr_precedence() {
+    // a + b * c
+    let expr: Expr = syn::parse_str("a + b * c").unwrap();
+    let (op, right) = match &expr {
+        Expr::Binary(ExprBinary { op, right, .. }) => (op, right),
+        _ => unreachable!(),
+    };
+    assert!(right.precedence() > op.precedence());
+
+    // (a + b) * c
+    let expr: Expr = syn::parse_str("(a + b) * c").unwrap();
+    let (left, op) = match &expr {
+        Expr::Binary(ExprBinary { left, op, .. }) => (left, op),
+        _ => unreachable!(),
+    };
+    assert!(left.precedence() > op.precedence());
+    assert!(left.unparen().precedence() < op.precedence());
+
+    for (source, precedence) in [
+        ("x", 17),
+        ("(x, y)", 17),
+        ("x.f()", 16),
+        ("x?", 16),
+        ("-x", 15),
+        ("&mut x", 15),
+        ("x as T", 14),
+        ("x == y", 7),
+        ("x..y", 4),
+        ("x += y", 2),
+        ("|x| x", 0),
+        ("return x", 0),
+    ] {
+        let expr: Expr = syn::parse_str(source).unwrap();
+        assert_eq!(expr.precedence(), precedence, "{}", source);
+    }
+}
+
+#[test]
+fn test_simplify_parens() {
+    for (input, expected) in [
+        ("(a * b) + (c * d)", "a * b + c * d"),
+        ("(a + b) * (c + d)", "(a + b) * (c + d)"),
+        ("(a - b) - (c - d)", "a - b - (c - d)"),
+        ("(a == b) == c", "(a == b) == c"),
+        ("a = (b = c)", "a = b = c"),
+        ("a += (b += c)", "a += b += c"),
+        ("((x))", "(x)"),
+        ("(-(x)).abs()", "(- x) . abs ()"),
+        ("f((a, b), (c), [(d)])", "f ((a , b) , c , [d])"),
+        ("S { x: (a + b) }", "S { x : a + b }"),
+        ("((a.f))()", "(a . f) ()"),
+        ("(a.f)(x)", "(a . f) (x)"),
+        ("(1).0", "(1) . 0"),
+        ("(x as u8) < y", "(x as u8) < y"),
+        (
+            "((x as u8) as u16) + (a + (b as T))",
+            "(x as u8 as u16) + (a + (b as T))",
+        ),
+        ("((a..b)).len()", "(a .. b) . len ()"),
+        ("(match x {}) + 1", "(match x { }) + 1"),
+        ("((match x {}).f()) + 1", "(match x { }) . f () + 1"),
+        ("1 + (match x {})", "1 + match x { }"),
+        ("if (a == (S {})) { (b) }", "if (a == (S { })) { (b) }"),
+        ("if a { (b + c) * ((d)) }", "if a { (b + c) * d }"),
+        ("(|x| x)((y))", "(| x | x) (y)"),
+        ("(#[attr] x).y", "(# [attr] x) . y"),
+    ] {
+        let mut expr: Expr = syn::parse_str(input).unwrap();
+        expr.simplify_parens();
+        let expected: proc_macro2::TokenStream = expected.parse().unwrap();
+        assert_eq!(quote!(#expr).to_string(), expected.to_string(), "{}", input,);
+    }
+}
+
+#[test]
+fn test_closure_param_attrs() {
+    let expr: Expr = syn::parse_str("|#[a] x, #[b] #[c] y: u8, #[d] (z, _)| x").unwrap();
+
+    let closure = match &expr {
+        Expr::Closure(closure) => closure,
+        _ => panic!("expected closure"),
+    };
+    let attrs: Vec<usize> = closure
+        .inputs
+        .iter()
+        .map(|input| match input {
+            Pat::Ident(pat) => pat.attrs.len(),
+            Pat::Type(pat) => pat.attrs.len(),
+            Pat::Tuple(pat) => pat.attrs.len(),
+            _ => panic!("unexpected closure input"),
+        })
+        .collect();
+    assert_eq!(attrs, [1, 2, 1]);
+
+    let reparsed: Expr = syn::parse2(quote!(#expr)).unwrap();
+    assert_eq!(reparsed, expr);
+}
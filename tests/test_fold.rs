@@ -0,0 +1,47 @@
+use syn::fold::{self, Fold, TrackChanges};
+use syn::{parse_quote, Expr, File, Lit, LitInt};
+
+struct IncrementLiterals;
+
+impl Fold for IncrementLiterals {
+    fn fold_lit_int(&mut self, lit: LitInt) -> LitInt {
+        let value = lit.base10_parse::<u64>().unwrap();
+        LitInt::new(&(value + 1).to_string(), lit.span())
+    }
+}
+
+struct Identity;
+
+impl Fold for Identity {
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        fold::fold_expr(self, expr)
+    }
+}
+
+#[test]
+fn test_track_changes() {
+    let file: File = parse_quote! {
+        const A: &str = "a";
+        const B: u8 = 1;
+        const C: u8 = u8::MAX;
+    };
+
+    let mut fold = TrackChanges::new(Identity);
+    let file = fold.fold_file(file);
+    assert!(!fold.changed);
+
+    let mut fold = TrackChanges::new(IncrementLiterals);
+    let mut changed = Vec::new();
+    for item in file.items {
+        fold.changed = false;
+        let _ = fold.fold_item(item);
+        changed.push(fold.changed);
+    }
+    assert_eq!(changed, [false, true, false]);
+
+    let mut fold = TrackChanges::new(IncrementLiterals);
+    let lit: Lit = parse_quote!(1);
+    let lit = fold.fold_lit(lit);
+    assert!(fold.changed);
+    assert!(matches!(lit, Lit::Int(lit) if lit.base10_digits() == "2"));
+}
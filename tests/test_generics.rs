@@ -8,7 +8,12 @@
 mod macros;
 
 use quote::quote;
-use syn::{DeriveInput, ItemFn, TypeParamBound, WhereClause, WherePredicate};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_quote, BoundLifetimes, DeriveInput, GenericParam, Generics, ItemFn, Lifetime, Path,
+    PredicateType, TraitBound, TraitBoundModifier, Type, TypeParam, TypeParamBound, Variance,
+    WhereClause, WherePredicate,
+};
 
 #[test]
 fn test_split_for_impl() {
@@ -121,6 +126,25 @@ fn test_split_for_impl() {
         Test::<'a, 'b, T>
     };
     assert_eq!(generated.to_string(), expected.to_string());
+
+    let trait_: Path = parse_quote!(MyTrait);
+    let self_ty: Type = parse_quote!(Test #ty_generics);
+    let generated = generics.impl_header(Some(&trait_), &self_ty);
+    let expected = quote! {
+        impl<'a, 'b: 'a, #[may_dangle] T: 'a> MyTrait
+        for Test<'a, 'b, T>
+        where
+            T: Debug
+    };
+    assert_eq!(generated.to_string(), expected.to_string());
+
+    let generated = generics.impl_header(None, &self_ty);
+    let expected = quote! {
+        impl<'a, 'b: 'a, #[may_dangle] T: 'a> Test<'a, 'b, T>
+        where
+            T: Debug
+    };
+    assert_eq!(generated.to_string(), expected.to_string());
 }
 
 #[test]
@@ -167,6 +191,34 @@ fn test_ty_param_bound() {
     "###);
 }
 
+#[test]
+fn test_tilde_const_bound() {
+    let tokens = quote!(~const Default);
+    snapshot!(tokens as TypeParamBound, @r###"
+    TypeParamBound::Trait(TraitBound {
+        modifier: TraitBoundModifier::MaybeConst,
+        path: Path {
+            segments: [
+                PathSegment {
+                    ident: "Default",
+                },
+            ],
+        },
+    })
+    "###);
+
+    let where_clause: WhereClause = parse_quote!(where T: ~const Default + ?Sized);
+    assert_eq!(
+        quote!(#where_clause).to_string(),
+        "where T : ~ const Default + ? Sized",
+    );
+    let reparsed: WhereClause = syn::parse2(quote!(#where_clause)).unwrap();
+    assert_eq!(reparsed, where_clause);
+
+    let bound: TypeParamBound = syn::parse_str("(~const Default)").unwrap();
+    assert_eq!(quote!(#bound).to_string(), "(~ const Default)");
+}
+
 #[test]
 fn test_fn_precedence_in_where_clause() {
     // This should parse as two separate bounds, `FnOnce() -> i32` and `Send` - not
@@ -274,3 +326,256 @@ fn test_where_clause_at_end_of_input() {
 
     assert_eq!(input.predicates.len(), 0);
 }
+
+#[test]
+fn test_unused_params_in() {
+    fn unused(generics: &Generics, tys: &[&Type]) -> Vec<String> {
+        generics
+            .unused_params_in(tys)
+            .into_iter()
+            .map(|param| match param {
+                GenericParam::Lifetime(param) => param.lifetime.to_string(),
+                GenericParam::Type(param) => param.ident.to_string(),
+                GenericParam::Const(param) => param.ident.to_string(),
+            })
+            .collect()
+    }
+
+    let generics: Generics = parse_quote!(<'a, 'b, T, U: 'b, const N: usize>);
+    assert_eq!(unused(&generics, &[]), ["'a", "'b", "T", "U", "N"]);
+
+    let a: Type = parse_quote!(Cow<'a, [T]>);
+    let b: Type = parse_quote!(fn(&'static U) -> Foo<{ N + 1 }>);
+    assert_eq!(unused(&generics, &[&a]), ["'b", "U", "N"]);
+    assert_eq!(unused(&generics, &[&a, &b]), ["'b"]);
+
+    // A lifetime and a type parameter with the same name are distinct.
+    let generics: Generics = parse_quote!(<'T, T>);
+    let ty: Type = parse_quote!(Vec<T>);
+    assert_eq!(unused(&generics, &[&ty]), ["'T"]);
+
+    // An associated type named like a parameter is not a use of it.
+    let generics: Generics = parse_quote!(<X, T>);
+    let ty: Type = parse_quote!(<X as Trait>::T);
+    assert_eq!(unused(&generics, &[&ty]), ["T"]);
+    let ty: Type = parse_quote!(X::T);
+    assert_eq!(unused(&generics, &[&ty]), ["T"]);
+}
+
+#[test]
+fn test_phantom_data_variant() {
+    let generics: Generics = parse_quote!(<'a, T: Clone, const N: usize, U = ()>);
+
+    let marker = generics.phantom_data_variant(Variance::Covariant);
+    let expected: Type =
+        parse_quote!(::core::marker::PhantomData<(*const &'a (), *const T, *const U)>);
+    assert_eq!(marker, expected);
+
+    let marker = generics.phantom_data_variant(Variance::Contravariant);
+    let expected: Type = parse_quote!(::core::marker::PhantomData<(fn(&'a ()), fn(T), fn(U))>);
+    assert_eq!(marker, expected);
+
+    // A single parameter still produces a tuple.
+    let generics: Generics = parse_quote!(<T>);
+    let marker = generics.phantom_data_variant(Variance::Invariant);
+    assert_eq!(
+        quote!(#marker).to_string(),
+        ":: core :: marker :: PhantomData < (* mut T ,) >",
+    );
+
+    let generics = Generics::default();
+    let marker = generics.phantom_data_variant(Variance::Invariant);
+    let expected: Type = parse_quote!(::core::marker::PhantomData<()>);
+    assert_eq!(marker, expected);
+}
+
+#[test]
+fn test_merge() {
+    let mut generics: Generics = parse_quote!(<T, const N: usize>);
+    let mut other: Generics = parse_quote!(<'a, U: 'a, 'b>);
+    other.where_clause = Some(parse_quote!(where U: Clone,));
+    generics.merge(&other);
+
+    let expected: Generics = parse_quote!(<'a, 'b, T, const N: usize, U: 'a>);
+    assert_eq!(generics.params, expected.params);
+    let where_clause = generics.where_clause.as_ref().unwrap();
+    assert_eq!(quote!(#where_clause).to_string(), "where U : Clone");
+
+    // Merging into empty generics introduces the angle brackets.
+    let mut generics = Generics::default();
+    generics.merge(&parse_quote!(<T>));
+    assert_eq!(quote!(#generics).to_string(), "< T >");
+
+    // Duplicate names are kept as is.
+    let mut generics: Generics = parse_quote!(<T>);
+    generics.merge(&parse_quote!(<T>));
+    assert_eq!(quote!(#generics).to_string(), "< T , T >");
+
+    // Merging empty generics is a no-op.
+    let mut generics = Generics::default();
+    generics.merge(&Generics::default());
+    assert!(generics.lt_token.is_none());
+    assert!(generics.where_clause.is_none());
+}
+
+#[test]
+fn test_intern_bounds() {
+    let mut generics: Generics = parse_quote!(<'a: 'b + 'b, 'b, T: Clone + Send + Clone, U>);
+    let mut other: Generics = parse_quote!(<V: Clone>);
+    other.where_clause = Some(parse_quote! {
+        where
+            T: Clone,
+            U: Send,
+            for<'c> U: Fn(&'c u8),
+            U: Send + Sync,
+            'a: 'b,
+            for<'c> U: Fn(&'c u8),
+    });
+    generics.merge(&other);
+    generics.merge(&other);
+    generics.intern_bounds();
+
+    let expected = quote! {
+        <'a: 'b, 'b, T: Clone + Send, U, V: Clone, V: Clone>
+        where
+            T: Clone,
+            U: Send + Sync,
+            for<'c> U: Fn(&'c u8),
+            'a: 'b
+    };
+    let where_clause = &generics.where_clause;
+    assert_eq!(
+        quote!(#generics #where_clause).to_string(),
+        expected.to_string(),
+    );
+
+    // Generics without a where clause keep not having one.
+    let mut generics: Generics = parse_quote!(<T: Clone>);
+    generics.intern_bounds();
+    assert!(generics.where_clause.is_none());
+}
+
+#[test]
+fn test_predicates_for() {
+    let mut where_clause: WhereClause = parse_quote! {
+        where
+            T: Clone,
+            (T): Debug,
+            Vec<T>: Send,
+            'a: 'b,
+            for<'x> T: Fn(&'x str),
+    };
+
+    let ty: Type = parse_quote!(T);
+    let bounds: Vec<String> = where_clause
+        .predicates_for(&ty)
+        .map(|predicate| {
+            let bounds = &predicate.bounds;
+            quote!(#bounds).to_string()
+        })
+        .collect();
+    assert_eq!(bounds, ["Clone", "Debug", "Fn (& 'x str)"]);
+
+    let ty: Type = parse_quote!(Vec<T>);
+    assert_eq!(where_clause.predicates_for(&ty).count(), 1);
+    let ty: Type = parse_quote!(U);
+    assert_eq!(where_clause.predicates_for(&ty).count(), 0);
+
+    let ty: Type = parse_quote!(Vec<T>);
+    for predicate in where_clause.predicates_for_mut(&ty) {
+        predicate.bounds.push(parse_quote!(Sync));
+    }
+    let expected: WhereClause = parse_quote! {
+        where
+            T: Clone,
+            (T): Debug,
+            Vec<T>: Send + Sync,
+            'a: 'b,
+            for<'x> T: Fn(&'x str),
+    };
+    assert_eq!(where_clause, expected);
+}
+
+#[test]
+fn test_type_param_add_bound() {
+    let mut param: TypeParam = parse_quote!(T);
+    assert!(param.colon_token.is_none());
+    param.add_bound(parse_quote!(Clone));
+    assert!(param.colon_token.is_some());
+    assert_eq!(quote!(#param).to_string(), "T : Clone");
+
+    let mut param: TypeParam = parse_quote!(T: Debug);
+    param.add_bound(parse_quote!(Clone));
+    param.add_bound(parse_quote!('a));
+    assert_eq!(quote!(#param).to_string(), "T : Debug + Clone + 'a");
+
+    let path: Path = parse_quote!(Clone);
+    assert!(param.has_bound(&path));
+    assert!(!param.has_bound(&parse_quote!(Copy)));
+    assert!(!param.has_bound(&parse_quote!(std::clone::Clone)));
+
+    let param: TypeParam = parse_quote!(T: ?Sized + Into<String> + ::core::marker::Send);
+    assert!(!param.has_bound(&parse_quote!(Sized)));
+    assert!(param.has_bound(&parse_quote!(Into)));
+    assert!(param.has_bound(&parse_quote!(::core::marker::Send)));
+    assert!(!param.has_bound(&parse_quote!(core::marker::Send)));
+}
+
+#[test]
+fn test_bound_lifetimes_new() {
+    let where_clause: WhereClause = parse_quote!(where F: for<'a> Fn(&'a str));
+    let predicate = match &where_clause.predicates[0] {
+        WherePredicate::Type(predicate) => predicate,
+        _ => panic!("expected type predicate"),
+    };
+    let bound = match &predicate.bounds[0] {
+        TypeParamBound::Trait(bound) => bound,
+        _ => panic!("expected trait bound"),
+    };
+    let lifetime: Lifetime = parse_quote!('a);
+    assert_eq!(
+        bound.lifetimes(),
+        Some(&BoundLifetimes::new(vec![lifetime]))
+    );
+
+    let lifetime: Lifetime = parse_quote!('a);
+    let built = WhereClause {
+        where_token: Default::default(),
+        predicates: Punctuated::from_iter(vec![WherePredicate::Type(PredicateType {
+            lifetimes: None,
+            bounded_ty: parse_quote!(F),
+            colon_token: Default::default(),
+            bounds: Punctuated::from_iter(vec![TypeParamBound::Trait(TraitBound {
+                paren_token: None,
+                modifier: TraitBoundModifier::None,
+                lifetimes: Some(BoundLifetimes::new(vec![lifetime])),
+                path: bound.path.clone(),
+            })]),
+        })]),
+    };
+    assert_eq!(built, where_clause);
+    assert_eq!(
+        quote!(#built).to_string(),
+        "where F : for < 'a > Fn (& 'a str)",
+    );
+
+    let bound: TraitBound = parse_quote!(Clone);
+    assert!(bound.lifetimes().is_none());
+}
+
+#[test]
+fn test_where_predicate_constructors() {
+    let ty: Type = parse_quote!(Foo<T>);
+    let bounds: Vec<TypeParamBound> = vec![parse_quote!(Clone), parse_quote!(Send)];
+    let predicate = WherePredicate::bound(ty, bounds);
+    assert_eq!(quote!(#predicate).to_string(), "Foo < T > : Clone + Send");
+    let expected: WherePredicate = parse_quote!(Foo<T>: Clone + Send);
+    assert_eq!(predicate, expected);
+
+    let a: Lifetime = parse_quote!('a);
+    let bounds: Vec<Lifetime> = vec![parse_quote!('b), parse_quote!('c)];
+    let predicate = WherePredicate::outlives(a, bounds);
+    assert_eq!(quote!(#predicate).to_string(), "'a : 'b + 'c");
+    let expected: WherePredicate = parse_quote!('a: 'b + 'c);
+    assert_eq!(predicate, expected);
+}
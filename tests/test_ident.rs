@@ -83,3 +83,32 @@ fn ident_new_number() {
 fn ident_new_invalid() {
     new("a#");
 }
+
+#[test]
+fn ident_prepend_append() {
+    use syn::ext::IdentExt;
+
+    let ident = parse("  field").unwrap();
+    let getter = ident.prepend("get_");
+    let setter = ident.append("_mut");
+    assert_eq!(getter, "get_field");
+    assert_eq!(setter, "field_mut");
+    let span = format!("{:?}", ident.span());
+    assert_eq!(format!("{:?}", getter.span()), span);
+    assert_eq!(format!("{:?}", setter.span()), span);
+
+    let raw = parse("r#type").unwrap();
+    assert_eq!(raw.prepend("get_"), "r#get_type");
+    assert_eq!(raw.append("_id"), "r#type_id");
+    assert_eq!(parse("r#sel").unwrap().append("f"), "self");
+}
+
+#[test]
+fn ident_is_raw() {
+    use syn::ext::IdentExt;
+
+    assert!(parse("r#type").unwrap().is_raw());
+    assert!(parse("r#x").unwrap().is_raw());
+    assert!(!parse("x").unwrap().is_raw());
+    assert!(!parse("r#type").unwrap().unraw().is_raw());
+}
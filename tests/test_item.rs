@@ -324,3 +324,171 @@ fn test_impl_trait_trailing_plus() {
     }
     "###);
 }
+
+#[test]
+fn test_item_ident() {
+    let items: syn::File = syn::parse_quote! {
+        struct S;
+        fn f() {}
+        extern crate alloc as std_alloc;
+        macro_rules! m { () => {} }
+        println!();
+        impl S {}
+        use std::mem;
+    };
+
+    let idents: Vec<_> = items
+        .items
+        .iter()
+        .map(|item| item.ident().map(Ident::to_string))
+        .collect();
+    assert_eq!(
+        idents,
+        [
+            Some("S".to_owned()),
+            Some("f".to_owned()),
+            Some("std_alloc".to_owned()),
+            Some("m".to_owned()),
+            None,
+            None,
+            None,
+        ],
+    );
+}
+
+#[test]
+fn test_item_attrs() {
+    let mut item: Item = syn::parse_quote! {
+        #[derive(Debug)]
+        #[repr(C)]
+        struct S;
+    };
+    assert_eq!(item.attrs().len(), 2);
+
+    item.attrs_mut().unwrap().retain(|attr| attr.path().is_ident("repr"));
+    assert_eq!(quote!(#item).to_string(), "# [repr (C)] struct S ;");
+
+    let mut item = Item::Verbatim(quote!(#[attr] impl ! {}));
+    assert!(item.attrs().is_empty());
+    assert!(item.attrs_mut().is_none());
+}
+
+#[test]
+fn test_without_doc_attrs() {
+    let method: syn::TraitItemFn = syn::parse_quote! {
+        /// Returns the length.
+        #[doc = "More docs."]
+        #[doc(alias = "size")]
+        #[must_use]
+        fn len(&self) -> usize;
+    };
+    let method = method.without_doc_attrs();
+    let attrs = &method.attrs;
+    assert_eq!(
+        quote!(#(#attrs)*).to_string(),
+        "# [doc (alias = \"size\")] # [must_use]",
+    );
+
+    let method: syn::ImplItemFn = syn::parse_quote! {
+        /// Returns the length.
+        #[inline]
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    };
+    let method = method.without_doc_attrs();
+    assert_eq!(method.attrs.len(), 1);
+    assert!(method.attrs[0].path().is_ident("inline"));
+}
+
+#[test]
+fn test_wrap_return() {
+    let mut sig: syn::Signature = syn::parse_quote!(fn f() -> Vec<u8>);
+    sig.wrap_return(|ty| syn::parse_quote!(Result<#ty, Error>));
+    let expected: syn::Signature = syn::parse_quote!(fn f() -> Result<Vec<u8>, Error>);
+    assert_eq!(sig, expected);
+
+    let mut sig: syn::Signature = syn::parse_quote!(async fn f(&self));
+    sig.wrap_return(|ty| syn::parse_quote!(Option<#ty>));
+    let expected: syn::Signature = syn::parse_quote!(async fn f(&self) -> Option<()>);
+    assert_eq!(sig, expected);
+}
+
+#[test]
+fn test_take_inner_attrs() {
+    let mut item: syn::ItemMod = syn::parse_quote! {
+        #[cfg(test)]
+        mod tests {
+            //! Tests.
+            #![allow(dead_code)]
+
+            fn f() {}
+        }
+    };
+
+    let inner = item.take_inner_attrs();
+    let inner = quote!(#(#inner)*);
+    let expected = quote! {
+        #![doc = r" Tests."]
+        #![allow(dead_code)]
+    };
+    assert_eq!(inner.to_string(), expected.to_string());
+
+    let expected: syn::ItemMod = syn::parse_quote! {
+        #[cfg(test)]
+        mod tests {
+            fn f() {}
+        }
+    };
+    assert_eq!(item, expected);
+    assert!(item.take_inner_attrs().is_empty());
+}
+
+#[test]
+fn test_arg_types() {
+    let sig: syn::Signature = syn::parse_quote!(fn f(self: Box<Self>, a: u8, (b, c): (i32, i32)));
+    let arg_types: Vec<&syn::Type> = sig.arg_types().collect();
+    let expected: [syn::Type; 2] = [syn::parse_quote!(u8), syn::parse_quote!((i32, i32))];
+    assert_eq!(arg_types, expected.iter().collect::<Vec<_>>());
+
+    let sig: syn::Signature = syn::parse_quote!(fn f(&self));
+    assert_eq!(sig.arg_types().count(), 0);
+}
+
+#[test]
+fn test_fn_param_attrs() {
+    let tokens = quote!(fn f(#[a] self, #[b] #[c] x: u8, #[d] (y, z): (u8, u8)) {});
+    let item: syn::ItemFn = syn::parse2(tokens.clone()).unwrap();
+    assert_eq!(quote!(#item).to_string(), tokens.to_string());
+
+    let attrs: Vec<usize> = item
+        .sig
+        .inputs
+        .iter()
+        .map(|arg| match arg {
+            syn::FnArg::Receiver(receiver) => receiver.attrs.len(),
+            syn::FnArg::Typed(pat) => pat.attrs.len(),
+        })
+        .collect();
+    assert_eq!(attrs, [1, 2, 1]);
+}
+
+#[test]
+fn test_signature_qualifiers() {
+    let sig: syn::Signature = syn::parse_quote!(const async unsafe extern fn f(a: u8, ...));
+    assert!(sig.is_const());
+    assert!(sig.is_async());
+    assert!(sig.is_unsafe());
+    assert!(sig.is_variadic());
+    assert_eq!(sig.abi_name().as_deref(), Some("C"));
+
+    let sig: syn::Signature = syn::parse_quote!(extern "Rust" fn f(a: u8));
+    assert!(!sig.is_const());
+    assert!(!sig.is_async());
+    assert!(!sig.is_unsafe());
+    assert!(!sig.is_variadic());
+    assert_eq!(sig.abi_name().as_deref(), Some("Rust"));
+
+    let sig: syn::Signature = syn::parse_quote!(fn f());
+    assert_eq!(sig.abi_name(), None);
+}
@@ -50,6 +50,22 @@ fn iter() {
     assert_eq!(p.into_iter().next_back(), Some(4));
 }
 
+#[test]
+fn puncts() {
+    let p: Punctuated<_, Token![,]> = punctuated!(2, 3, 4);
+    check_exact_size_iterator!(p.puncts());
+    assert_eq!(p.puncts().count(), 2);
+
+    let mut p: Punctuated<_, Token![,]> = punctuated!(2, 3, 4);
+    p.push_punct(<Token![,]>::default());
+    check_exact_size_iterator!(p.puncts());
+    assert_eq!(p.puncts().count(), 3);
+    assert!(p.puncts().next_back().is_some());
+
+    let p: Punctuated<i32, Token![,]> = Punctuated::new();
+    assert!(p.puncts().next().is_none());
+}
+
 #[test]
 fn may_dangle() {
     let p: Punctuated<_, Token![,]> = punctuated!(2, 3, 4);
@@ -68,3 +84,26 @@ fn may_dangle() {
         }
     }
 }
+
+#[test]
+fn from_values_and_puncts() {
+    let commas = vec![<Token![,]>::default(), <Token![,]>::default()];
+    let p: Punctuated<_, Token![,]> = Punctuated::from_values_and_puncts(vec![2, 3, 4], commas);
+    assert_eq!(p.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    assert!(!p.trailing_punct());
+
+    let commas = vec![<Token![,]>::default(), <Token![,]>::default()];
+    let p: Punctuated<_, Token![,]> = Punctuated::from_values_and_puncts(vec![2, 3], commas);
+    assert_eq!(p.len(), 2);
+    assert!(p.trailing_punct());
+
+    let p: Punctuated<i32, Token![,]> = Punctuated::from_values_and_puncts(Vec::new(), Vec::new());
+    assert!(p.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "expected 2 or 3 punctuation tokens for 3 values, got 1")]
+fn from_values_and_puncts_mismatched() {
+    let commas = vec![<Token![,]>::default()];
+    let _: Punctuated<_, Token![,]> = Punctuated::from_values_and_puncts(vec![2, 3, 4], commas);
+}
@@ -267,3 +267,19 @@ fn test_error() {
     let err = syn::parse_str::<LitStr>("5").unwrap_err();
     assert_eq!("expected string literal", err.to_string());
 }
+
+#[test]
+fn test_bool_and_char_value() {
+    fn lit(s: &str) -> Lit {
+        syn::parse_str(s).unwrap()
+    }
+
+    assert_eq!(lit("true").bool_value(), Some(true));
+    assert_eq!(lit("false").bool_value(), Some(false));
+    assert_eq!(lit("'x'").bool_value(), None);
+
+    assert_eq!(lit("','").char_value(), Some(','));
+    assert_eq!(lit("'\\u{1F980}'").char_value(), Some('\u{1F980}'));
+    assert_eq!(lit("b','").char_value(), None);
+    assert_eq!(lit("\",\"").char_value(), None);
+}
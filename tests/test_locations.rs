@@ -0,0 +1,120 @@
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Ident, Item, Receiver};
+
+#[test]
+fn test_locations() {
+    let content = "\u{feff}#!/usr/bin/env run-cargo-script\nconst É: &str = \"ü\";\n\tfn f() {}\n";
+    let (file, locations) = syn::parse_str_with_locations(content).unwrap();
+    assert_eq!(
+        file.shebang.as_deref(),
+        Some("#!/usr/bin/env run-cargo-script")
+    );
+
+    let (item_const, item_fn) = match file.items.as_slice() {
+        [Item::Const(item_const), Item::Fn(item_fn)] => (item_const, item_fn),
+        _ => panic!(),
+    };
+
+    let start = locations.lookup(item_const.ident.span()).unwrap();
+    assert_eq!((start.line, start.column), (2, 6));
+    let range = locations.byte_range(item_const.ident.span()).unwrap();
+    assert_eq!(&content[range], "É");
+    let range = locations.byte_range(item_const.expr.span()).unwrap();
+    assert_eq!(&content[range], "\"ü\"");
+
+    let start = locations.lookup(item_fn.span()).unwrap();
+    assert_eq!((start.line, start.column), (3, 1));
+    let range = locations.byte_range(item_fn.span()).unwrap();
+    assert_eq!(&content[range], "fn f() {}");
+
+    assert!(locations.byte_range(Span::call_site()).is_none());
+}
+
+#[test]
+fn test_locations_of_idents() {
+    struct CheckIdents<'a> {
+        content: &'a str,
+        locations: &'a syn::LocationMap,
+        count: usize,
+    }
+
+    impl<'ast> Visit<'ast> for CheckIdents<'_> {
+        fn visit_ident(&mut self, ident: &'ast Ident) {
+            let range = self.locations.byte_range(ident.span()).unwrap();
+            assert_eq!(self.content[range], ident.to_string());
+            self.count += 1;
+            visit::visit_ident(self, ident);
+        }
+
+        // Doc comments are spanned as a whole.
+        fn visit_attribute(&mut self, _attr: &'ast Attribute) {}
+
+        // The type of a shorthand receiver is reconstructed.
+        fn visit_receiver(&mut self, _receiver: &'ast Receiver) {}
+    }
+
+    let content = include_str!("../src/locations.rs");
+    let (file, locations) = syn::parse_str_with_locations(content).unwrap();
+    let mut check = CheckIdents {
+        content,
+        locations: &locations,
+        count: 0,
+    };
+    check.visit_file(&file);
+    assert!(check.count > 100);
+}
+
+#[test]
+fn test_locations_of_delimiters() {
+    let content = "fn f(a: u8) { [a, 1] }";
+    let (file, locations) = syn::parse_str_with_locations(content).unwrap();
+    let item_fn = match file.items.as_slice() {
+        [Item::Fn(item_fn)] => item_fn,
+        _ => panic!(),
+    };
+    let text = |span: Span| &content[locations.byte_range(span).unwrap()];
+
+    let paren = item_fn.sig.paren_token;
+    assert_eq!(text(paren.span.join()), "(a: u8)");
+    assert_eq!(text(paren.open()), "(");
+    assert_eq!(text(paren.close()), ")");
+
+    let brace = item_fn.block.brace_token;
+    assert_eq!(text(brace.span.join()), "{ [a, 1] }");
+    assert_eq!(text(brace.open()), "{");
+    assert_eq!(text(brace.close()), "}");
+
+    let bracket = match &item_fn.block.stmts[..] {
+        [syn::Stmt::Expr(syn::Expr::Array(array), None)] => array.bracket_token,
+        _ => panic!(),
+    };
+    assert_eq!(text(bracket.open()), "[");
+    assert_eq!(text(bracket.close()), "]");
+}
+
+#[test]
+fn test_punctuated_respan() {
+    let content = "fn f(a: u8, b: u8,) {}";
+    let (file, locations) = syn::parse_str_with_locations(content).unwrap();
+    let mut item_fn = match file.items.into_iter().next() {
+        Some(Item::Fn(item_fn)) => item_fn,
+        _ => panic!(),
+    };
+    let text = |span: Span| &content[locations.byte_range(span).unwrap()];
+
+    let target = item_fn.sig.ident.span();
+    item_fn.sig.inputs.respan(target);
+
+    for punct in item_fn.sig.inputs.puncts() {
+        assert_eq!(text(punct.span), "f");
+    }
+    let args: Vec<&str> = item_fn
+        .sig
+        .inputs
+        .iter()
+        .map(|arg| text(arg.span()))
+        .collect();
+    assert_eq!(args, ["a: u8", "b: u8"]);
+}
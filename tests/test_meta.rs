@@ -7,7 +7,9 @@
 #[macro_use]
 mod macros;
 
-use syn::{Meta, MetaList, MetaNameValue};
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{parse_quote, Meta, MetaList, MetaNameValue};
 
 #[test]
 fn test_parse_meta_item_word() {
@@ -151,3 +153,23 @@ fn test_parse_path() {
     }
     "###);
 }
+
+#[test]
+fn test_meta_constructors() {
+    let derive = Ident::new("derive", Span::call_site());
+    let meta = Meta::list(derive.into(), quote!(Clone));
+    let expected: Meta = parse_quote!(derive(Clone));
+    assert_eq!(meta, expected);
+    assert_eq!(
+        quote!(#[#meta]).to_string(),
+        quote!(#[derive(Clone)]).to_string()
+    );
+
+    let meta = Meta::path_from(Ident::new("test", Span::call_site()));
+    let expected: Meta = parse_quote!(test);
+    assert_eq!(meta, expected);
+
+    let meta = Meta::name_value(parse_quote!(path), parse_quote!("sys/windows.rs"));
+    let expected: Meta = parse_quote!(path = "sys/windows.rs");
+    assert_eq!(meta, expected);
+}
@@ -1,8 +1,9 @@
 #![allow(clippy::non_ascii_literal)]
 
 use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+use quote::quote;
 use syn::parse::{discouraged::Speculative, Parse, ParseStream, Parser, Result};
-use syn::{parenthesized, Token};
+use syn::{parenthesized, Ident, MacroDelimiter, Token};
 
 #[test]
 #[should_panic(expected = "Fork was not derived from the advancing parse stream")]
@@ -89,3 +90,151 @@ fn trailing_empty_none_group() {
 
     parse.parse2(tokens).unwrap();
 }
+
+#[test]
+fn remaining_counts_top_level_tokens() {
+    (|input: ParseStream| {
+        assert_eq!(input.remaining(), 6);
+        input.parse::<Token![fn]>()?;
+        assert_eq!(input.remaining(), 5);
+        let content;
+        parenthesized!(content in input);
+        assert_eq!(content.remaining(), 3);
+        content.parse::<TokenStream>()?;
+        assert_eq!(content.remaining(), 0);
+        input.parse::<TokenStream>()?;
+        assert_eq!(input.remaining(), 0);
+        Ok(())
+    })
+    .parse_str("fn (x, 'a) 'b -> {}")
+    .unwrap();
+}
+
+#[test]
+fn parse_macro_delimiter() {
+    let parse = |input: ParseStream| {
+        let mut delimiters = Vec::new();
+        while !input.is_empty() {
+            let (delimiter, _span, content) = input.parse_macro_delimiter()?;
+            let ident: Ident = content.parse()?;
+            delimiters.push((delimiter, ident.to_string()));
+        }
+        Ok(delimiters)
+    };
+
+    let delimiters = parse.parse_str("(a) [b] {c}").unwrap();
+    let delimiters: Vec<(&str, &str)> = delimiters
+        .iter()
+        .map(|(delimiter, ident)| {
+            let delimiter = match delimiter {
+                MacroDelimiter::Paren(_) => "()",
+                MacroDelimiter::Bracket(_) => "[]",
+                MacroDelimiter::Brace(_) => "{}",
+            };
+            (delimiter, ident.as_str())
+        })
+        .collect();
+    assert_eq!(delimiters, [("()", "a"), ("[]", "b"), ("{}", "c")]);
+
+    // Invisible groups are looked through.
+    let tokens = TokenStream::from_iter(vec![TokenTree::Group(Group::new(
+        Delimiter::None,
+        quote!([x]),
+    ))]);
+    assert_eq!(parse.parse2(tokens).unwrap().len(), 1);
+
+    let error = parse.parse_str("x").unwrap_err();
+    assert_eq!(error.to_string(), "expected delimiter");
+
+    // Unparsed content of the group is an error.
+    let error = parse.parse_str("(a b)").unwrap_err();
+    assert_eq!(error.to_string(), "unexpected token");
+}
+
+#[test]
+fn peek_keyword() {
+    let peek = |keyword: &'static str| {
+        move |input: ParseStream| {
+            let peeked = input.peek_keyword(keyword);
+            input.parse::<TokenStream>()?;
+            Ok(peeked)
+        }
+    };
+
+    assert!(peek("default").parse_str("default T").unwrap());
+    assert!(peek("fn").parse_str("fn f()").unwrap());
+    assert!(!peek("default").parse_str("defaults").unwrap());
+    assert!(!peek("default").parse_str("r#default").unwrap());
+    assert!(peek("r#default").parse_str("r#default").unwrap());
+    assert!(!peek("default").parse_str("").unwrap());
+    assert!(!peek("default").parse_str("'default").unwrap());
+}
+
+#[test]
+fn checkpoint_restore() {
+    let parse = |input: ParseStream| {
+        let checkpoint = input.checkpoint();
+        let first: Ident = input.parse()?;
+        input.parse::<Token![+]>()?;
+        input.restore(&checkpoint);
+        let again: Ident = input.parse()?;
+        assert_eq!(first, again);
+        input.restore(&checkpoint);
+        input.parse::<TokenStream>()
+    };
+    let rest = parse.parse_str("a + b").unwrap();
+    assert_eq!(rest.to_string(), "a + b");
+
+    // Tokens left over in a group by an abandoned alternative are not an
+    // error once the stream is restored.
+    let parse = |input: ParseStream| {
+        let checkpoint = input.checkpoint();
+        {
+            let content;
+            parenthesized!(content in input);
+            content.parse::<Ident>()?;
+        }
+        input.restore(&checkpoint);
+        input.parse::<TokenStream>()
+    };
+    let rest = parse.parse_str("(a b)").unwrap();
+    assert_eq!(rest.to_string(), "(a b)");
+}
+
+#[test]
+#[should_panic(expected = "Checkpoint was not taken from the restored parse stream")]
+fn smuggled_checkpoint_into_brackets() {
+    struct BreakRules;
+    impl Parse for BreakRules {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let a;
+            parenthesized!(a in input);
+            input.restore(&a.checkpoint());
+            Ok(Self)
+        }
+    }
+
+    syn::parse_str::<BreakRules>("()").unwrap();
+}
+
+#[test]
+fn lookahead_hint() {
+    let parse = |input: ParseStream| {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![,]) || lookahead.peek(Token![;]) {
+            return input.parse::<TokenStream>();
+        }
+        lookahead.hint("end of group");
+        Err(lookahead.error())
+    };
+    let error = parse.parse_str("x").unwrap_err();
+    assert_eq!(error.to_string(), "expected one of: `,`, `;`, end of group",);
+
+    let parse = |input: ParseStream| -> Result<()> {
+        let lookahead = input.lookahead1();
+        lookahead.hint("a number");
+        Err(lookahead.error())
+    };
+    let error = parse.parse_str("x").unwrap_err();
+    assert_eq!(error.to_string(), "expected a number");
+}
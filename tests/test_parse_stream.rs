@@ -1,6 +1,8 @@
+use proc_macro2::Span;
+use quote::quote;
 use syn::ext::IdentExt;
-use syn::parse::ParseStream;
-use syn::{Ident, Token};
+use syn::parse::{ParseStream, Parser};
+use syn::{Expr, Ident, Token, Type};
 
 #[test]
 fn test_peek() {
@@ -10,3 +12,49 @@ fn test_peek() {
         _ = input.peek(Token![::]);
     };
 }
+
+#[test]
+fn test_parse_partial() {
+    let (ty, rest) = syn::parse_partial::<Type>(quote!(&'a str, more tokens)).unwrap();
+    assert_eq!(quote!(#ty).to_string(), "& 'a str");
+    assert_eq!(rest.to_string(), ", more tokens");
+
+    let (expr, rest) = syn::parse_partial::<Expr>(quote!(a + b)).unwrap();
+    assert_eq!(quote!(#expr).to_string(), "a + b");
+    assert!(rest.is_empty());
+
+    // Errors from parsing the leading node are still reported.
+    syn::parse_partial::<Type>(quote!(=> x)).unwrap_err();
+
+    // Tokens left over within a group are an error.
+    syn::parse_partial::<Type>(quote!([u8; 4 4] rest)).unwrap_err();
+}
+
+#[test]
+fn test_span() {
+    let parse = |input: ParseStream| {
+        let content;
+        let a_span = input.span();
+        let a: Ident = input.parse()?;
+        assert_eq!(format!("{:?}", a_span), format!("{:?}", a.span()));
+
+        let paren_span = input.span();
+        let paren = syn::parenthesized!(content in input);
+        assert_eq!(
+            format!("{:?}", paren_span),
+            format!("{:?}", paren.span.open())
+        );
+
+        content.parse::<Ident>()?;
+        assert_eq!(
+            format!("{:?}", content.span()),
+            format!("{:?}", paren.span.close()),
+        );
+        assert_eq!(
+            format!("{:?}", input.span()),
+            format!("{:?}", Span::call_site()),
+        );
+        Ok(())
+    };
+    parse.parse_str("a (b)").unwrap();
+}
@@ -95,3 +95,71 @@ fn test_ranges() {
     Pat::parse_single.parse_str("[_, (..=hi), _]").unwrap();
     Pat::parse_single.parse_str("[_, lo..=hi, _]").unwrap();
 }
+
+#[test]
+fn test_reject_complex() {
+    for simple in ["x", "mut x", "ref x", "ref mut x"] {
+        let pat = Pat::parse_single.parse_str(simple).unwrap();
+        assert_eq!(pat.reject_complex().unwrap(), "x");
+    }
+
+    for complex in ["x @ Some(_)", "(a, b)", "_", "S { x }", "&x"] {
+        let pat = Pat::parse_single.parse_str(complex).unwrap();
+        pat.reject_complex().unwrap_err();
+    }
+}
+
+#[test]
+fn test_rest_position() {
+    for (pat, expected) in [
+        ("[first, .., last]", Some(1)),
+        ("[a, b, rest @ ..]", Some(2)),
+        ("[.., z]", Some(0)),
+        ("[a, b]", None),
+        ("[a, b @ _]", None),
+        ("[]", None),
+    ] {
+        let pat = match Pat::parse_single.parse_str(pat).unwrap() {
+            Pat::Slice(pat) => pat,
+            value => panic!("expected PatSlice, got {:?}", value),
+        };
+        assert_eq!(pat.rest_position(), expected);
+    }
+}
+
+#[test]
+fn test_pat_const() {
+    let pat = Pat::parse_single.parse_str("const { 1 + 1 }").unwrap();
+    assert_eq!(quote!(#pat).to_string(), "const { 1 + 1 }");
+    snapshot!(pat, @r###"
+    Pat::Const(ExprConst {
+        block: Block {
+            stmts: [
+                Stmt::Expr(
+                    Expr::Binary {
+                        left: Expr::Lit {
+                            lit: 1,
+                        },
+                        op: BinOp::Add,
+                        right: Expr::Lit {
+                            lit: 1,
+                        },
+                    },
+                    None,
+                ),
+            ],
+        },
+    })
+    "###);
+
+    let pat = Pat::parse_single
+        .parse_str("const { A }..=const { B }")
+        .unwrap();
+    match pat {
+        Pat::Range(pat) => {
+            assert!(matches!(pat.start.as_deref(), Some(syn::Expr::Const(_))));
+            assert!(matches!(pat.end.as_deref(), Some(syn::Expr::Const(_))));
+        }
+        value => panic!("expected PatRange, got {:?}", value),
+    }
+}
@@ -5,7 +5,7 @@ mod macros;
 
 use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
-use syn::{parse_quote, Expr, Type, TypePath};
+use syn::{parse_quote, Expr, PathArguments, Type, TypePath};
 
 #[test]
 fn parse_interpolated_leading_component() {
@@ -121,3 +121,51 @@ fn parse_parenthesized_path_arguments_with_disambiguator() {
     }
     "###);
 }
+
+#[test]
+fn generic_argument_accessors() {
+    let ty: TypePath = parse_quote!(Foo<'a, T, { N + 1 }, Item = U>);
+    let args = match &ty.path.segments[0].arguments {
+        PathArguments::AngleBracketed(args) => &args.args,
+        _ => panic!("expected angle bracketed arguments"),
+    };
+
+    assert_eq!(args.iter().filter_map(|arg| arg.as_lifetime()).count(), 1);
+    assert_eq!(args.iter().filter_map(|arg| arg.as_type()).count(), 1);
+    assert_eq!(args.iter().filter_map(|arg| arg.as_const()).count(), 1);
+
+    let assoc = args.iter().find_map(|arg| arg.as_assoc_type()).unwrap();
+    assert_eq!(assoc.ident, "Item");
+    assert_eq!(assoc.ty.to_token_stream().to_string(), "U");
+}
+
+#[test]
+fn require_ident() {
+    let content = "type A = serde; type B = serde::Serialize; type C = ::serde; type D = Vec<u8>; type E = Fn(u8) -> Vec<u8>;";
+    let (file, locations) = syn::parse_str_with_locations(content).unwrap();
+    let paths: Vec<&syn::Path> = file
+        .items
+        .iter()
+        .map(|item| match item {
+            syn::Item::Type(item) => match &*item.ty {
+                Type::Path(ty) => &ty.path,
+                _ => panic!(),
+            },
+            _ => panic!(),
+        })
+        .collect();
+
+    assert_eq!(paths[0].require_ident().unwrap(), "serde");
+
+    for (path, text) in paths[1..].iter().zip([
+        "serde::Serialize",
+        "::serde",
+        "Vec<u8>",
+        "Fn(u8) -> Vec<u8>",
+    ]) {
+        let err = path.require_ident().unwrap_err();
+        assert_eq!(err.to_string(), "expected identifier, found path");
+        let range = locations.byte_range(err.span()).unwrap();
+        assert_eq!(&content[range], text);
+    }
+}
@@ -0,0 +1,119 @@
+use proc_macro2::{Ident, Span};
+use syn::rename::{apply_to_field, apply_to_variant};
+
+const RULES: [&str; 8] = [
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+fn new(s: &str) -> Ident {
+    Ident::new(s, Span::call_site())
+}
+
+// The expected names are those produced by serde_derive for the rules in the
+// order of `RULES`.
+
+#[test]
+fn test_variant() {
+    let cases = [
+        (
+            "Outcome",
+            [
+                "outcome", "OUTCOME", "Outcome", "outcome", "outcome", "OUTCOME", "outcome",
+                "OUTCOME",
+            ],
+        ),
+        (
+            "VeryTasty",
+            [
+                "verytasty",
+                "VERYTASTY",
+                "VeryTasty",
+                "veryTasty",
+                "very_tasty",
+                "VERY_TASTY",
+                "very-tasty",
+                "VERY-TASTY",
+            ],
+        ),
+        ("A", ["a", "A", "A", "a", "a", "A", "a", "A"]),
+        (
+            "Z42",
+            ["z42", "Z42", "Z42", "z42", "z42", "Z42", "z42", "Z42"],
+        ),
+        (
+            "HTTPServer",
+            [
+                "httpserver",
+                "HTTPSERVER",
+                "HTTPServer",
+                "hTTPServer",
+                "h_t_t_p_server",
+                "H_T_T_P_SERVER",
+                "h-t-t-p-server",
+                "H-T-T-P-SERVER",
+            ],
+        ),
+    ];
+    for (variant, expected) in cases {
+        for (rule, expected) in RULES.iter().zip(expected) {
+            assert_eq!(apply_to_variant(&new(variant), rule).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn test_field() {
+    let cases = [
+        (
+            "outcome",
+            [
+                "outcome", "OUTCOME", "Outcome", "outcome", "outcome", "OUTCOME", "outcome",
+                "OUTCOME",
+            ],
+        ),
+        (
+            "very_tasty",
+            [
+                "very_tasty",
+                "VERY_TASTY",
+                "VeryTasty",
+                "veryTasty",
+                "very_tasty",
+                "VERY_TASTY",
+                "very-tasty",
+                "VERY-TASTY",
+            ],
+        ),
+        ("a", ["a", "A", "A", "a", "a", "A", "a", "A"]),
+        (
+            "z42",
+            ["z42", "Z42", "Z42", "z42", "z42", "Z42", "z42", "Z42"],
+        ),
+    ];
+    for (field, expected) in cases {
+        for (rule, expected) in RULES.iter().zip(expected) {
+            assert_eq!(apply_to_field(&new(field), rule).unwrap(), expected);
+        }
+    }
+
+    let raw = Ident::new_raw("type", Span::call_site());
+    assert_eq!(apply_to_field(&raw, "UPPERCASE").unwrap(), "TYPE");
+}
+
+#[test]
+fn test_unsupported_rule() {
+    let err = apply_to_field(&new("field"), "Title Case").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unsupported rename_all rule: \"Title Case\""
+    );
+    let err = apply_to_variant(&new("Variant"), "title").unwrap_err();
+    assert_eq!(err.to_string(), "unsupported rename_all rule: \"title\"");
+}
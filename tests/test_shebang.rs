@@ -61,3 +61,69 @@ fn test_comment() {
     }
     "###);
 }
+
+#[test]
+fn test_inner_attribute() {
+    let content = "#!/usr/bin/env rustx\n#![allow(dead_code)]\nfn main() {}";
+    let file = syn::parse_file(content).unwrap();
+    snapshot!(file.clone(), @r###"
+    File {
+        shebang: Some("#!/usr/bin/env rustx"),
+        attrs: [
+            Attribute {
+                style: AttrStyle::Inner,
+                meta: Meta::List {
+                    path: Path {
+                        segments: [
+                            PathSegment {
+                                ident: "allow",
+                            },
+                        ],
+                    },
+                    delimiter: MacroDelimiter::Paren,
+                    tokens: TokenStream(`dead_code`),
+                },
+            },
+        ],
+        items: [
+            Item::Fn {
+                vis: Visibility::Inherited,
+                sig: Signature {
+                    ident: "main",
+                    generics: Generics,
+                    output: ReturnType::Default,
+                },
+                block: Block,
+            },
+        ],
+    }
+    "###);
+
+    let shebang = file.shebang.as_ref().unwrap();
+    let printed = format!("{}\n{}", shebang, quote::quote!(#file));
+    let reparsed = syn::parse_file(&printed).unwrap();
+    assert_eq!(reparsed.shebang, file.shebang);
+    assert_eq!(reparsed, file);
+}
+
+#[test]
+fn test_file_items() {
+    let content = "#!/usr/bin/env rustx\n#![allow(dead_code)]\nfn main() {}\nstruct S;";
+    let mut items = syn::parse_file_items(content);
+    assert_eq!(items.shebang(), Some("#!/usr/bin/env rustx"));
+    assert_eq!(items.attrs().len(), 1);
+    assert!(matches!(items.next(), Some(Ok(syn::Item::Fn(_)))));
+    assert!(matches!(items.next(), Some(Ok(syn::Item::Struct(_)))));
+    assert!(items.next().is_none());
+
+    let content = "fn f() {} fn g struct S;";
+    let mut items = syn::parse_file_items(content);
+    assert!(items.shebang().is_none());
+    assert!(matches!(items.next(), Some(Ok(syn::Item::Fn(_)))));
+    assert!(matches!(items.next(), Some(Err(_))));
+    assert!(items.next().is_none());
+
+    let mut items = syn::parse_file_items("fn f() {} )");
+    assert!(matches!(items.next(), Some(Err(_))));
+    assert!(items.next().is_none());
+}
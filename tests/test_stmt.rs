@@ -9,7 +9,7 @@ mod macros;
 
 use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
 use quote::quote;
-use syn::Stmt;
+use syn::{parse_quote, Block, Stmt};
 
 #[test]
 fn test_raw_operator() {
@@ -19,10 +19,29 @@ fn test_raw_operator() {
     Stmt::Local {
         pat: Pat::Wild,
         init: Some(LocalInit {
-            expr: Expr::Verbatim(`& raw const x`),
+            expr: Expr::RawAddr {
+                mutability: PointerMutability::Const,
+                expr: Expr::Path {
+                    path: Path {
+                        segments: [
+                            PathSegment {
+                                ident: "x",
+                            },
+                        ],
+                    },
+                },
+            },
         }),
     }
     "###);
+
+    let stmt = syn::parse_str::<Stmt>("let _ = &raw mut x;").unwrap();
+    assert_eq!(quote!(#stmt).to_string(), "let _ = & raw mut x ;");
+    let stmt = syn::parse_str::<Stmt>("let _ = &raw const x;").unwrap();
+    assert_eq!(quote!(#stmt).to_string(), "let _ = & raw const x ;");
+
+    let expr: syn::ExprRawAddr = syn::parse_str("&raw mut x").unwrap();
+    assert!(matches!(expr.mutability, syn::PointerMutability::Mut(_)));
 }
 
 #[test]
@@ -231,3 +250,40 @@ fn test_macros() {
     })
     "###);
 }
+
+#[test]
+fn test_wrap_tail() {
+    fn wrap(mut block: Block) -> Block {
+        block.wrap_tail(|expr| parse_quote!(Ok(#expr)));
+        block
+    }
+
+    let expected: Block = parse_quote!({
+        let x = 1;
+        Ok(x)
+    });
+    assert_eq!(
+        wrap(parse_quote!({
+            let x = 1;
+            x
+        })),
+        expected
+    );
+
+    let expected: Block = parse_quote!({ Ok(vec![]) });
+    assert_eq!(wrap(parse_quote!({ vec![] })), expected);
+
+    let expected: Block = parse_quote!({
+        run();
+        Ok(())
+    });
+    assert_eq!(
+        wrap(parse_quote!({
+            run();
+        })),
+        expected
+    );
+
+    let expected: Block = parse_quote!({ Ok(()) });
+    assert_eq!(wrap(parse_quote!({})), expected);
+}
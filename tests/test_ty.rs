@@ -5,7 +5,11 @@ mod macros;
 
 use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::quote;
-use syn::Type;
+use syn::parse::{ParseStream, Parser};
+use syn::{
+    parse_quote, Abi, RefSummary, ReturnType, Token, Type, TypeBareFn, TypeEqOptions, TypePath,
+    TypeTraitObject,
+};
 
 #[test]
 fn test_mut_self() {
@@ -335,3 +339,517 @@ fn test_trailing_plus() {
     }
     "###);
 }
+
+#[test]
+fn test_smart_ptr_inner() {
+    let ty: Type = parse_quote!(Box<T>);
+    let (wrapper, inner) = ty.smart_ptr_inner().unwrap();
+    assert_eq!(wrapper, "Box");
+    assert_eq!(quote!(#inner).to_string(), "T");
+
+    let ty: Type = parse_quote!(std::sync::Arc<Mutex<u8>>);
+    let (wrapper, inner) = ty.smart_ptr_inner().unwrap();
+    assert_eq!(wrapper, "Arc");
+    assert_eq!(quote!(#inner).to_string(), "Mutex < u8 >");
+
+    let ty: Type = parse_quote!(Cow<'a, [u8]>);
+    let (wrapper, inner) = ty.smart_ptr_inner().unwrap();
+    assert_eq!(wrapper, "Cow");
+    assert_eq!(quote!(#inner).to_string(), "[u8]");
+
+    let ty: Type = parse_quote!(Rc);
+    assert!(ty.smart_ptr_inner().is_none());
+
+    let ty: Type = parse_quote!(Vec<T>);
+    assert!(ty.smart_ptr_inner().is_none());
+
+    let ty: Type = parse_quote!(<T as Trait>::Box<U>);
+    assert!(ty.smart_ptr_inner().is_none());
+}
+
+#[test]
+fn test_eq_with() {
+    let strict = TypeEqOptions::default();
+    let lenient = TypeEqOptions::default()
+        .lifetimes(false)
+        .const_args(false)
+        .normalize_std_paths(true);
+    assert!(!lenient.lifetimes && !lenient.const_args && lenient.normalize_std_paths);
+
+    let a: Type = parse_quote!(&'a std::vec::Vec<(T)>);
+    let b: Type = parse_quote!(&Vec<T>);
+    assert!(!a.eq_with(&b, strict));
+    assert!(a.eq_with(&b, lenient));
+
+    let a: Type = parse_quote!(Foo<'a, T, { N + 1 }>);
+    let b: Type = parse_quote!(Foo<T, { N }>);
+    assert!(!a.eq_with(&b, strict));
+    assert!(a.eq_with(&b, lenient));
+
+    let a: Type = parse_quote!(HashMap<K, V>);
+    let b: Type = parse_quote!(HashMap<V, K>);
+    assert!(!a.eq_with(&b, lenient));
+
+    let a: Type = parse_quote!(Box<dyn Fn(u8) -> u8 + Send + 'static>);
+    let b: Type = parse_quote!(Box<dyn Fn(u8) -> u8 + Send>);
+    assert!(!a.eq_with(&b, strict));
+    assert!(a.eq_with(&b, lenient));
+
+    let a: Type = parse_quote!(extern "C" fn(x: u8));
+    let b: Type = parse_quote!(extern fn(u8));
+    assert!(a.eq_with(&b, strict));
+
+    let a: Type = parse_quote!(<std::vec::Vec<T> as IntoIterator>::Item);
+    let b: Type = parse_quote!(<Vec<T> as IntoIterator>::Item);
+    assert!(a.eq_with(&b, lenient));
+    assert!(!a.eq_with(&b, strict));
+
+    // Only a leading `::` before std, core or alloc is normalized away.
+    let a: Type = parse_quote!(::std::vec::Vec<T>);
+    let b: Type = parse_quote!(Vec<T>);
+    assert!(a.eq_with(&b, lenient));
+    let a: Type = parse_quote!(::my_crate::Thing);
+    let b: Type = parse_quote!(my_crate::Thing);
+    assert!(!a.eq_with(&b, lenient));
+
+    // Generic arguments of associated types follow the options.
+    let a: Type = parse_quote!(dyn Trait<Assoc<'a, std::string::String> = u8>);
+    let b: Type = parse_quote!(dyn Trait<Assoc<String> = u8>);
+    assert!(!a.eq_with(&b, strict));
+    assert!(a.eq_with(&b, lenient));
+    let a: Type = parse_quote!(impl Trait<Assoc<'a>: Send>);
+    let b: Type = parse_quote!(impl Trait<Assoc<'b>: Send>);
+    assert!(!a.eq_with(&b, strict));
+    assert!(a.eq_with(&b, lenient));
+}
+
+#[test]
+fn test_qself_split() {
+    fn idents<'a>(segments: impl Iterator<Item = &'a syn::PathSegment>) -> Vec<String> {
+        segments.map(|segment| segment.ident.to_string()).collect()
+    }
+
+    let ty: TypePath = parse_quote!(<Vec<T> as a::b::Trait>::Assoc::method);
+    let (self_ty, trait_, projection) = ty.qself_split().unwrap();
+    assert_eq!(quote!(#self_ty).to_string(), "Vec < T >");
+    assert_eq!(idents(trait_.unwrap()), ["a", "b", "Trait"]);
+    assert_eq!(idents(projection), ["Assoc", "method"]);
+
+    let ty: TypePath = parse_quote!(<T>::method);
+    let (self_ty, trait_, projection) = ty.qself_split().unwrap();
+    assert_eq!(quote!(#self_ty).to_string(), "T");
+    assert!(trait_.is_none());
+    assert_eq!(idents(projection), ["method"]);
+
+    let ty: TypePath = parse_quote!(std::vec::Vec<T>);
+    assert!(ty.qself_split().is_none());
+}
+
+#[test]
+fn test_references_path() {
+    let node: syn::Path = parse_quote!(Node);
+
+    let ty: Type = parse_quote!(Option<Box<Node<T>>>);
+    assert!(ty.references_path(&node));
+
+    let ty: Type = parse_quote!((u8, [&'a mut Node; 2]));
+    assert!(ty.references_path(&node));
+
+    let ty: Type = parse_quote!(fn(u8) -> Node);
+    assert!(ty.references_path(&node));
+
+    let ty: Type = parse_quote!(Box<dyn Fn(Node)>);
+    assert!(ty.references_path(&node));
+
+    let ty: Type = parse_quote!(<Vec<Node> as IntoIterator>::Item);
+    assert!(ty.references_path(&node));
+
+    let ty: Type = parse_quote!(Box<dyn Node>);
+    assert!(!ty.references_path(&node));
+
+    let ty: Type = parse_quote!(crate::Node);
+    assert!(!ty.references_path(&node));
+    assert!(ty.references_path(&parse_quote!(crate::Node)));
+
+    let ty: Type = parse_quote!(NodeRef);
+    assert!(!ty.references_path(&node));
+}
+
+#[test]
+fn test_as_ident() {
+    let ty: Type = parse_quote!(u32);
+    assert_eq!(ty.as_ident().unwrap(), "u32");
+
+    for ty in [
+        "::u32",
+        "std::string::String",
+        "Vec<u8>",
+        "<T>::U",
+        "(u32)",
+        "&u32",
+    ] {
+        let ty: Type = syn::parse_str(ty).unwrap();
+        assert!(ty.as_ident().is_none(), "{}", quote!(#ty));
+    }
+
+    // Invisible group.
+    let tokens = TokenStream::from_iter(vec![TokenTree::Group(Group::new(
+        Delimiter::None,
+        quote!(String),
+    ))]);
+    let ty: Type = syn::parse2(tokens).unwrap();
+    assert!(matches!(ty, Type::Group(_)));
+    assert_eq!(ty.as_ident().unwrap(), "String");
+}
+
+#[test]
+fn test_references_ident_and_lifetime() {
+    let t: syn::Ident = parse_quote!(T);
+    let a: syn::Lifetime = parse_quote!('a);
+
+    let mentions = [
+        "T",
+        "&'a mut T",
+        "[T]",
+        "[T; 4]",
+        "(u8, T)",
+        "Vec<Option<T>>",
+        "T::Item",
+        "<T as IntoIterator>::Item",
+        "fn(T) -> u8",
+        "fn() -> Box<T>",
+        "Box<dyn Fn(T)>",
+        "impl Iterator<Item = T>",
+    ];
+    for ty in mentions {
+        let ty: Type = syn::parse_str(ty).unwrap();
+        assert!(ty.references_ident(&t), "{}", quote!(#ty));
+    }
+
+    let no_mentions = ["U", "::T", "Tree", "crate::T", "[u8; T]", "m!(T)"];
+    for ty in no_mentions {
+        let ty: Type = syn::parse_str(ty).unwrap();
+        assert!(!ty.references_ident(&t), "{}", quote!(#ty));
+    }
+
+    // Paths built by hand may have no segments.
+    let ty = Type::Path(TypePath {
+        qself: None,
+        path: syn::Path {
+            leading_colon: None,
+            segments: syn::punctuated::Punctuated::new(),
+        },
+    });
+    assert!(!ty.references_ident(&t));
+
+    let mentions = [
+        "&'a str",
+        "Cow<'a, str>",
+        "&[&'a u8]",
+        "(u8, &'a str)",
+        "fn(&'a str)",
+        "Box<dyn Trait + 'a>",
+        "impl Iterator<Item = &'a u8>",
+        "for<'b> fn(&'b &'a str)",
+    ];
+    for ty in mentions {
+        let ty: Type = syn::parse_str(ty).unwrap();
+        assert!(ty.references_lifetime(&a), "{}", quote!(#ty));
+    }
+
+    let no_mentions = [
+        "&str",
+        "&'b str",
+        "T",
+        "for<'a> fn(&'a str)",
+        "Box<dyn for<'a> Fn(&'a str)>",
+    ];
+    for ty in no_mentions {
+        let ty: Type = syn::parse_str(ty).unwrap();
+        assert!(!ty.references_lifetime(&a), "{}", quote!(#ty));
+    }
+}
+
+#[test]
+fn test_cache_key() {
+    // mimics the token stream corresponding to `Vec<$ty>`
+    let tokens = TokenStream::from_iter(vec![
+        TokenTree::Ident(Ident::new("Vec", Span::call_site())),
+        TokenTree::Punct(Punct::new('<', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::None, quote! { [u8; 4] })),
+        TokenTree::Punct(Punct::new('>', Spacing::Alone)),
+    ]);
+    let grouped: Type = syn::parse2(tokens).unwrap();
+    let plain: Type = syn::parse_str("Vec<[u8;4]>").unwrap();
+    assert_eq!(grouped.cache_key(), "Vec < [u8 ; 4] >");
+    assert_eq!(grouped.cache_key(), plain.cache_key());
+
+    let ty: Type = parse_quote!(fn() -> <T as ::core::ops::Deref>::Target);
+    assert_eq!(
+        ty.cache_key(),
+        "fn () -> < T as :: core :: ops :: Deref > :: Target",
+    );
+
+    let a: Type = parse_quote!(Vec<T>);
+    let b: Type = parse_quote!(std::vec::Vec<T>);
+    assert_ne!(a.cache_key(), b.cache_key());
+}
+
+#[test]
+fn test_infer() {
+    let ty: Type = parse_quote!(_);
+    assert!(ty.is_infer());
+
+    let ty = Type::Group(syn::TypeGroup {
+        group_token: Default::default(),
+        elem: Box::new(parse_quote!(_)),
+    });
+    assert!(ty.is_infer());
+
+    let ty: Type = parse_quote!(&_);
+    assert!(!ty.is_infer());
+
+    let mut ty: Type = parse_quote!((_, &'a [_; 2], fn(_) -> Option<_>, Vec<u8>));
+    syn::ty::replace_infer(&mut ty, &parse_quote!(Vec<_>));
+    let expected: Type = parse_quote! {
+        (Vec<_>, &'a [Vec<_>; 2], fn(Vec<_>) -> Option<Vec<_>>, Vec<u8>)
+    };
+    assert_eq!(ty, expected);
+}
+
+#[test]
+fn test_to_turbofish() {
+    fn turbofish(ty: Type) -> String {
+        ty.to_turbofish().to_string()
+    }
+
+    assert_eq!(turbofish(parse_quote!(Vec<u8>)), "Vec :: < u8 >");
+    assert_eq!(turbofish(parse_quote!(Vec::<u8>)), "Vec :: < u8 >");
+    assert_eq!(turbofish(parse_quote!(String)), "String");
+    assert_eq!(turbofish(parse_quote!([u8; 4])), "[u8 ; 4]");
+    assert_eq!(
+        turbofish(parse_quote!(a::B<T>::C<'a, U>)),
+        "a :: B :: < T > :: C :: < 'a , U >",
+    );
+    assert_eq!(
+        turbofish(parse_quote!(<Vec<T> as IntoIterator<X>>::Item)),
+        "< Vec < T > as IntoIterator :: < X > > :: Item",
+    );
+
+    let ty: Type = parse_quote!(HashMap<K, V>);
+    let ctor = ty.to_turbofish();
+    let expr: syn::Expr = parse_quote!(#ctor::new());
+    assert_eq!(expr, parse_quote!(HashMap::<K, V>::new()));
+}
+
+#[test]
+fn test_bare_fn_builders() {
+    let ty: TypeBareFn = parse_quote!(fn(u8) -> u8);
+    let ty = ty.with_unsafe(true).with_abi(Some("C"));
+    let expected: TypeBareFn = parse_quote!(unsafe extern "C" fn(u8) -> u8);
+    assert_eq!(ty, expected);
+
+    let ty = ty.with_abi(Some("system")).with_unsafe(true);
+    let expected: TypeBareFn = parse_quote!(unsafe extern "system" fn(u8) -> u8);
+    assert_eq!(ty, expected);
+
+    let ty = ty.with_abi(None).with_unsafe(false);
+    let expected: TypeBareFn = parse_quote!(fn(u8) -> u8);
+    assert_eq!(ty, expected);
+
+    let ty: TypeBareFn = syn::parse_str("extern fn()").unwrap();
+    let expected: TypeBareFn = parse_quote!(extern "C" fn());
+    assert_eq!(ty.with_abi(Some("C")), expected);
+}
+
+#[test]
+fn test_reference_summary() {
+    fn summary(ty: Type) -> (usize, bool, bool) {
+        let summary = ty.reference_summary();
+        (summary.depth, summary.outer_mut, summary.any_mut)
+    }
+
+    assert_eq!(summary(parse_quote!(String)), (0, false, false));
+    assert_eq!(summary(parse_quote!(&str)), (1, false, false));
+    assert_eq!(summary(parse_quote!(&mut Vec<u8>)), (1, true, true));
+    assert_eq!(summary(parse_quote!(&(&mut T))), (2, false, true));
+    assert_eq!(summary(parse_quote!(&mut &'a [u8])), (2, true, true));
+    assert_eq!(summary(parse_quote!(&*mut &T)), (1, false, false));
+    assert_eq!(summary(parse_quote!(Box<&mut T>)), (0, false, false));
+
+    let ty: Type = parse_quote!(&mut &str);
+    let expected = RefSummary {
+        depth: 2,
+        outer_mut: true,
+        any_mut: true,
+    };
+    assert_eq!(ty.reference_summary(), expected);
+    assert_eq!(
+        format!("{:?}", expected),
+        "RefSummary { depth: 2, outer_mut: true, any_mut: true }",
+    );
+}
+
+#[test]
+fn test_split_bounds() {
+    fn split(ty: TypeTraitObject) -> (Option<String>, Vec<String>, Vec<String>) {
+        let (principal, auto_traits, lifetimes) = ty.split_bounds();
+        (
+            principal.map(|bound| quote!(#bound).to_string()),
+            auto_traits
+                .into_iter()
+                .map(|bound| quote!(#bound).to_string())
+                .collect(),
+            lifetimes.into_iter().map(ToString::to_string).collect(),
+        )
+    }
+
+    let ty: TypeTraitObject = parse_quote!(dyn Iterator<Item = u8> + Send + 'a + Sync);
+    assert_eq!(
+        split(ty),
+        (
+            Some("Iterator < Item = u8 >".to_owned()),
+            vec!["Send".to_owned(), "Sync".to_owned()],
+            vec!["'a".to_owned()],
+        ),
+    );
+
+    // A `?Sized` bound or a second trait is never the principal.
+    let ty: TypeTraitObject = parse_quote!(dyn ?Sized + std::marker::Send + Display + Debug);
+    assert_eq!(
+        split(ty),
+        (
+            Some("Display".to_owned()),
+            vec![
+                "? Sized".to_owned(),
+                "std :: marker :: Send".to_owned(),
+                "Debug".to_owned(),
+            ],
+            vec![],
+        ),
+    );
+
+    let ty: TypeTraitObject = parse_quote!(dyn Send + 'static);
+    assert_eq!(
+        split(ty),
+        (None, vec!["Send".to_owned()], vec!["'static".to_owned()]),
+    );
+}
+
+#[test]
+fn test_needs_parens_in_ref_position() {
+    for (ty, expected) in [
+        ("dyn A + B", true),
+        ("dyn A + 'static", true),
+        ("impl A + B", true),
+        ("dyn Fn() -> dyn A + B", true),
+        ("dyn A", false),
+        ("impl A", false),
+        ("fn()", false),
+        ("fn() -> dyn A", false),
+        ("(dyn A + B)", false),
+        ("Box<dyn A + B>", false),
+    ] {
+        let ty: Type = syn::parse_str(ty).unwrap();
+        assert_eq!(
+            ty.needs_parens_in_ref_position(),
+            expected,
+            "{}",
+            quote!(#ty)
+        );
+    }
+
+    // A function pointer returning `dyn A + B`, which only comes about by
+    // construction since the parser takes the `+` as ending the type.
+    let mut ty: Type = parse_quote!(fn() -> (dyn A + B));
+    if let Type::BareFn(bare_fn) = &mut ty {
+        if let ReturnType::Type(_, output) = &mut bare_fn.output {
+            if let Type::Paren(paren) = &**output {
+                *output = paren.elem.clone();
+            }
+        }
+    }
+    assert_eq!(quote!(#ty).to_string(), "fn () -> dyn A + B");
+    assert!(ty.needs_parens_in_ref_position());
+
+    // The argument of `Box<dyn A>` can be put behind a reference as is.
+    let ty: Type = parse_quote!(Box<dyn A>);
+    let arg = match &ty {
+        Type::Path(ty) => match &ty.path.segments[0].arguments {
+            syn::PathArguments::AngleBracketed(arguments) => match &arguments.args[0] {
+                syn::GenericArgument::Type(arg) => arg,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert!(!arg.needs_parens_in_ref_position());
+    syn::parse_str::<Type>(&quote!(&#arg).to_string()).unwrap();
+
+    // `*const fn()` needs no parentheses.
+    let ty: Type = parse_quote!(*const fn());
+    match ty {
+        Type::Ptr(ty) => assert!(!ty.elem.needs_parens_in_ref_position()),
+        _ => unreachable!(),
+    }
+
+    // `&dyn A + B` is rejected, so `dyn A + B` needs parentheses.
+    syn::parse_str::<Type>("&dyn A + B").unwrap_err();
+    syn::parse_str::<Type>("&(dyn A + B)").unwrap();
+}
+
+#[test]
+fn test_abi_name() {
+    let ty: Type = parse_quote!(extern "C" fn());
+    let abi = match &ty {
+        Type::BareFn(bare_fn) => bare_fn.abi.as_ref().unwrap(),
+        _ => panic!("expected fn pointer"),
+    };
+    assert_eq!(abi.name().as_deref(), Some("C"));
+
+    let abi: Abi = parse_quote!(extern "\x73ystem");
+    assert_eq!(abi.name().as_deref(), Some("system"));
+
+    let abi: Abi = parse_quote!(extern);
+    assert_eq!(abi.name(), None);
+}
+
+#[test]
+fn test_underscore_token() {
+    let ty: Type = parse_quote!(_);
+    match &ty {
+        Type::Infer(infer) => {
+            let underscore: Token![_] = infer.underscore_token;
+            assert_eq!(quote!(#underscore).to_string(), "_");
+        }
+        _ => panic!("expected Type::Infer"),
+    }
+
+    let underscore: Token![_] = parse_quote!(_);
+    let ty: Type = parse_quote!(Vec<#underscore>);
+    assert_eq!(quote!(#ty).to_string(), "Vec < _ >");
+
+    let peek = |input: ParseStream| {
+        let peeked = (input.peek(Token![_]), input.peek(syn::Ident));
+        input.parse::<TokenStream>()?;
+        Ok(peeked)
+    };
+    assert_eq!(peek.parse_str("_").unwrap(), (true, false));
+    assert_eq!(peek.parse_str("_x").unwrap(), (false, true));
+    assert_eq!(peek.parse_str("x").unwrap(), (false, true));
+}
+
+#[test]
+fn test_return_type_as_type() {
+    let output: ReturnType = parse_quote!();
+    let unit: Type = parse_quote!(());
+    assert_eq!(*output.as_type(), unit);
+
+    let output: ReturnType = parse_quote!(-> Option<u8>);
+    let ty: Type = parse_quote!(Option<u8>);
+    assert_eq!(*output.as_type(), ty);
+
+    let output: ReturnType = parse_quote!(-> ());
+    assert_eq!(*output.as_type(), unit);
+}
@@ -0,0 +1,86 @@
+use std::fs;
+use syn::{parse_quote, File};
+
+#[test]
+fn test_unparse() {
+    let file: File = parse_quote! {
+        #![allow(dead_code)]
+
+        /// Doc comment.
+        #[derive(Debug)]
+        enum E<T> where T: Clone {
+            A,
+            B(T),
+        }
+
+        fn f(x: &[u8], y: Option<Vec<u8>>) -> Result<(), Error> {
+            let p = Point { x: 1, y: -2 };
+            if let Some(y) = y {
+                for b in y.iter().map(|b| b * 2) {
+                    g(*b)?;
+                }
+            } else {
+                return Err(Error::new("no y"));
+            }
+            match x.len() {
+                0 => {}
+                n if n < 4 => println!("short"),
+                _ => panic!(),
+            }
+            Ok(())
+        }
+    };
+
+    let expected = r#"#![allow(dead_code)]
+
+/// Doc comment.
+#[derive(Debug)]
+enum E<T> where T: Clone {
+    A,
+    B(T),
+}
+
+fn f(x: &[u8], y: Option<Vec<u8>>) -> Result<(), Error> {
+    let p = Point { x: 1, y: -2 };
+    if let Some(y) = y {
+        for b in y.iter().map(|b| b * 2) {
+            g(*b)?;
+        }
+    } else {
+        return Err(Error::new("no y"));
+    }
+    match x.len() {
+        0 => {}
+        n if n < 4 => println!("short"),
+        _ => panic!(),
+    }
+    Ok(())
+}
+"#;
+    assert_eq!(syn::unparse::file(&file), expected);
+}
+
+#[test]
+fn test_unparse_integer_dot() {
+    // Written as `1.0`, the field access would be read back as a float.
+    let file: File = syn::parse_str("fn f() { 1 .0; (1).0; 1.5.max(2.0); 0..n; x.0.1; }").unwrap();
+    let expected = "fn f() {\n    1 .0;\n    (1).0;\n    1.5.max(2.0);\n    0..n;\n    x.0.1;\n}\n";
+    let unparsed = syn::unparse::file(&file);
+    assert_eq!(unparsed, expected);
+    assert_eq!(syn::parse_file(&unparsed).unwrap(), file);
+}
+
+#[test]
+fn test_unparse_round_trip() {
+    for entry in fs::read_dir("src").unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let content = fs::read_to_string(&path).unwrap();
+        let file = syn::parse_file(&content).unwrap();
+        let unparsed = syn::unparse::file(&file);
+        let reparsed = syn::parse_file(&unparsed).unwrap();
+        assert_eq!(reparsed, file, "{}", path.display());
+    }
+}
@@ -0,0 +1,179 @@
+use proc_macro2::Span;
+use syn::visit::{self, Collector, Visit};
+use syn::visit_mut::{self, AttrStripper, VisitMut};
+use syn::{parse_quote, Expr, ExprPath, File, Ident, ItemFn, Lifetime, Signature, Type};
+
+#[derive(Default)]
+struct CountLifetimes(Vec<String>);
+
+impl<'ast> Visit<'ast> for CountLifetimes {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.0.push(lifetime.ident.to_string());
+        visit::visit_lifetime(self, lifetime);
+    }
+}
+
+impl VisitMut for CountLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        self.0.push(lifetime.ident.to_string());
+        visit_mut::visit_lifetime_mut(self, lifetime);
+    }
+}
+
+#[test]
+fn test_visit_lifetimes() {
+    let mut sig: Signature = parse_quote! {
+        fn f<'a>(x: &'a Foo<'a>) -> Box<dyn Trait + 'a>
+    };
+
+    let mut visitor = CountLifetimes::default();
+    visitor.visit_signature(&sig);
+    assert_eq!(visitor.0, ["a", "a", "a", "a"]);
+
+    let mut visitor = CountLifetimes::default();
+    visitor.visit_signature_mut(&mut sig);
+    assert_eq!(visitor.0, ["a", "a", "a", "a"]);
+
+    let mut ty: Type = parse_quote! {
+        (
+            <&'a T as Trait<'b>>::Assoc<'c>,
+            for<'d> fn(&'d u8, &'e u8) -> &'f u8,
+            dyn for<'g> Fn(&'g u8) + Send + 'h,
+        )
+    };
+
+    let expected = ["a", "b", "c", "d", "d", "e", "f", "g", "g", "h"];
+
+    let mut visitor = CountLifetimes::default();
+    visitor.visit_type(&ty);
+    assert_eq!(visitor.0, expected);
+
+    let mut visitor = CountLifetimes::default();
+    visitor.visit_type_mut(&mut ty);
+    assert_eq!(visitor.0, expected);
+}
+
+#[test]
+fn test_attr_stripper() {
+    let mut file: File = parse_quote! {
+        #[my_helper]
+        mod m {
+            #![my_helper]
+            #[derive(Debug)]
+            struct S {
+                #[my_helper(skip)]
+                #[doc = "field"]
+                a: u8,
+            }
+            #[my_helper]
+            fn f(#[my_helper] x: u8) -> u8 {
+                #[my_helper]
+                let y = #[my_helper] (x + 1);
+                mod inner {
+                    #[my_helper]
+                    enum E {
+                        #[my_helper]
+                        V,
+                    }
+                }
+                y
+            }
+        }
+    };
+
+    let mut stripper = AttrStripper::new(|attr| attr.path().is_ident("my_helper"));
+    stripper.visit_file_mut(&mut file);
+
+    let expected: File = parse_quote! {
+        mod m {
+            #[derive(Debug)]
+            struct S {
+                #[doc = "field"]
+                a: u8,
+            }
+            fn f(x: u8) -> u8 {
+                let y = (x + 1);
+                mod inner {
+                    enum E {
+                        V,
+                    }
+                }
+                y
+            }
+        }
+    };
+    assert_eq!(file, expected);
+}
+
+#[derive(Default)]
+struct RecordPuncts(Vec<String>);
+
+impl<'ast> Visit<'ast> for RecordPuncts {
+    fn visit_ident(&mut self, ident: &'ast Ident) {
+        self.0.push(ident.to_string());
+    }
+
+    fn visit_punct(&mut self, punct: &str, spans: &[Span]) {
+        assert_eq!(punct.len(), spans.len());
+        self.0.push(punct.to_owned());
+    }
+}
+
+impl VisitMut for RecordPuncts {
+    fn visit_ident_mut(&mut self, ident: &mut Ident) {
+        self.0.push(ident.to_string());
+    }
+
+    fn visit_punct_mut(&mut self, punct: &str, spans: &mut [Span]) {
+        assert_eq!(punct.len(), spans.len());
+        self.0.push(punct.to_owned());
+    }
+}
+
+#[test]
+fn test_visit_punct() {
+    let mut ty: Type = parse_quote!(a::b::C<D, E + F,>);
+    let expected = ["a", "::", "b", "::", "C", "D", ",", "E", "+", "F", ","];
+
+    let mut visitor = RecordPuncts::default();
+    visitor.visit_type(&ty);
+    assert_eq!(visitor.0, expected);
+
+    let mut visitor = RecordPuncts::default();
+    visitor.visit_type_mut(&mut ty);
+    assert_eq!(visitor.0, expected);
+}
+
+#[test]
+fn test_collector() {
+    let item: ItemFn = parse_quote! {
+        fn f<'a>(x: &'a str) -> usize {
+            'outer: loop {
+                break 'outer x.len() + MAX::<{ N }>;
+            }
+        }
+    };
+
+    let idents = visit::collect_idents(&item);
+    assert_eq!(idents, ["f", "x", "str", "usize", "x", "len", "MAX", "N"]);
+
+    let lifetimes = visit::collect::<Lifetime, _>(&item);
+    assert_eq!(lifetimes.len(), 4);
+
+    let paths: Vec<String> = visit::collect::<ExprPath, _>(&item)
+        .into_iter()
+        .map(|path| path.path.segments[0].ident.to_string())
+        .collect();
+    assert_eq!(paths, ["x", "MAX", "N"]);
+
+    let expr: Expr = parse_quote!(a.b(c));
+    let first = visit::find_first::<ExprPath, _>(&expr).unwrap();
+    assert!(first.path.is_ident("a"));
+    assert!(visit::find_first::<Lifetime, _>(&expr).is_none());
+
+    let mut count = 0;
+    let mut collector = Collector::new(|_: &Ident| count += 1);
+    collector.visit_expr(&expr);
+    drop(collector);
+    assert_eq!(count, 3);
+}